@@ -4,12 +4,14 @@ use word_iterator::WordIterator;
 
 /// The casing style of a string.
 ///
-/// You can pass this to [`map_ascii_case`] to determine the casing style of the
-/// returned `&'static str`.
+/// You can pass this to [`map_ascii_case`] or [`map_case`]
+/// to determine the casing style of the returned `&'static str`.
 ///
 ///
 /// [`map_ascii_case`]: ./macro.map_ascii_case.html
+/// [`map_case`]: ./macro.map_case.html
 #[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum Case {
     /// Lowercase
     Lower,
@@ -27,6 +29,245 @@ pub enum Case {
     Kebab,
     /// Kebab case, eg: `FOO-BAR-BAZ`. Also turns the string uppercase.
     UpperKebab,
+    /// Title case, eg: `Foo Bar Baz`. The first character of every word is uppercase.
+    Title,
+    /// Train case, eg: `Foo-Bar-Baz`. The first character of every word is uppercase.
+    Train,
+    /// Dot case, eg: `foo.bar.baz`. Also turns the string lowercase.
+    Dot,
+    /// Flat case, eg: `foobarbaz`. Also turns the string lowercase.
+    Flat,
+    /// COBOL case, eg: `FOO-BAR-BAZ`. The same as `UpperKebab`.
+    Cobol,
+    /// Path case, eg: `foo/bar/baz`. Also turns the string lowercase.
+    Path,
+    /// A custom casing style, which joins words with `separator`,
+    /// converting the first word to `first_word_case`,
+    /// and every other word to `word_case`.
+    ///
+    /// [`Case::custom`] constructs this with the same case for every word.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{Case, WordCase, map_ascii_case};
+    ///
+    /// const CASE: Case = Case::Custom {
+    ///     separator: "::",
+    ///     first_word_case: WordCase::Upper,
+    ///     word_case: WordCase::Capitalized,
+    /// };
+    ///
+    /// assert_eq!(map_ascii_case!(CASE, "hello world_foo"), "HELLO::World::Foo");
+    ///
+    /// ```
+    ///
+    /// [`Case::custom`]: #method.custom
+    Custom {
+        /// The string that's written between words.
+        separator: &'static str,
+        /// The case that the first word is converted to.
+        first_word_case: WordCase,
+        /// The case that every word after the first is converted to.
+        word_case: WordCase,
+    },
+}
+
+impl Case {
+    /// Constructs a `Case::Custom` that joins words with `separator`,
+    /// converting every word to `word_case`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use const_format::{Case, WordCase, map_ascii_case};
+    ///
+    /// const CASE: Case = Case::custom(", ", WordCase::Upper);
+    ///
+    /// assert_eq!(map_ascii_case!(CASE, "fooBar baz"), "FOO, BAR, BAZ");
+    ///
+    /// ```
+    pub const fn custom(separator: &'static str, word_case: WordCase) -> Self {
+        Case::Custom {
+            separator,
+            first_word_case: word_case,
+            word_case,
+        }
+    }
+}
+
+/// The case that the words of a string are converted to,
+/// used by [`Case::Custom`].
+///
+/// [`Case::Custom`]: ./enum.Case.html#variant.Custom
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum WordCase {
+    /// Lowercase, eg: `foo`
+    Lower,
+    /// Uppercase, eg: `FOO`
+    Upper,
+    /// The first character is uppercase, and the rest is lowercase, eg: `Foo`
+    Capitalized,
+}
+
+/// Options for how the casing styles that split the string into words
+/// (all of them except `Case::Lower` and `Case::Upper`) find the words.
+///
+/// You can pass this as the optional third argument of [`map_ascii_case`] and [`map_case`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{Acronyms, Case, CaseOptions, map_ascii_case};
+///
+/// const IN: &str = "HTTPServer2Go v1.2";
+///
+/// assert_eq!(map_ascii_case!(Case::Snake, IN), "httpserver_2_go_v_1_2");
+///
+/// const GROUPED: CaseOptions = CaseOptions::NEW
+///     .set_acronyms(Acronyms::Grouped)
+///     .set_digit_boundaries(false);
+/// assert_eq!(map_ascii_case!(Case::Snake, IN, GROUPED), "http_server2_go_v1_2");
+///
+/// const SPLIT: CaseOptions = CaseOptions::NEW.set_acronyms(Acronyms::Split);
+/// assert_eq!(map_ascii_case!(Case::Snake, IN, SPLIT), "h_t_t_p_server_2_go_v_1_2");
+///
+/// const KEEP_DOTS: CaseOptions = GROUPED.set_kept_punctuation(".");
+/// assert_eq!(map_ascii_case!(Case::Snake, IN, KEEP_DOTS), "http_server2_go_v1.2");
+///
+/// ```
+///
+/// [`map_ascii_case`]: ./macro.map_ascii_case.html
+/// [`map_case`]: ./macro.map_case.html
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CaseOptions {
+    acronyms: Acronyms,
+    digit_boundaries: bool,
+    kept_punctuation: &'static str,
+}
+
+impl CaseOptions {
+    /// Constructs a `CaseOptions` with these values:
+    ///
+    /// - acronyms: `Acronyms::Joined`
+    ///
+    /// - digit boundaries: true
+    ///
+    /// - kept punctuation: `""`
+    ///
+    pub const NEW: Self = Self {
+        acronyms: Acronyms::Joined,
+        digit_boundaries: true,
+        kept_punctuation: "",
+    };
+
+    /// Sets how sequences of uppercase letters are split into words.
+    #[inline]
+    pub const fn set_acronyms(mut self, acronyms: Acronyms) -> Self {
+        self.acronyms = acronyms;
+        self
+    }
+
+    /// Sets whether digits are separate words from the letters around them.
+    ///
+    /// When this is false, digits continue the current word,
+    /// eg: `Server2Go` is split into `Server2` and `Go`.
+    #[inline]
+    pub const fn set_digit_boundaries(mut self, digit_boundaries: bool) -> Self {
+        self.digit_boundaries = digit_boundaries;
+        self
+    }
+
+    /// Sets the ascii punctuation characters that are kept in the returned string,
+    /// instead of being treated as spaces.
+    ///
+    /// These characters continue the current word (or start a new one).
+    /// Non-punctuation characters in `kept_punctuation` are ignored.
+    #[inline]
+    pub const fn set_kept_punctuation(mut self, kept_punctuation: &'static str) -> Self {
+        self.kept_punctuation = kept_punctuation;
+        self
+    }
+
+    /// Gets how sequences of uppercase letters are split into words.
+    #[inline]
+    pub const fn acronyms(self) -> Acronyms {
+        self.acronyms
+    }
+
+    /// Gets whether digits are separate words from the letters around them.
+    #[inline]
+    pub const fn digit_boundaries(self) -> bool {
+        self.digit_boundaries
+    }
+
+    /// Gets the ascii punctuation characters that are kept in the returned string.
+    #[inline]
+    pub const fn kept_punctuation(self) -> &'static str {
+        self.kept_punctuation
+    }
+}
+
+/// How sequences of uppercase letters are split into words,
+/// set with [`CaseOptions::set_acronyms`].
+///
+/// [`CaseOptions::set_acronyms`]: ./struct.CaseOptions.html#method.set_acronyms
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Acronyms {
+    /// Uppercase letters are in the same word as the lowercase letters after them,
+    /// eg: `HTTPServer` is one word.
+    Joined,
+    /// The last uppercase letter before lowercase letters starts a new word,
+    /// eg: `HTTPServer` is split into `HTTP` and `Server`.
+    Grouped,
+    /// Every uppercase letter starts a new word,
+    /// eg: `HTTPServer` is split into `H`, `T`, `T`, `P`, and `Server`.
+    Split,
+}
+
+/// How the casing styles that split the string into words convert it.
+#[derive(Copy, Clone)]
+struct WordStyle {
+    separator: &'static str,
+    first_word_case: WordCase,
+    word_case: WordCase,
+}
+
+impl WordStyle {
+    const fn new(separator: &'static str, first_word_case: WordCase, word_case: WordCase) -> Self {
+        Self {
+            separator,
+            first_word_case,
+            word_case,
+        }
+    }
+}
+
+/// Returns `None` for the casing styles that don't split the string into words.
+const fn word_style(case: Case) -> Option<WordStyle> {
+    use WordCase as WC;
+
+    Some(match case {
+        Case::Upper | Case::Lower => return None,
+        Case::Pascal => WordStyle::new("", WC::Capitalized, WC::Capitalized),
+        Case::Camel => WordStyle::new("", WC::Lower, WC::Capitalized),
+        Case::Snake => WordStyle::new("_", WC::Lower, WC::Lower),
+        Case::UpperSnake => WordStyle::new("_", WC::Upper, WC::Upper),
+        Case::Kebab => WordStyle::new("-", WC::Lower, WC::Lower),
+        Case::UpperKebab | Case::Cobol => WordStyle::new("-", WC::Upper, WC::Upper),
+        Case::Title => WordStyle::new(" ", WC::Capitalized, WC::Capitalized),
+        Case::Train => WordStyle::new("-", WC::Capitalized, WC::Capitalized),
+        Case::Dot => WordStyle::new(".", WC::Lower, WC::Lower),
+        Case::Flat => WordStyle::new("", WC::Lower, WC::Lower),
+        Case::Path => WordStyle::new("/", WC::Lower, WC::Lower),
+        Case::Custom {
+            separator,
+            first_word_case,
+            word_case,
+        } => WordStyle::new(separator, first_word_case, word_case),
+    })
 }
 
 macro_rules! if_next_word {
@@ -51,6 +292,61 @@ macro_rules! while_next_word {
     };
 }
 
+/// Calls `$body` with `$b` set to each byte that `$bytes` is converted to.
+macro_rules! for_each_converted_byte {
+    ($case:expr, $options:expr, $bytes:ident, |$b:ident| $body:block) => {{
+        let case: Case = $case;
+        match word_style(case) {
+            None => {
+                let mut i = 0;
+                while i < $bytes.len() {
+                    let $b = match case {
+                        Case::Upper => uppercase_u8($bytes[i]),
+                        _ => lowercase_u8($bytes[i]),
+                    };
+                    $body
+                    i += 1;
+                }
+            }
+            Some(style) => {
+                let separator = style.separator.as_bytes();
+                let mut word_iter = WordIterator::new($bytes, $options);
+                let mut word_case = style.first_word_case;
+                let mut is_first_word = true;
+
+                while_next_word! {word_iter, word_range => {
+                    let mut i = 0;
+                    while !is_first_word && i < separator.len() {
+                        let $b = separator[i];
+                        $body
+                        i += 1;
+                    }
+
+                    let mut i = word_range.start;
+                    while i < word_range.end {
+                        let byte = $bytes[i];
+                        let $b = match word_case {
+                            WordCase::Upper => uppercase_u8(byte),
+                            WordCase::Capitalized if i == word_range.start => uppercase_u8(byte),
+                            _ => lowercase_u8(byte),
+                        };
+                        $body
+                        i += 1;
+                    }
+
+                    is_first_word = false;
+                    word_case = style.word_case;
+                }}
+            }
+        }
+    }};
+}
+
+mod unicode;
+mod unicode_tables;
+
+pub use unicode::{convert_str_unicode, size_after_unicode_conversion};
+
 struct WordCountAndLength {
     /// The amount of words
     count: usize,
@@ -58,10 +354,10 @@ struct WordCountAndLength {
     length: usize,
 }
 
-const fn words_count_and_length(bytes: &[u8]) -> WordCountAndLength {
+const fn words_count_and_length(bytes: &[u8], options: CaseOptions) -> WordCountAndLength {
     let mut count = 0;
     let mut length = 0;
-    let mut word_iter = WordIterator::new(bytes);
+    let mut word_iter = WordIterator::new(bytes, options);
     while_next_word! {word_iter, word_range => {
         count += 1;
         length += word_range.end - word_range.start;
@@ -69,101 +365,67 @@ const fn words_count_and_length(bytes: &[u8]) -> WordCountAndLength {
     WordCountAndLength { count, length }
 }
 
-pub const fn size_after_conversion(case: Case, s: &str) -> usize {
-    match case {
-        Case::Upper | Case::Lower => s.len(),
-        Case::Pascal | Case::Camel => {
-            let wcl = words_count_and_length(s.as_bytes());
-            wcl.length
-        }
-        Case::Snake | Case::Kebab | Case::UpperSnake | Case::UpperKebab => {
-            let wcl = words_count_and_length(s.as_bytes());
-            wcl.length + wcl.count.saturating_sub(1)
+pub const fn size_after_conversion(case: Case, options: CaseOptions, s: &str) -> usize {
+    match word_style(case) {
+        None => s.len(),
+        Some(style) => {
+            let wcl = words_count_and_length(s.as_bytes(), options);
+            wcl.length + style.separator.len() * wcl.count.saturating_sub(1)
         }
     }
 }
 
-pub const fn convert_str<const N: usize>(case: Case, s: &str) -> [u8; N] {
+pub const fn convert_str<const N: usize>(case: Case, options: CaseOptions, s: &str) -> [u8; N] {
     let mut arr = [0; N];
-    let mut inp = s.as_bytes();
+    let bytes = s.as_bytes();
     let mut o = 0;
 
-    macro_rules! map_bytes {
-        ($byte:ident => $e:expr) => {
-            while let [$byte, rem @ ..] = inp {
-                let $byte = *$byte;
-                inp = rem;
-                arr[o] = $e;
-                o += 1;
-            }
-        };
-    }
-
-    macro_rules! write_byte {
-        ($byte:expr) => {
-            arr[o] = $byte;
-            o += 1;
-        };
-    }
-
-    macro_rules! write_range_from {
-        ($range:expr, $from:expr, $byte:ident => $mapper:expr) => {{
-            let mut range = $range;
-            while range.start < range.end {
-                let $byte = $from[range.start];
-                arr[o] = $mapper;
-
-                range.start += 1;
-                o += 1;
-            }
-        }};
-    }
-
-    macro_rules! write_snake_kebab_case {
-        ($separator:expr, $byte_conversion:expr) => {{
-            let mut word_iter = WordIterator::new(inp);
-
-            if_next_word! {word_iter, word_range => {
-                write_range_from!(word_range, inp, byte => $byte_conversion(byte));
-
-                while_next_word!{word_iter, word_range => {
-                    write_byte!($separator);
-                    write_range_from!(word_range, inp, byte => $byte_conversion(byte));
-                }}
-            }}
-        }};
-    }
-
-    macro_rules! write_pascal_camel_case {
-        ($first_word_conv:expr) => {{
-            let mut word_iter = WordIterator::new(inp);
+    for_each_converted_byte! {case, options, bytes, |b| {
+        arr[o] = b;
+        o += 1;
+    }}
 
-            if_next_word! {word_iter, word_range => {
-                write_byte!($first_word_conv(inp[word_range.start]));
-                word_range.start += 1;
-                write_range_from!(word_range, inp, byte => lowercase_u8(byte));
+    arr
+}
 
-                while_next_word!{word_iter, word_range => {
-                    write_byte!(uppercase_u8(inp[word_range.start]));
-                    word_range.start += 1;
-                    write_range_from!(word_range, inp, byte => lowercase_u8(byte));
-                }}
-            }}
-        }};
-    }
+/// Whether `s` is already in the `case` casing style,
+/// which means that [`map_ascii_case`] would return `s` unchanged.
+///
+/// This uses the default [`CaseOptions`] to split `s` into words.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{Case, is_case};
+///
+/// const IS_SNAKE: bool = is_case(Case::Snake, "foo_bar_100");
+/// assert!(IS_SNAKE);
+///
+/// assert!(is_case(Case::Kebab, "foo-bar"));
+/// assert!(is_case(Case::Pascal, "FooBar"));
+/// assert!(is_case(Case::Upper, "FOO BAR"));
+///
+/// assert!(!is_case(Case::Snake, "fooBar"));
+/// assert!(!is_case(Case::Snake, "foo__bar"));
+/// assert!(!is_case(Case::Kebab, "-foo-bar"));
+/// assert!(!is_case(Case::Camel, "FooBar"));
+///
+/// ```
+///
+/// [`map_ascii_case`]: ./macro.map_ascii_case.html
+/// [`CaseOptions`]: ./struct.CaseOptions.html
+pub const fn is_case(case: Case, s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let mut o = 0;
 
-    match case {
-        Case::Upper => map_bytes!(b => uppercase_u8(b)),
-        Case::Lower => map_bytes!(b => lowercase_u8(b)),
-        Case::Snake => write_snake_kebab_case!(b'_', lowercase_u8),
-        Case::UpperSnake => write_snake_kebab_case!(b'_', uppercase_u8),
-        Case::Kebab => write_snake_kebab_case!(b'-', lowercase_u8),
-        Case::UpperKebab => write_snake_kebab_case!(b'-', uppercase_u8),
-        Case::Pascal => write_pascal_camel_case!(uppercase_u8),
-        Case::Camel => write_pascal_camel_case!(lowercase_u8),
-    }
+    for_each_converted_byte! {case, CaseOptions::NEW, bytes, |b| {
+        if o == bytes.len() || bytes[o] != b {
+            return false;
+        }
+        o += 1;
+    }}
 
-    arr
+    o == bytes.len()
 }
 
 const CASE_DIFF: u8 = b'a' - b'A';
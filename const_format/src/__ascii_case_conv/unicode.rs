@@ -0,0 +1,325 @@
+use super::{
+    unicode_tables as tables, word_iterator::WordIterator, word_style, Case, CaseOptions, WordCase,
+};
+
+use crate::char_encoding::{char_to_utf8_u32, decode_utf8};
+
+const MULTI_FLAG: u32 = 0x8000_0000;
+
+const GREEK_CAPITAL_SIGMA: u32 = 0x3A3;
+const GREEK_SMALL_FINAL_SIGMA: u32 = 0x3C2;
+
+/// Whether a char is in a `*_BOUNDARIES` table.
+const fn in_boundaries(bounds: &[u32], c: u32) -> bool {
+    // Counting the boundaries that are less than or equal to `c`
+    let mut start = 0;
+    let mut end = bounds.len();
+    while start < end {
+        let mid = (start + end) / 2;
+        if bounds[mid] <= c {
+            start = mid + 1;
+        } else {
+            end = mid;
+        }
+    }
+
+    start % 2 == 1
+}
+
+/// Searches for `c` in a case conversion table, returning its index.
+const fn find_mapping(table: &[(u32, u32)], c: u32) -> Option<usize> {
+    let mut start = 0;
+    let mut end = table.len();
+    while start < end {
+        let mid = (start + end) / 2;
+        let key = table[mid].0;
+        if key == c {
+            return Some(mid);
+        } else if key < c {
+            start = mid + 1;
+        } else {
+            end = mid;
+        }
+    }
+    None
+}
+
+/// Whether `c` changes when it's lowercased.
+pub(super) const fn has_lowercase(c: u32) -> bool {
+    find_mapping(tables::LOWERCASE_TABLE, c).is_some()
+}
+
+/// Whether `c` changes when it's uppercased.
+pub(super) const fn has_uppercase(c: u32) -> bool {
+    find_mapping(tables::UPPERCASE_TABLE, c).is_some()
+}
+
+pub(super) const fn is_numeric(c: u32) -> bool {
+    in_boundaries(tables::NUMERIC_BOUNDARIES, c)
+}
+
+/// Whether `c` is alphabetic, or a char that continues a word (eg: combining marks).
+pub(super) const fn is_letter(c: u32) -> bool {
+    in_boundaries(tables::LETTER_BOUNDARIES, c)
+}
+
+/// The chars that a char is converted to, only the first `len` are used.
+#[derive(Copy, Clone)]
+struct MappedChar {
+    chars: [u32; 3],
+    len: usize,
+}
+
+#[derive(Copy, Clone)]
+enum CharCase {
+    Lower,
+    Upper,
+}
+
+const fn map_char(table: &[(u32, u32)], multi: &[[u32; 3]], c: u32) -> MappedChar {
+    let value = match find_mapping(table, c) {
+        Some(i) => table[i].1,
+        None => {
+            return MappedChar {
+                chars: [c, 0, 0],
+                len: 1,
+            }
+        }
+    };
+
+    if value & MULTI_FLAG == 0 {
+        return MappedChar {
+            chars: [value, 0, 0],
+            len: 1,
+        };
+    }
+
+    let chars = multi[(value & !MULTI_FLAG) as usize];
+    let len = if chars[2] != 0 { 3 } else { 2 };
+    MappedChar { chars, len }
+}
+
+/// Converts the char at `bytes[index]` to `case`,
+/// `start..end` is the range that the final sigma rule looks at for context.
+const fn convert_char(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    index: usize,
+    c: u32,
+    case: CharCase,
+) -> MappedChar {
+    match case {
+        CharCase::Upper => map_char(tables::UPPERCASE_TABLE, tables::UPPERCASE_MULTI, c),
+        CharCase::Lower => {
+            if c == GREEK_CAPITAL_SIGMA && is_final_sigma(bytes, start, end, index) {
+                MappedChar {
+                    chars: [GREEK_SMALL_FINAL_SIGMA, 0, 0],
+                    len: 1,
+                }
+            } else {
+                map_char(tables::LOWERCASE_TABLE, tables::LOWERCASE_MULTI, c)
+            }
+        }
+    }
+}
+
+const fn is_case_ignorable(c: u32) -> bool {
+    in_boundaries(tables::CASE_IGNORABLE_BOUNDARIES, c)
+}
+
+const fn is_cased(c: u32) -> bool {
+    in_boundaries(tables::CASED_BOUNDARIES, c)
+}
+
+/// Whether the `Σ` at `bytes[index]` is at the end of a word,
+/// following the same rule as `str::to_lowercase`.
+const fn is_final_sigma(bytes: &[u8], start: usize, end: usize, index: usize) -> bool {
+    let mut i = index;
+    let preceded_by_cased = loop {
+        if i == start {
+            break false;
+        }
+        i -= 1;
+        while (bytes[i] as i8) < -0x40 {
+            i -= 1;
+        }
+        let (c, _) = decode_utf8(bytes, i);
+        if !is_case_ignorable(c) {
+            break is_cased(c);
+        }
+    };
+
+    // `Σ` is 2 bytes long
+    i = index + 2;
+    let followed_by_cased = loop {
+        if i >= end {
+            break false;
+        }
+        let (c, len) = decode_utf8(bytes, i);
+        if !is_case_ignorable(c) {
+            break is_cased(c);
+        }
+        i += len;
+    };
+
+    preceded_by_cased && !followed_by_cased
+}
+
+/// Calls `$body` with `$c` set to each char that `$bytes[$start..$end]` is converted to.
+///
+/// `$bytes[$context_start..$end]` is the range that the final sigma rule looks at.
+macro_rules! convert_range {
+    (
+        $bytes:ident, $context_start:expr, $start:expr, $end:expr, $case:expr,
+        |$c:ident| $body:block
+    ) => {{
+        let context_start: usize = $context_start;
+        let end: usize = $end;
+        let mut i: usize = $start;
+        while i < end {
+            let (c, len) = decode_utf8($bytes, i);
+            let mapped = convert_char($bytes, context_start, end, i, c, $case);
+            let mut j = 0;
+            while j < mapped.len {
+                let $c = mapped.chars[j];
+                $body
+                j += 1;
+            }
+            i += len;
+        }
+    }};
+}
+
+/// Calls `$body` with `$c` set to each char that the word in
+/// `$bytes[$word_range]` is converted to.
+macro_rules! convert_word {
+    ($bytes:ident, $word_range:ident, $word_case:expr, |$c:ident| $body:block) => {{
+        let start = $word_range.start;
+        let end = $word_range.end;
+        match $word_case {
+            WordCase::Lower => {
+                convert_range!($bytes, start, start, end, CharCase::Lower, |$c| $body)
+            }
+            WordCase::Upper => {
+                convert_range!($bytes, start, start, end, CharCase::Upper, |$c| $body)
+            }
+            WordCase::Capitalized => {
+                let (_, len) = decode_utf8($bytes, start);
+                convert_range!($bytes, start, start, start + len, CharCase::Upper, |$c| {
+                    $body
+                });
+                convert_range!($bytes, start, start + len, end, CharCase::Lower, |$c| $body);
+            }
+        }
+    }};
+}
+
+/// Calls `$body` with `$c` set to each char that `$bytes` is converted to.
+macro_rules! for_each_converted_char {
+    ($case:expr, $options:expr, $bytes:ident, |$c:ident| $body:block) => {{
+        let case: Case = $case;
+        match word_style(case) {
+            None => match case {
+                Case::Upper => {
+                    convert_range!($bytes, 0, 0, $bytes.len(), CharCase::Upper, |$c| $body)
+                }
+                _ => convert_range!($bytes, 0, 0, $bytes.len(), CharCase::Lower, |$c| $body),
+            },
+            Some(style) => {
+                let separator = style.separator.as_bytes();
+                let mut word_iter = WordIterator::new_unicode($bytes, $options);
+
+                if_next_word! {word_iter, word_range => {
+                    convert_word!($bytes, word_range, style.first_word_case, |$c| $body);
+
+                    while_next_word!{word_iter, word_range => {
+                        let mut i = 0;
+                        while i < separator.len() {
+                            let ($c, len) = decode_utf8(separator, i);
+                            $body
+                            i += len;
+                        }
+                        convert_word!($bytes, word_range, style.word_case, |$c| $body);
+                    }}
+                }}
+            }
+        }
+    }};
+}
+
+pub const fn size_after_unicode_conversion(case: Case, options: CaseOptions, s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut length = 0;
+    for_each_converted_char! {case, options, bytes, |c| {
+        length += char_to_utf8_u32(c).1;
+    }}
+    length
+}
+
+pub const fn convert_str_unicode<const N: usize>(
+    case: Case,
+    options: CaseOptions,
+    s: &str,
+) -> [u8; N] {
+    let bytes = s.as_bytes();
+    let mut arr = [0; N];
+    let mut o = 0;
+    for_each_converted_char! {case, options, bytes, |c| {
+        let (encoded, len) = char_to_utf8_u32(c);
+        let mut k = 0;
+        while k < len {
+            arr[o] = encoded[k];
+            o += 1;
+            k += 1;
+        }
+    }}
+    arr
+}
+
+#[cfg(all(test, not(miri)))]
+mod tests {
+    use super::*;
+
+    fn mapped_eq(mapped: MappedChar, iter: impl Iterator<Item = char>) -> bool {
+        mapped.chars[..mapped.len]
+            .iter()
+            .copied()
+            .eq(iter.map(|c| c as u32))
+    }
+
+    #[test]
+    fn char_conversion_matches_core() {
+        for c in '\0'..=char::MAX {
+            let upper = map_char(tables::UPPERCASE_TABLE, tables::UPPERCASE_MULTI, c as u32);
+            assert!(mapped_eq(upper, c.to_uppercase()), "{:?}", c);
+
+            let lower = map_char(tables::LOWERCASE_TABLE, tables::LOWERCASE_MULTI, c as u32);
+            assert!(mapped_eq(lower, c.to_lowercase()), "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn char_classification_matches_core() {
+        for c in '\0'..=char::MAX {
+            assert_eq!(is_numeric(c as u32), c.is_numeric(), "{:?}", c);
+            if c.is_alphabetic() {
+                assert!(is_letter(c as u32), "{:?}", c);
+            }
+        }
+
+        for c in ['a', 'Σ', 'ß', 'ǅ', 'ª', 'ʰ'] {
+            assert!(is_cased(c as u32) || is_case_ignorable(c as u32), "{:?}", c);
+        }
+        for c in ['\'', '.', ':', '\u{301}', '\u{AD}'] {
+            assert!(is_case_ignorable(c as u32), "{:?}", c);
+        }
+        for c in [' ', '0', '_', '一'] {
+            assert!(
+                !is_cased(c as u32) && !is_case_ignorable(c as u32),
+                "{:?}",
+                c
+            );
+        }
+    }
+}
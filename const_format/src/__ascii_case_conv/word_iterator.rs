@@ -1,3 +1,10 @@
+use super::{
+    unicode::{has_lowercase, has_uppercase, is_letter, is_numeric},
+    Acronyms, CaseOptions,
+};
+
+use crate::char_encoding::decode_utf8;
+
 use core::fmt::{self, Debug};
 
 macro_rules! for_range_inc {
@@ -26,6 +33,7 @@ impl Debug for ByteKind {
             _ if self.0 == Self::LowerCase.0 => "LowerCase",
             _ if self.0 == Self::UpperCase.0 => "UpperCase",
             _ if self.0 == Self::NonAscii.0 => "NonAscii",
+            _ if self.0 == Self::Joined.0 => "Joined",
             _ => unreachable!(),
         })
     }
@@ -40,7 +48,12 @@ impl ByteKind {
     const Alphabetic: Self = Self(Self::LowerCase.0 | Self::UpperCase.0);
     // Assumes that non-ascii chars are mostly alphabetic,
     // this should work out fine most of the time.
+    //
+    // With unicode classification, this is used for letters that don't have case.
     const NonAscii: Self = Self(0b1100);
+    // Chars that continue the current word without changing its kind,
+    // which are the kept punctuation, and digits when they aren't word boundaries.
+    const Joined: Self = Self(0b1_0000);
 }
 
 impl ByteKind {
@@ -61,7 +74,7 @@ impl ByteKind {
     }
 
     pub const fn is_end_of_word(mut self, prev: Self, other: Self) -> bool {
-        if self.0 == Self::NonAscii.0 {
+        if self.0 == Self::NonAscii.0 && prev.is_alphabetic() {
             self = prev;
         }
 
@@ -77,6 +90,10 @@ impl ByteKind {
 pub(crate) struct WordIterator<'a> {
     bytes: &'a [u8],
     start: usize,
+    /// Whether non-ascii chars are classified with unicode properties,
+    /// instead of being treated as letters.
+    unicode: bool,
+    options: CaseOptions,
 }
 
 const BYTE_KIND: &[ByteKind; 256] = &{
@@ -92,25 +109,135 @@ const BYTE_KIND: &[ByteKind; 256] = &{
 };
 
 impl<'a> WordIterator<'a> {
-    pub(crate) const fn new(bytes: &'a [u8]) -> Self {
-        Self { bytes, start: 0 }
+    pub(crate) const fn new(bytes: &'a [u8], options: CaseOptions) -> Self {
+        Self {
+            bytes,
+            start: 0,
+            unicode: false,
+            options,
+        }
+    }
+
+    /// Constructs a `WordIterator` that treats unicode letters and numbers
+    /// as word characters, and every other `char` as a word separator.
+    ///
+    /// `bytes` must be utf8.
+    pub(crate) const fn new_unicode(bytes: &'a [u8], options: CaseOptions) -> Self {
+        Self {
+            bytes,
+            start: 0,
+            unicode: true,
+            options,
+        }
+    }
+
+    /// Gets the kind of the char at the start of `self.bytes`,
+    /// and how many bytes to advance to get past it.
+    const fn first_kind(&self) -> (ByteKind, usize) {
+        self.kind_at(self.bytes, 0)
+    }
+
+    /// Gets the kind of the char at `bytes[index]`,
+    /// and how many bytes to advance to get past it.
+    const fn kind_at(&self, bytes: &[u8], index: usize) -> (ByteKind, usize) {
+        let b = bytes[index];
+        if !self.unicode || b < 128 {
+            let kind = BYTE_KIND[b as usize];
+            let is_joined = if kind.0 == ByteKind::Number.0 {
+                !self.options.digit_boundaries
+            } else {
+                kind.0 == ByteKind::Other.0 && self.is_kept_punctuation(b)
+            };
+            return (if is_joined { ByteKind::Joined } else { kind }, 1);
+        }
+
+        let (c, len) = decode_utf8(bytes, index);
+        let kind = if has_lowercase(c) {
+            ByteKind::UpperCase
+        } else if has_uppercase(c) {
+            ByteKind::LowerCase
+        } else if is_numeric(c) {
+            if self.options.digit_boundaries {
+                ByteKind::Number
+            } else {
+                ByteKind::Joined
+            }
+        } else if is_letter(c) {
+            ByteKind::NonAscii
+        } else {
+            ByteKind::Other
+        };
+        (kind, len)
+    }
+
+    const fn is_kept_punctuation(&self, b: u8) -> bool {
+        let kept = self.options.kept_punctuation.as_bytes();
+        let mut i = 0;
+        while i < kept.len() {
+            if kept[i] == b {
+                return true;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Whether the uppercase char at the start of `self.bytes`,
+    /// which is `len` bytes long and follows another uppercase char,
+    /// starts a new word.
+    const fn is_start_of_acronym_word(&self, len: usize) -> bool {
+        match self.options.acronyms {
+            Acronyms::Joined => false,
+            Acronyms::Split => true,
+            Acronyms::Grouped if len < self.bytes.len() => {
+                let (after, _) = self.kind_at(self.bytes, len);
+                after.0 == ByteKind::LowerCase.0
+            }
+            Acronyms::Grouped => false,
+        }
+    }
+
+    const fn skip_bytes(mut self, mut len: usize) -> Self {
+        while len != 0 {
+            if let [_, rem @ ..] = self.bytes {
+                self.bytes = rem;
+                self.start += 1;
+            }
+            len -= 1;
+        }
+        self
     }
 
     const fn skip_same_kind(mut self, mut kind: ByteKind) -> (Self, ByteKind) {
         let orig_bytes_len = self.bytes.len();
 
         let mut prev_kind = kind;
-        while let [b, rem @ ..] = self.bytes {
-            let next_kind = BYTE_KIND[*b as usize];
-            let cmp = kind.is_end_of_word(prev_kind, next_kind);
+        while let [_, ..] = self.bytes {
+            let (next_kind, mut len) = self.first_kind();
+            let cmp = if next_kind.0 == ByteKind::Joined.0 {
+                kind.0 == ByteKind::Other.0
+            } else if kind.0 == ByteKind::Joined.0 {
+                next_kind.0 == ByteKind::Other.0
+            } else if kind.0 == ByteKind::UpperCase.0 && next_kind.0 == ByteKind::UpperCase.0 {
+                self.is_start_of_acronym_word(len)
+            } else {
+                kind.is_end_of_word(prev_kind, next_kind)
+            };
             if kind.is_alphabetic() {
                 prev_kind = kind;
             }
-            kind = next_kind;
+            if next_kind.0 != ByteKind::Joined.0 || kind.0 == ByteKind::Other.0 {
+                kind = next_kind;
+            }
             if cmp {
                 break;
             }
-            self.bytes = rem;
+            while len != 0 {
+                if let [_, rem @ ..] = self.bytes {
+                    self.bytes = rem;
+                }
+                len -= 1;
+            }
         }
 
         // Advance until a char boundary is found
@@ -132,7 +259,9 @@ impl<'a> WordIterator<'a> {
         if let [] = this.bytes {
             None
         } else {
-            let (next, _) = this.skip_same_kind(fkind);
+            // Skipping the first char, since acronyms can end a word before any char
+            let (_, len) = this.first_kind();
+            let (next, _) = this.skip_bytes(len).skip_same_kind(fkind);
             let range = this.start..next.start;
             Some((next, range))
         }
@@ -146,8 +275,18 @@ mod tests {
     use arrayvec::ArrayVec;
 
     fn get_words(text: &str) -> ArrayVec<&str, 20> {
+        collect_words(text, WordIterator::new(text.as_bytes(), CaseOptions::NEW))
+    }
+
+    fn get_unicode_words(text: &str) -> ArrayVec<&str, 20> {
+        collect_words(
+            text,
+            WordIterator::new_unicode(text.as_bytes(), CaseOptions::NEW),
+        )
+    }
+
+    fn collect_words<'a>(text: &'a str, mut word_iter: WordIterator<'_>) -> ArrayVec<&'a str, 20> {
         let mut list = <ArrayVec<&str, 20>>::new();
-        let mut word_iter = WordIterator::new(text.as_bytes());
 
         while let Some((niter, word_range)) = word_iter.next() {
             word_iter = niter;
@@ -181,4 +320,79 @@ mod tests {
 
         assert_eq!(get_words("    ña01934 ")[..], ["ña", "01934"],);
     }
+
+    #[test]
+    fn test_unicode_word_iter() {
+        assert_eq!(
+            get_unicode_words("01934324ñmaniÑNnFooBar")[..],
+            ["01934324", "ñmani", "ÑNn", "Foo", "Bar"],
+        );
+
+        assert_eq!(
+            get_unicode_words("größe—ÜBER_маленькийWert «γειά» σου")[..],
+            ["größe", "ÜBER", "маленький", "Wert", "γειά", "σου"],
+        );
+
+        assert_eq!(
+            get_unicode_words("    嶲01934 嶲A 嶲a ña٣ ")[..],
+            ["嶲", "01934", "嶲A", "嶲a", "ña", "٣"],
+        );
+
+        // combining marks continue the word
+        assert_eq!(
+            get_unicode_words("n\u{303}a e\u{301}")[..],
+            ["n\u{303}a", "e\u{301}"]
+        );
+    }
+
+    fn get_words_with(text: &str, options: CaseOptions) -> ArrayVec<&str, 20> {
+        collect_words(text, WordIterator::new(text.as_bytes(), options))
+    }
+
+    #[test]
+    fn test_word_iter_options() {
+        let grouped = CaseOptions::NEW.set_acronyms(Acronyms::Grouped);
+        let split = CaseOptions::NEW.set_acronyms(Acronyms::Split);
+        let no_digits = CaseOptions::NEW.set_digit_boundaries(false);
+
+        let text = "HTTPServer2Go ABC aBCd";
+        assert_eq!(
+            get_words_with(text, CaseOptions::NEW)[..],
+            ["HTTPServer", "2", "Go", "ABC", "a", "BCd"],
+        );
+        assert_eq!(
+            get_words_with(text, grouped)[..],
+            ["HTTP", "Server", "2", "Go", "ABC", "a", "B", "Cd"],
+        );
+        assert_eq!(
+            get_words_with(text, split)[..],
+            ["H", "T", "T", "P", "Server", "2", "Go", "A", "B", "C", "a", "B", "Cd"],
+        );
+        assert_eq!(
+            get_words_with(text, no_digits)[..],
+            ["HTTPServer2", "Go", "ABC", "a", "BCd"],
+        );
+        assert_eq!(
+            get_words_with("100foo v2 12 ñ3", no_digits)[..],
+            ["100foo", "v2", "12", "ñ3"],
+        );
+
+        let kept = CaseOptions::NEW.set_kept_punctuation("._");
+        assert_eq!(
+            get_words_with("_foo.bar-baz v1.2 .", kept)[..],
+            ["_foo.bar", "baz", "v", "1.2", "."],
+        );
+        assert_eq!(
+            get_words_with("_foo.bar-baz v1.2", kept.set_digit_boundaries(false))[..],
+            ["_foo.bar", "baz", "v1.2"],
+        );
+
+        assert_eq!(
+            collect_words(
+                "ÜBERSchön ÉTÉ٣",
+                WordIterator::new_unicode("ÜBERSchön ÉTÉ٣".as_bytes(), grouped),
+            )[..],
+            ["ÜBER", "Schön", "ÉTÉ", "٣"],
+        );
+    }
 }
@@ -17,6 +17,32 @@ mod str_split;
 #[cfg(feature = "rust_1_64")]
 pub use str_split::{SplitInput, SplitInputConv};
 
+mod str_trim;
+pub use str_trim::{str_trim, str_trim_end, str_trim_start, TrimInput, TrimInputConv};
+
+mod str_join;
+pub use str_join::{JoinInput, JoinInputConv, StrSliceConv};
+
+mod str_find;
+pub use str_find::{FindInput, FindInputConv};
+
+mod str_escape;
+pub use str_escape::{Escape, EscapeInput};
+
+#[cfg(feature = "fmt")]
+pub(crate) use str_escape::{escape_byte, escaped_len};
+
+mod byte_encoding;
+pub use byte_encoding::{DecodeArgs, DecodeValidity, EncodeInput, Encoding, EncodingInputConv};
+
+mod str_parse;
+pub use str_parse::{
+    parse_bool_range, parse_int_range, ParseBoolArgs, ParseIntArgs, ParseValidity, StrParseInt,
+};
+
+mod str_scan;
+pub use str_scan::{assert_scan, scan_match, ScanMatch, ScanPiece};
+
 mod pattern;
 
 use pattern::{Pattern, PatternCtor, PatternNorm};
@@ -43,34 +69,51 @@ mod ascii_byte {
 }
 pub use ascii_byte::AsciiByte;
 
-// copied from the konst crate, if that implementation is wrong, this needs to be fixed
+// Finds the first instance of `right` in `left` that starts at or after `from`,
+// returning the index where it starts.
 const fn bytes_find(left: &[u8], right: &[u8], from: usize) -> Option<usize> {
-    let mut matching = right;
-
-    __for_range! {i in from..left.len() =>
-        match matching {
-            [mb, m_rem @ ..] => {
-                let b = left[i];
-
-                matching = if b == *mb {
-                    m_rem
-                } else {
-                    match right {
-                        // For when the string is "lawlawn" and we are trying to find "lawn"
-                        [mb2, m_rem2 @ ..] if b == *mb2 => m_rem2,
-                        _ => right,
-                    }
-                };
-            }
-            [] => {
-                return Some(i - right.len())
-            }
+    let mut start = from;
+
+    while start + right.len() <= left.len() {
+        if bytes_eq_at(left, right, start) {
+            return Some(start);
+        }
+        start += 1;
+    }
+
+    None
+}
+
+// Finds the last instance of `right` in `left`, returning the index where it starts.
+const fn bytes_rfind(left: &[u8], right: &[u8]) -> Option<usize> {
+    let mut end = left.len();
+
+    while end >= right.len() {
+        let start = end - right.len();
+        if bytes_eq_at(left, right, start) {
+            return Some(start);
         }
+        end -= 1;
     }
 
-    if matching.is_empty() {
-        Some(left.len() - right.len())
-    } else {
-        None
+    None
+}
+
+const fn bytes_starts_with(bytes: &[u8], prefix: &[u8]) -> bool {
+    bytes.len() >= prefix.len() && bytes_eq_at(bytes, prefix, 0)
+}
+
+const fn bytes_ends_with(bytes: &[u8], suffix: &[u8]) -> bool {
+    bytes.len() >= suffix.len() && bytes_eq_at(bytes, suffix, bytes.len() - suffix.len())
+}
+
+// Whether `right` is in `left` at the `offset` index,
+// requires `offset + right.len() <= left.len()`.
+const fn bytes_eq_at(left: &[u8], right: &[u8], offset: usize) -> bool {
+    __for_range! {i in 0..right.len() =>
+        if left[offset + i] != right[i] {
+            return false;
+        }
     }
+    true
 }
@@ -0,0 +1,355 @@
+/// Converts the argument of the encoding/decoding macros into a byte slice.
+pub struct EncodingInputConv<T>(pub T);
+
+impl EncodingInputConv<&'static str> {
+    pub const fn conv(self) -> &'static [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl EncodingInputConv<&'static [u8]> {
+    pub const fn conv(self) -> &'static [u8] {
+        self.0
+    }
+}
+
+impl<const N: usize> EncodingInputConv<&'static [u8; N]> {
+    pub const fn conv(self) -> &'static [u8] {
+        self.0
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum Encoding {
+    Hex,
+    Base64,
+}
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+const BASE64_PADDING: u8 = b'=';
+
+////////////////////////////////////////////////////////////////////////////////
+
+pub struct EncodeInput {
+    pub encoding: Encoding,
+    pub bytes: &'static [u8],
+}
+
+impl EncodeInput {
+    pub const fn encoded_length(&self) -> usize {
+        let len = self.bytes.len();
+        match self.encoding {
+            Encoding::Hex => len * 2,
+            Encoding::Base64 => (len + 2) / 3 * 4,
+        }
+    }
+
+    pub const fn encode<const L: usize>(&self) -> [u8; L] {
+        let bytes = self.bytes;
+        let mut out = [0u8; L];
+
+        match self.encoding {
+            Encoding::Hex => {
+                __for_range! {i in 0..bytes.len() =>
+                    out[i * 2] = HEX_DIGITS[(bytes[i] >> 4) as usize];
+                    out[i * 2 + 1] = HEX_DIGITS[(bytes[i] & 0xF) as usize];
+                }
+            }
+            Encoding::Base64 => {
+                let mut i = 0;
+                let mut o = 0;
+                while i < bytes.len() {
+                    let rem = bytes.len() - i;
+                    let b0 = bytes[i] as u32;
+                    let b1 = if rem > 1 { bytes[i + 1] as u32 } else { 0 };
+                    let b2 = if rem > 2 { bytes[i + 2] as u32 } else { 0 };
+                    let group = (b0 << 16) | (b1 << 8) | b2;
+
+                    out[o] = BASE64_CHARS[(group >> 18) as usize & 0x3F];
+                    out[o + 1] = BASE64_CHARS[(group >> 12) as usize & 0x3F];
+                    out[o + 2] = if rem > 1 {
+                        BASE64_CHARS[(group >> 6) as usize & 0x3F]
+                    } else {
+                        BASE64_PADDING
+                    };
+                    out[o + 3] = if rem > 2 {
+                        BASE64_CHARS[group as usize & 0x3F]
+                    } else {
+                        BASE64_PADDING
+                    };
+
+                    i += 3;
+                    o += 4;
+                }
+            }
+        }
+
+        out
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum DecodeValidity {
+    Valid,
+    /// The byte at this offset isn't a valid hex digit.
+    InvalidHexDigit(usize),
+    /// The hex input has this (odd) length.
+    OddHexLength(usize),
+    /// The byte at this offset isn't a valid base64 character.
+    InvalidBase64Char(usize),
+    /// The byte at this offset is in (or after) the base64 padding,
+    /// and isn't valid padding.
+    InvalidBase64Padding(usize),
+    /// The base64 input has this length, which can't be decoded.
+    InvalidBase64Length(usize),
+    /// The last base64 character (at this offset) has non-zero unused bits.
+    NonCanonicalBase64(usize),
+}
+
+impl DecodeValidity {
+    pub const fn is_valid(self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    pub const fn assert_valid(self) {
+        match self {
+            Self::Valid => (),
+            Self::InvalidHexDigit(offset) => [/* invalid hex digit at this byte offset */][offset],
+            Self::OddHexLength(len) => [/* hex input has an odd length */][len],
+            Self::InvalidBase64Char(offset) => {
+                [/* invalid base64 character at this byte offset */][offset]
+            }
+            Self::InvalidBase64Padding(offset) => {
+                [/* invalid base64 padding at this byte offset */][offset]
+            }
+            Self::InvalidBase64Length(len) => [/* base64 input has an invalid length */][len],
+            Self::NonCanonicalBase64(offset) => {
+                [/* base64 character has non-zero trailing bits at this byte offset */][offset]
+            }
+        }
+    }
+}
+
+pub struct DecodeArgs {
+    pub encoding: Encoding,
+    pub bytes: &'static [u8],
+    pub validity: DecodeValidity,
+    /// The length of the decoded bytes, `0` if the input is invalid.
+    pub decoded_len: usize,
+    /// The amount of base64 characters, excluding padding.
+    data_len: usize,
+}
+
+#[allow(non_snake_case)]
+pub const fn DecodeArgs(encoding: Encoding, bytes: &'static [u8]) -> DecodeArgs {
+    let (validity, data_len, decoded_len) = match encoding {
+        Encoding::Hex => validate_hex(bytes),
+        Encoding::Base64 => validate_base64(bytes),
+    };
+
+    let decoded_len = if validity.is_valid() { decoded_len } else { 0 };
+
+    DecodeArgs {
+        encoding,
+        bytes,
+        validity,
+        decoded_len,
+        data_len,
+    }
+}
+
+impl DecodeArgs {
+    pub const fn assert_valid(&self) {
+        self.validity.assert_valid()
+    }
+
+    pub const fn decode<const L: usize>(&self) -> [u8; L] {
+        let bytes = self.bytes;
+        let mut out = [0u8; L];
+
+        if !self.validity.is_valid() {
+            return out;
+        }
+
+        match self.encoding {
+            Encoding::Hex => {
+                __for_range! {i in 0..L =>
+                    out[i] = (hex_value(bytes[i * 2]) << 4) | hex_value(bytes[i * 2 + 1]);
+                }
+            }
+            Encoding::Base64 => {
+                let mut i = 0;
+                let mut o = 0;
+                while i < self.data_len {
+                    let rem = self.data_len - i;
+                    let mut group = 0u32;
+                    __for_range! {j in 0..4 =>
+                        let value = if j < rem { base64_value(bytes[i + j]) } else { 0 };
+                        group = (group << 6) | value as u32;
+                    }
+
+                    out[o] = (group >> 16) as u8;
+                    if rem > 2 {
+                        out[o + 1] = (group >> 8) as u8;
+                    }
+                    if rem > 3 {
+                        out[o + 2] = group as u8;
+                    }
+
+                    i += 4;
+                    o += 3;
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// The value of a hex digit, `0xFF` if `b` isn't one.
+const fn hex_value(b: u8) -> u8 {
+    match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'f' => b - b'a' + 10,
+        b'A'..=b'F' => b - b'A' + 10,
+        _ => 0xFF,
+    }
+}
+
+/// The value of a base64 character, `0xFF` if `b` isn't one.
+const fn base64_value(b: u8) -> u8 {
+    match b {
+        b'A'..=b'Z' => b - b'A',
+        b'a'..=b'z' => b - b'a' + 26,
+        b'0'..=b'9' => b - b'0' + 52,
+        b'+' => 62,
+        b'/' => 63,
+        _ => 0xFF,
+    }
+}
+
+/// Returns the validity, the length of the data part, and the decoded length.
+const fn validate_hex(bytes: &[u8]) -> (DecodeValidity, usize, usize) {
+    __for_range! {i in 0..bytes.len() =>
+        if hex_value(bytes[i]) == 0xFF {
+            return (DecodeValidity::InvalidHexDigit(i), 0, 0);
+        }
+    }
+
+    if bytes.len() % 2 == 1 {
+        return (DecodeValidity::OddHexLength(bytes.len()), 0, 0);
+    }
+
+    (DecodeValidity::Valid, bytes.len(), bytes.len() / 2)
+}
+
+/// Returns the validity, the length of the data part, and the decoded length.
+///
+/// This accepts base64 both with and without padding.
+const fn validate_base64(bytes: &[u8]) -> (DecodeValidity, usize, usize) {
+    let len = bytes.len();
+
+    let mut data_len = 0;
+    while data_len < len && bytes[data_len] != BASE64_PADDING {
+        if base64_value(bytes[data_len]) == 0xFF {
+            return (DecodeValidity::InvalidBase64Char(data_len), 0, 0);
+        }
+        data_len += 1;
+    }
+
+    let padding = len - data_len;
+    let rem = data_len % 4;
+
+    let expected_padding = (4 - rem) % 4;
+
+    __for_range! {i in data_len..len =>
+        if i >= data_len + expected_padding || bytes[i] != BASE64_PADDING {
+            return (DecodeValidity::InvalidBase64Padding(i), 0, 0);
+        }
+    }
+
+    if padding != 0 && padding < expected_padding {
+        return (DecodeValidity::InvalidBase64Length(len), 0, 0);
+    }
+
+    let (tail_len, unused_bits_mask) = match rem {
+        0 => (0, 0),
+        1 => return (DecodeValidity::InvalidBase64Length(len), 0, 0),
+        2 => (1, 0b1111),
+        _ => (2, 0b11),
+    };
+
+    if rem != 0 && base64_value(bytes[data_len - 1]) & unused_bits_mask != 0 {
+        return (DecodeValidity::NonCanonicalBase64(data_len - 1), 0, 0);
+    }
+
+    (DecodeValidity::Valid, data_len, data_len / 4 * 3 + tail_len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validity(encoding: Encoding, input: &'static str) -> DecodeValidity {
+        DecodeArgs(encoding, input.as_bytes()).validity
+    }
+
+    #[test]
+    fn hex_validity() {
+        use DecodeValidity as DV;
+
+        assert_eq!(validity(Encoding::Hex, ""), DV::Valid);
+        assert_eq!(validity(Encoding::Hex, "00fFaA"), DV::Valid);
+        assert_eq!(validity(Encoding::Hex, "0"), DV::OddHexLength(1));
+        assert_eq!(validity(Encoding::Hex, "abc"), DV::OddHexLength(3));
+        assert_eq!(validity(Encoding::Hex, "ag"), DV::InvalidHexDigit(1));
+        assert_eq!(validity(Encoding::Hex, "00 1"), DV::InvalidHexDigit(2));
+    }
+
+    #[test]
+    fn base64_validity() {
+        use DecodeValidity as DV;
+
+        for valid in ["", "QQ", "QQ==", "QUI", "QUI=", "QUJD", "QUJDRA=="] {
+            assert_eq!(validity(Encoding::Base64, valid), DV::Valid, "{:?}", valid);
+        }
+
+        assert_eq!(validity(Encoding::Base64, "Q"), DV::InvalidBase64Length(1));
+        assert_eq!(
+            validity(Encoding::Base64, "QUJDR"),
+            DV::InvalidBase64Length(5)
+        );
+        assert_eq!(
+            validity(Encoding::Base64, "QQ="),
+            DV::InvalidBase64Length(3)
+        );
+        assert_eq!(
+            validity(Encoding::Base64, "QUJD="),
+            DV::InvalidBase64Padding(4)
+        );
+        assert_eq!(
+            validity(Encoding::Base64, "QUI=="),
+            DV::InvalidBase64Padding(4)
+        );
+        assert_eq!(
+            validity(Encoding::Base64, "QQ==QQ=="),
+            DV::InvalidBase64Padding(4)
+        );
+        assert_eq!(
+            validity(Encoding::Base64, "QQ=A"),
+            DV::InvalidBase64Padding(3)
+        );
+        assert_eq!(validity(Encoding::Base64, "QU-D"), DV::InvalidBase64Char(2));
+        assert_eq!(
+            validity(Encoding::Base64, "QR=="),
+            DV::NonCanonicalBase64(1)
+        );
+        assert_eq!(validity(Encoding::Base64, "QUJ"), DV::NonCanonicalBase64(2));
+    }
+}
@@ -0,0 +1,193 @@
+/// How [`str_escape`] (and the `*_escaped` methods of
+/// `Formatter` and `StrWriterMut`) escape a string.
+///
+/// None of these add quotes around the string,
+/// the escaped string is meant to be written between the quotes
+/// (or in the position) that the escaping is for.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{Escape, str_escape};
+///
+/// const S: &str = "<a href='/'>ñ & \"b\"</a>\n";
+///
+/// assert_eq!(str_escape!(Escape::Json, S), r#"<a href='/'>ñ & \"b\"</a>\n"#);
+/// assert_eq!(str_escape!(Escape::C, S), r#"<a href='/'>\303\261 & \"b\"</a>\n"#);
+/// assert_eq!(
+///     str_escape!(Escape::Html, S),
+///     "&lt;a href=&#39;/&#39;&gt;ñ &amp; &quot;b&quot;&lt;/a&gt;\n",
+/// );
+/// assert_eq!(str_escape!(Escape::Shell, S), "<a href='\\''/'\\''>ñ & \"b\"</a>\n");
+/// assert_eq!(
+///     str_escape!(Escape::Percent, S),
+///     "%3Ca%20href%3D%27%2F%27%3E%C3%B1%20%26%20%22b%22%3C%2Fa%3E%0A",
+/// );
+///
+/// ```
+///
+/// [`str_escape`]: ./macro.str_escape.html
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Escape {
+    /// Escapes the string for use inside a JSON string.
+    ///
+    /// This escapes `"` and `\` with a backslash,
+    /// and control characters with `\n`/`\r`/`\t`/`\b`/`\f` or `\u00XX` escapes.
+    Json,
+    /// Escapes the string for use inside a C string literal.
+    ///
+    /// This escapes `"` and `\` with a backslash,
+    /// control characters with `\n`/`\r`/`\t`/`\a`/`\b`/`\v`/`\f` escapes,
+    /// and every other non-printable or non-ascii byte with a 3-digit octal escape
+    /// (eg: `ñ` is escaped as `\303\261`).
+    C,
+    /// Escapes the string for use in HTML text or a quoted HTML attribute.
+    ///
+    /// This escapes `&`, `<`, `>`, `"`, and `'` as character references.
+    Html,
+    /// Escapes the string for use inside a POSIX shell single-quoted string.
+    ///
+    /// This escapes `'` as `'\''`.
+    Shell,
+    /// Percent-encodes the string, for use in URLs.
+    ///
+    /// This escapes every byte other than the unreserved characters of RFC 3986
+    /// (ascii letters, digits, `-`, `.`, `_`, and `~`) as `%XX`.
+    Percent,
+}
+
+impl Escape {
+    #[cfg(feature = "fmt")]
+    /// Whether this escapes the bytes of non-ascii chars individually,
+    /// instead of leaving them unchanged.
+    pub(crate) const fn escapes_non_ascii(self) -> bool {
+        matches!(self, Escape::C | Escape::Percent)
+    }
+}
+
+/// The escaped form of a byte, only the first `len` bytes of `encoded` are used.
+#[derive(Copy, Clone)]
+pub(crate) struct EscapedByte {
+    pub(crate) encoded: [u8; 6],
+    pub(crate) len: usize,
+}
+
+const UPPER_HEX: &[u8; 16] = b"0123456789ABCDEF";
+const LOWER_HEX: &[u8; 16] = b"0123456789abcdef";
+
+const fn escaped(encoded: &[u8]) -> EscapedByte {
+    let mut out = EscapedByte {
+        encoded: [0; 6],
+        len: encoded.len(),
+    };
+    let mut i = 0;
+    while i < encoded.len() {
+        out.encoded[i] = encoded[i];
+        i += 1;
+    }
+    out
+}
+
+pub(crate) const fn escape_byte(escape: Escape, b: u8) -> EscapedByte {
+    match escape {
+        Escape::Json => match b {
+            b'"' => escaped(b"\\\""),
+            b'\\' => escaped(b"\\\\"),
+            b'\n' => escaped(b"\\n"),
+            b'\r' => escaped(b"\\r"),
+            b'\t' => escaped(b"\\t"),
+            0x08 => escaped(b"\\b"),
+            0x0C => escaped(b"\\f"),
+            0..=0x1F => escaped(&[
+                b'\\',
+                b'u',
+                b'0',
+                b'0',
+                LOWER_HEX[(b >> 4) as usize],
+                LOWER_HEX[(b & 0xF) as usize],
+            ]),
+            _ => escaped(&[b]),
+        },
+        Escape::C => match b {
+            b'"' => escaped(b"\\\""),
+            b'\\' => escaped(b"\\\\"),
+            b'\n' => escaped(b"\\n"),
+            b'\r' => escaped(b"\\r"),
+            b'\t' => escaped(b"\\t"),
+            0x07 => escaped(b"\\a"),
+            0x08 => escaped(b"\\b"),
+            0x0B => escaped(b"\\v"),
+            0x0C => escaped(b"\\f"),
+            b' '..=b'~' => escaped(&[b]),
+            _ => escaped(&[
+                b'\\',
+                b'0' + (b >> 6),
+                b'0' + ((b >> 3) & 0o7),
+                b'0' + (b & 0o7),
+            ]),
+        },
+        Escape::Html => match b {
+            b'&' => escaped(b"&amp;"),
+            b'<' => escaped(b"&lt;"),
+            b'>' => escaped(b"&gt;"),
+            b'"' => escaped(b"&quot;"),
+            b'\'' => escaped(b"&#39;"),
+            _ => escaped(&[b]),
+        },
+        Escape::Shell => match b {
+            b'\'' => escaped(b"'\\''"),
+            _ => escaped(&[b]),
+        },
+        Escape::Percent => match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => escaped(&[b]),
+            _ => escaped(&[
+                b'%',
+                UPPER_HEX[(b >> 4) as usize],
+                UPPER_HEX[(b & 0xF) as usize],
+            ]),
+        },
+    }
+}
+
+/// The length of `bytes[start..end]` after it's escaped.
+pub(crate) const fn escaped_len(
+    escape: Escape,
+    bytes: &[u8],
+    mut start: usize,
+    end: usize,
+) -> usize {
+    let mut len = 0;
+    while start < end {
+        len += escape_byte(escape, bytes[start]).len;
+        start += 1;
+    }
+    len
+}
+
+pub struct EscapeInput {
+    pub escape: Escape,
+    pub str: &'static str,
+}
+
+impl EscapeInput {
+    pub const fn escaped_length(&self) -> usize {
+        escaped_len(self.escape, self.str.as_bytes(), 0, self.str.len())
+    }
+
+    pub const fn escape<const L: usize>(&self) -> [u8; L] {
+        let bytes = self.str.as_bytes();
+        let mut out = [0u8; L];
+        let mut out_i = 0;
+
+        iter_copy_slice! {b in bytes =>
+            let escaped = escape_byte(self.escape, b);
+            __for_range! {i in 0..escaped.len =>
+                out[out_i] = escaped.encoded[i];
+                out_i += 1;
+            }
+        }
+
+        out
+    }
+}
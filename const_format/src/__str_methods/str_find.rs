@@ -0,0 +1,89 @@
+use super::{
+    bytes_ends_with, bytes_find, bytes_rfind, bytes_starts_with, Pattern, PatternCtor, PatternNorm,
+};
+
+pub struct FindInputConv<T>(pub &'static str, pub T);
+
+macro_rules! ctor {
+    ($ty:ty) => {
+        impl FindInputConv<$ty> {
+            pub const fn conv(self) -> FindInput {
+                FindInput {
+                    str: self.0,
+                    pattern: PatternCtor(self.1).conv(),
+                }
+            }
+        }
+    };
+}
+
+ctor! {u8}
+ctor! {&'static str}
+ctor! {char}
+
+pub struct FindInput {
+    str: &'static str,
+    pattern: Pattern,
+}
+
+impl FindInput {
+    pub const fn find(&self) -> Option<usize> {
+        let str = self.str.as_bytes();
+        match self.pattern.normalize() {
+            PatternNorm::AsciiByte(byte) => {
+                let byte = byte.get();
+                __for_range! {i in 0..str.len() =>
+                    if str[i] == byte {
+                        return Some(i);
+                    }
+                }
+                None
+            }
+            PatternNorm::Str(pattern) => {
+                if pattern.is_empty() {
+                    Some(0)
+                } else {
+                    bytes_find(str, pattern, 0)
+                }
+            }
+        }
+    }
+
+    pub const fn rfind(&self) -> Option<usize> {
+        let str = self.str.as_bytes();
+        match self.pattern.normalize() {
+            PatternNorm::AsciiByte(byte) => {
+                let byte = byte.get();
+                let mut i = str.len();
+                while i != 0 {
+                    i -= 1;
+                    if str[i] == byte {
+                        return Some(i);
+                    }
+                }
+                None
+            }
+            PatternNorm::Str(pattern) => bytes_rfind(str, pattern),
+        }
+    }
+
+    pub const fn contains(&self) -> bool {
+        self.find().is_some()
+    }
+
+    pub const fn starts_with(&self) -> bool {
+        let str = self.str.as_bytes();
+        match self.pattern.normalize() {
+            PatternNorm::AsciiByte(byte) => matches!(str, [b, ..] if *b == byte.get()),
+            PatternNorm::Str(pattern) => bytes_starts_with(str, pattern),
+        }
+    }
+
+    pub const fn ends_with(&self) -> bool {
+        let str = self.str.as_bytes();
+        match self.pattern.normalize() {
+            PatternNorm::AsciiByte(byte) => matches!(str, [.., b] if *b == byte.get()),
+            PatternNorm::Str(pattern) => bytes_ends_with(str, pattern),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use super::{Pattern, PatternCtor, PatternNorm};
+
+/// Converts the `strings` argument of `str_join` into a value that,
+/// when referenced, coerces to a `&'static [&'static str]`.
+pub struct StrSliceConv<T>(pub T);
+
+impl StrSliceConv<&'static [&'static str]> {
+    pub const fn conv(self) -> &'static [&'static str] {
+        self.0
+    }
+}
+
+impl<const N: usize> StrSliceConv<&'static [&'static str; N]> {
+    pub const fn conv(self) -> &'static [&'static str] {
+        self.0
+    }
+}
+
+impl<const N: usize> StrSliceConv<[&'static str; N]> {
+    pub const fn conv(self) -> [&'static str; N] {
+        self.0
+    }
+}
+
+pub struct JoinInputConv<T>(pub &'static [&'static str], pub T);
+
+macro_rules! ctor {
+    ($ty:ty) => {
+        impl JoinInputConv<$ty> {
+            pub const fn conv(self) -> JoinInput {
+                JoinInput {
+                    strings: self.0,
+                    separator: PatternCtor(self.1).conv(),
+                }
+            }
+        }
+    };
+}
+
+ctor! {u8}
+ctor! {&'static str}
+ctor! {char}
+
+pub struct JoinInput {
+    strings: &'static [&'static str],
+    separator: Pattern,
+}
+
+impl JoinInput {
+    pub const fn join_length(&self) -> usize {
+        let sep_len = match self.separator.normalize() {
+            PatternNorm::AsciiByte(_) => 1,
+            PatternNorm::Str(sep) => sep.len(),
+        };
+
+        let mut out_len = 0;
+        iter_copy_slice! {str in self.strings =>
+            out_len += str.len();
+        }
+
+        if let [_, rem @ ..] = self.strings {
+            out_len += rem.len() * sep_len;
+        }
+
+        out_len
+    }
+
+    pub const fn join<const L: usize>(&self) -> [u8; L] {
+        let mut out = [0u8; L];
+        let mut out_i = 0;
+
+        macro_rules! write_bytes {
+            ($bytes:expr) => {
+                iter_copy_slice! {b in $bytes =>
+                    out[out_i] = b;
+                    out_i += 1;
+                }
+            };
+        }
+
+        let separator = self.separator.normalize();
+
+        __for_range! {i in 0..self.strings.len() =>
+            if i != 0 {
+                match separator {
+                    PatternNorm::AsciiByte(byte) => {
+                        out[out_i] = byte.get();
+                        out_i += 1;
+                    }
+                    PatternNorm::Str(sep) => write_bytes!(sep),
+                }
+            }
+            write_bytes!(self.strings[i].as_bytes());
+        }
+
+        out
+    }
+}
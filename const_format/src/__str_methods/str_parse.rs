@@ -0,0 +1,344 @@
+/// The integer types that `str_parse` can parse into.
+pub trait StrParseInt {
+    /// The maximum value of the type.
+    const MAX_POSITIVE: u128;
+    /// The magnitude of the minimum value of the type.
+    const MAX_NEGATIVE: u128;
+}
+
+macro_rules! impl_str_parse_int {
+    (unsigned: $($unsigned:ty),*; signed: $($signed:ty),*) => {
+        $(
+            impl StrParseInt for $unsigned {
+                const MAX_POSITIVE: u128 = <$unsigned>::MAX as u128;
+                const MAX_NEGATIVE: u128 = 0;
+            }
+        )*
+        $(
+            impl StrParseInt for $signed {
+                const MAX_POSITIVE: u128 = <$signed>::MAX as u128;
+                const MAX_NEGATIVE: u128 = <$signed>::MAX as u128 + 1;
+            }
+        )*
+    };
+}
+
+impl_str_parse_int! {
+    unsigned: u8, u16, u32, u64, u128, usize;
+    signed: i8, i16, i32, i64, i128, isize
+}
+
+#[derive(Copy, Clone)]
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub enum ParseValidity {
+    Valid,
+    /// The radix argument, which isn't between 2 and 36.
+    InvalidRadix(usize),
+    /// The input has no digits, they were expected at this offset.
+    NoDigits(usize),
+    /// The byte at this offset isn't a valid digit.
+    InvalidDigit(usize),
+    /// The number stops fitting in the type at the digit at this offset.
+    OutOfRange(usize),
+    /// The input stops matching `true` and `false` at this offset.
+    InvalidBool(usize),
+}
+
+impl ParseValidity {
+    pub const fn is_valid(self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    /// The byte offset of the error, `0` if this is `Valid` or `InvalidRadix`.
+    pub const fn offset(self) -> usize {
+        match self {
+            Self::Valid | Self::InvalidRadix(_) => 0,
+            Self::NoDigits(offset)
+            | Self::InvalidDigit(offset)
+            | Self::OutOfRange(offset)
+            | Self::InvalidBool(offset) => offset,
+        }
+    }
+
+    pub const fn description(self) -> &'static str {
+        match self {
+            Self::Valid => "",
+            Self::InvalidRadix(_) => "radix must be between 2 and 36",
+            Self::NoDigits(_) => "expected digits",
+            Self::InvalidDigit(_) => "invalid digit",
+            Self::OutOfRange(_) => "number is out of range for the type",
+            Self::InvalidBool(_) => "expected `true` or `false`",
+        }
+    }
+
+    pub const fn assert_valid(self) {
+        match self {
+            Self::Valid => (),
+            Self::InvalidRadix(radix) => [/* radix must be between 2 and 36 */][radix],
+            Self::NoDigits(offset) => [/* expected digits at this byte offset */][offset],
+            Self::InvalidDigit(offset) => [/* invalid digit at this byte offset */][offset],
+            Self::OutOfRange(offset) => {
+                [/* number is out of range for the type, at this byte offset */][offset]
+            }
+            Self::InvalidBool(offset) => {
+                [/* expected `true` or `false`, mismatch at this byte offset */][offset]
+            }
+        }
+    }
+}
+
+pub struct ParseIntArgs {
+    pub validity: ParseValidity,
+    /// The absolute value of the parsed number.
+    pub magnitude: u128,
+    pub negative: bool,
+}
+
+/// Parses an integer from `str`,
+/// `max_positive`/`max_negative` are the largest magnitudes of
+/// positive/negative numbers that the integer type can represent.
+///
+/// If `radix` is `None`, this parses decimal,
+/// or the radix of the `0x`/`0o`/`0b` prefix.
+#[allow(non_snake_case)]
+pub const fn ParseIntArgs(
+    str: &'static str,
+    radix: Option<u32>,
+    max_positive: u128,
+    max_negative: u128,
+) -> ParseIntArgs {
+    parse_int_range(
+        str.as_bytes(),
+        0,
+        str.len(),
+        radix,
+        max_positive,
+        max_negative,
+    )
+}
+
+/// Parses an integer from `bytes[start..end]`,
+/// the offsets in the returned `ParseValidity` are relative to the start of `bytes`.
+pub const fn parse_int_range(
+    bytes: &[u8],
+    start: usize,
+    end: usize,
+    radix: Option<u32>,
+    max_positive: u128,
+    max_negative: u128,
+) -> ParseIntArgs {
+    const fn error(validity: ParseValidity) -> ParseIntArgs {
+        ParseIntArgs {
+            validity,
+            magnitude: 0,
+            negative: false,
+        }
+    }
+
+    let (negative, mut i) = if start < end && (bytes[start] == b'-' || bytes[start] == b'+') {
+        (bytes[start] == b'-', start + 1)
+    } else {
+        (false, start)
+    };
+
+    if negative && max_negative == 0 {
+        return error(ParseValidity::InvalidDigit(start));
+    }
+
+    let mut used_radix = match radix {
+        Some(radix) if radix < 2 || radix > 36 => {
+            return error(ParseValidity::InvalidRadix(radix as usize))
+        }
+        Some(radix) => radix,
+        None => 10,
+    };
+
+    if i + 1 < end && bytes[i] == b'0' {
+        let prefix_radix = match bytes[i + 1] {
+            b'x' => 16,
+            b'o' => 8,
+            b'b' => 2,
+            _ => 0,
+        };
+
+        // with an explicit radix, only its own prefix is skipped,
+        // because `0b` is the start of a hexadecimal number.
+        let skip_prefix = match radix {
+            Some(radix) => radix == prefix_radix,
+            None => prefix_radix != 0,
+        };
+
+        if skip_prefix {
+            used_radix = prefix_radix;
+            i += 2;
+        }
+    }
+
+    let limit = if negative { max_negative } else { max_positive };
+    let mut magnitude = 0u128;
+    let mut has_digits = false;
+
+    while i < end {
+        let b = bytes[i];
+        if b == b'_' && has_digits {
+            i += 1;
+            continue;
+        }
+
+        let digit = digit_value(b);
+        if digit >= used_radix {
+            return error(ParseValidity::InvalidDigit(i));
+        }
+
+        magnitude = match magnitude.checked_mul(used_radix as u128) {
+            Some(x) => match x.checked_add(digit as u128) {
+                Some(x) if x <= limit => x,
+                _ => return error(ParseValidity::OutOfRange(i)),
+            },
+            None => return error(ParseValidity::OutOfRange(i)),
+        };
+        has_digits = true;
+        i += 1;
+    }
+
+    if !has_digits {
+        return error(ParseValidity::NoDigits(end));
+    }
+
+    ParseIntArgs {
+        validity: ParseValidity::Valid,
+        magnitude,
+        negative,
+    }
+}
+
+/// The value of an ascii digit or letter, `u32::MAX` for any other byte.
+const fn digit_value(b: u8) -> u32 {
+    (match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'z' => b - b'a' + 10,
+        b'A'..=b'Z' => b - b'A' + 10,
+        _ => return u32::MAX,
+    }) as u32
+}
+
+pub struct ParseBoolArgs {
+    pub validity: ParseValidity,
+    pub value: bool,
+}
+
+#[allow(non_snake_case)]
+pub const fn ParseBoolArgs(str: &'static str) -> ParseBoolArgs {
+    parse_bool_range(str.as_bytes(), 0, str.len())
+}
+
+/// Parses a `bool` from `bytes[start..end]`,
+/// the offsets in the returned `ParseValidity` are relative to the start of `bytes`.
+pub const fn parse_bool_range(bytes: &[u8], start: usize, end: usize) -> ParseBoolArgs {
+    let len = end - start;
+    let true_len = common_prefix_len(bytes, start, end, b"true");
+    let false_len = common_prefix_len(bytes, start, end, b"false");
+
+    let (validity, value) = if true_len == 4 && len == 4 {
+        (ParseValidity::Valid, true)
+    } else if false_len == 5 && len == 5 {
+        (ParseValidity::Valid, false)
+    } else if true_len > false_len {
+        (ParseValidity::InvalidBool(start + true_len), false)
+    } else {
+        (ParseValidity::InvalidBool(start + false_len), false)
+    };
+
+    ParseBoolArgs { validity, value }
+}
+
+/// The length of the common prefix of `bytes[start..end]` and `word`.
+const fn common_prefix_len(bytes: &[u8], start: usize, end: usize, word: &[u8]) -> usize {
+    let mut i = 0;
+    while start + i < end && i < word.len() && bytes[start + i] == word[i] {
+        i += 1;
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn int_validity(str: &'static str, radix: Option<u32>) -> ParseValidity {
+        ParseIntArgs(str, radix, i8::MAX_POSITIVE, i8::MAX_NEGATIVE).validity
+    }
+
+    #[test]
+    fn int_validity_errors() {
+        use ParseValidity as PV;
+
+        for valid in [
+            "0", "-128", "+127", "0x7F", "-0x80", "0o17", "0b1_0", "1_2_",
+        ] {
+            assert_eq!(int_validity(valid, None), PV::Valid, "{:?}", valid);
+        }
+
+        assert_eq!(int_validity("", None), PV::NoDigits(0));
+        assert_eq!(int_validity("-", None), PV::NoDigits(1));
+        assert_eq!(int_validity("0x", None), PV::NoDigits(2));
+        assert_eq!(int_validity("0x_", None), PV::InvalidDigit(2));
+        assert_eq!(int_validity("0x_f", None), PV::InvalidDigit(2));
+        assert_eq!(int_validity("0b_1", Some(2)), PV::InvalidDigit(2));
+        assert_eq!(int_validity("_1", None), PV::InvalidDigit(0));
+        assert_eq!(int_validity("-_1", None), PV::InvalidDigit(1));
+        assert_eq!(int_validity("12a", None), PV::InvalidDigit(2));
+        assert_eq!(int_validity(" 1", None), PV::InvalidDigit(0));
+        assert_eq!(int_validity("0b12", None), PV::InvalidDigit(3));
+        assert_eq!(int_validity("0xFF", Some(8)), PV::InvalidDigit(1));
+        assert_eq!(int_validity("128", None), PV::OutOfRange(2));
+        assert_eq!(int_validity("-129", None), PV::OutOfRange(3));
+        assert_eq!(int_validity("1000", None), PV::OutOfRange(3));
+        assert_eq!(int_validity("1", Some(1)), PV::InvalidRadix(1));
+        assert_eq!(int_validity("1", Some(37)), PV::InvalidRadix(37));
+
+        for negative in ["-1", "-0", "-"] {
+            assert_eq!(
+                ParseIntArgs(negative, None, u8::MAX_POSITIVE, u8::MAX_NEGATIVE).validity,
+                PV::InvalidDigit(0),
+                "{:?}",
+                negative,
+            );
+        }
+    }
+
+    #[test]
+    fn range_validity() {
+        use ParseValidity as PV;
+
+        let bytes = b"a=-12,b=0x1g,c=true";
+        let parse_int = |start, end| parse_int_range(bytes, start, end, None, 127, 128);
+
+        assert_eq!(parse_int(2, 5).validity, PV::Valid);
+        assert_eq!(parse_int(2, 5).magnitude, 12);
+        assert!(parse_int(2, 5).negative);
+        assert_eq!(parse_int(8, 12).validity, PV::InvalidDigit(11));
+        assert_eq!(parse_int(8, 10).validity, PV::NoDigits(10));
+        assert_eq!(parse_int(5, 5).validity, PV::NoDigits(5));
+
+        assert_eq!(parse_bool_range(bytes, 15, 19).validity, PV::Valid);
+        assert_eq!(
+            parse_bool_range(bytes, 15, 18).validity,
+            PV::InvalidBool(18)
+        );
+        assert_eq!(parse_bool_range(bytes, 0, 3).validity, PV::InvalidBool(0));
+    }
+
+    #[test]
+    fn bool_validity() {
+        use ParseValidity as PV;
+
+        assert_eq!(ParseBoolArgs("true").validity, PV::Valid);
+        assert_eq!(ParseBoolArgs("false").validity, PV::Valid);
+        assert_eq!(ParseBoolArgs("").validity, PV::InvalidBool(0));
+        assert_eq!(ParseBoolArgs("tru").validity, PV::InvalidBool(3));
+        assert_eq!(ParseBoolArgs("trUe").validity, PV::InvalidBool(2));
+        assert_eq!(ParseBoolArgs("falsey").validity, PV::InvalidBool(5));
+        assert_eq!(ParseBoolArgs("1").validity, PV::InvalidBool(0));
+    }
+}
@@ -0,0 +1,163 @@
+use super::bytes_find;
+
+/// A part of a `str_scan` pattern.
+#[derive(Copy, Clone)]
+pub enum ScanPiece {
+    /// Text that the input must contain at this position.
+    Str(&'static str),
+    /// An argument, which matches everything up to the text that follows it.
+    Arg,
+}
+
+/// The result of matching the input of `str_scan` against its pattern.
+pub struct ScanMatch<const N: usize> {
+    /// The byte offset where each argument starts in the input.
+    pub starts: [usize; N],
+    /// The byte offset where each argument ends in the input.
+    pub ends: [usize; N],
+    pub is_match: bool,
+    /// The byte offset where the input stopped matching the pattern, `0` if it matches.
+    pub offset: usize,
+    /// The text of the pattern that was expected at `offset`,
+    /// empty if the end of the input was expected.
+    pub expected: &'static str,
+}
+
+pub const fn scan_match<const N: usize>(input: &str, pieces: &[ScanPiece]) -> ScanMatch<N> {
+    const fn mismatch<const N: usize>(
+        mut out: ScanMatch<N>,
+        bytes: &[u8],
+        mut offset: usize,
+        expected: &'static str,
+    ) -> ScanMatch<N> {
+        // the offset is used to split the input, so it must be on a char boundary
+        while offset < bytes.len() && (bytes[offset] as i8) < -0x40 {
+            offset -= 1;
+        }
+        out.is_match = false;
+        out.offset = offset;
+        out.expected = expected;
+        out
+    }
+
+    let bytes = input.as_bytes();
+    let mut out = ScanMatch {
+        starts: [0; N],
+        ends: [0; N],
+        is_match: true,
+        offset: 0,
+        expected: "",
+    };
+
+    let mut pos = 0;
+    let mut arg_i = 0;
+
+    __for_range! {piece_i in 0..pieces.len() =>
+        match pieces[piece_i] {
+            ScanPiece::Str(str) => {
+                let str_bytes = str.as_bytes();
+                __for_range! {i in 0..str_bytes.len() =>
+                    if pos + i == bytes.len() || bytes[pos + i] != str_bytes[i] {
+                        return mismatch(out, bytes, pos + i, str);
+                    }
+                }
+                pos += str_bytes.len();
+            }
+            ScanPiece::Arg => {
+                let next = if piece_i + 1 < pieces.len() {
+                    pieces[piece_i + 1]
+                } else {
+                    ScanPiece::Arg
+                };
+                let end = match next {
+                    ScanPiece::Str(next) => match bytes_find(bytes, next.as_bytes(), pos) {
+                        Some(end) => end,
+                        None => return mismatch(out, bytes, bytes.len(), next),
+                    },
+                    // consecutive arguments are rejected by the `str_scan` macro,
+                    // so this is the last argument.
+                    ScanPiece::Arg => bytes.len(),
+                };
+                out.starts[arg_i] = pos;
+                out.ends[arg_i] = end;
+                arg_i += 1;
+                pos = end;
+            }
+        }
+    }
+
+    if pos != bytes.len() {
+        return mismatch(out, bytes, pos, "");
+    }
+
+    out
+}
+
+pub const fn assert_scan(matches: bool, message: &'static str) {
+    if !matches {
+        panic!("{}", message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ScanPiece::{Arg, Str};
+
+    fn ranges<const N: usize>(m: &ScanMatch<N>) -> [(usize, usize); N] {
+        let mut out = [(0, 0); N];
+        for (i, x) in out.iter_mut().enumerate() {
+            *x = (m.starts[i], m.ends[i]);
+        }
+        out
+    }
+
+    #[test]
+    fn matching() {
+        let version = &[Arg, Str("."), Arg, Str("."), Arg];
+
+        let m1 = scan_match::<3>("1.22.333", version);
+        assert!(m1.is_match);
+        assert_eq!(ranges(&m1), [(0, 1), (2, 4), (5, 8)]);
+
+        let m2 = scan_match::<3>("..", version);
+        assert!(m2.is_match);
+        assert_eq!(ranges(&m2), [(0, 0), (1, 1), (2, 2)]);
+
+        let m3 = scan_match::<3>("1.2", version);
+        assert!(!m3.is_match);
+        assert_eq!((m3.offset, m3.expected), (3, "."));
+
+        let key_value = &[Str("key="), Arg, Str(";")];
+
+        let m4 = scan_match::<1>("key=value;", key_value);
+        assert!(m4.is_match);
+        assert_eq!(ranges(&m4), [(4, 9)]);
+
+        let m5 = scan_match::<1>("kez=value;", key_value);
+        assert_eq!((m5.is_match, m5.offset, m5.expected), (false, 2, "key="));
+
+        let m6 = scan_match::<1>("key=value;;", key_value);
+        assert_eq!((m6.is_match, m6.offset, m6.expected), (false, 10, ""));
+
+        let m7 = scan_match::<1>("ke", key_value);
+        assert_eq!((m7.is_match, m7.offset, m7.expected), (false, 2, "key="));
+
+        // separators that overlap their own prefix
+        let m8 = scan_match::<1>("1aaab", &[Arg, Str("aab")]);
+        assert!(m8.is_match);
+        assert_eq!(ranges(&m8), [(0, 2)]);
+
+        let m9 = scan_match::<2>("1abaabaab2", &[Arg, Str("aabaab"), Arg]);
+        assert!(m9.is_match);
+        assert_eq!(ranges(&m9), [(0, 3), (9, 10)]);
+    }
+
+    #[test]
+    fn mismatch_on_char_boundary() {
+        // 'ñ' and 'ó' have the same first byte
+        let m = scan_match::<0>("aó", &[Str("añ")]);
+        assert_eq!((m.is_match, m.offset), (false, 1));
+    }
+}
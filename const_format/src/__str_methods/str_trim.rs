@@ -0,0 +1,217 @@
+use super::{bytes_ends_with, bytes_starts_with, Pattern, PatternCtor, PatternNorm};
+
+pub struct TrimInputConv<T>(pub &'static str, pub T);
+
+macro_rules! ctor {
+    ($ty:ty) => {
+        impl TrimInputConv<$ty> {
+            pub const fn conv(self) -> TrimInput {
+                TrimInput {
+                    str: self.0,
+                    pattern: PatternCtor(self.1).conv(),
+                }
+            }
+        }
+    };
+}
+
+ctor! {u8}
+ctor! {&'static str}
+ctor! {char}
+
+pub struct TrimInput {
+    str: &'static str,
+    pattern: Pattern,
+}
+
+impl TrimInput {
+    pub const fn trim_matches(&self) -> &'static str {
+        let bytes = trim_start_matches(self.str.as_bytes(), self.pattern);
+        // Safety: `trim_*_matches` only remove whole instances of a valid utf8 pattern
+        unsafe { bytes_to_str(trim_end_matches(bytes, self.pattern)) }
+    }
+
+    pub const fn trim_start_matches(&self) -> &'static str {
+        // Safety: `trim_start_matches` only removes whole instances of a valid utf8 pattern
+        unsafe { bytes_to_str(trim_start_matches(self.str.as_bytes(), self.pattern)) }
+    }
+
+    pub const fn trim_end_matches(&self) -> &'static str {
+        // Safety: `trim_end_matches` only removes whole instances of a valid utf8 pattern
+        unsafe { bytes_to_str(trim_end_matches(self.str.as_bytes(), self.pattern)) }
+    }
+}
+
+pub const fn str_trim(str: &'static str) -> &'static str {
+    let bytes = trim_start_whitespace(str.as_bytes());
+    // Safety: `trim_*_whitespace` only remove whole whitespace chars
+    unsafe { bytes_to_str(trim_end_whitespace(bytes)) }
+}
+
+pub const fn str_trim_start(str: &'static str) -> &'static str {
+    // Safety: `trim_start_whitespace` only removes whole whitespace chars
+    unsafe { bytes_to_str(trim_start_whitespace(str.as_bytes())) }
+}
+
+pub const fn str_trim_end(str: &'static str) -> &'static str {
+    // Safety: `trim_end_whitespace` only removes whole whitespace chars
+    unsafe { bytes_to_str(trim_end_whitespace(str.as_bytes())) }
+}
+
+const unsafe fn bytes_to_str(bytes: &'static [u8]) -> &'static str {
+    core::str::from_utf8_unchecked(bytes)
+}
+
+const fn skip_start(mut bytes: &[u8], mut count: usize) -> &[u8] {
+    while count != 0 {
+        if let [_, rem @ ..] = bytes {
+            bytes = rem;
+        }
+        count -= 1;
+    }
+    bytes
+}
+
+const fn skip_end(mut bytes: &[u8], mut count: usize) -> &[u8] {
+    while count != 0 {
+        if let [rem @ .., _] = bytes {
+            bytes = rem;
+        }
+        count -= 1;
+    }
+    bytes
+}
+
+const fn trim_start_matches(mut bytes: &[u8], pattern: Pattern) -> &[u8] {
+    match pattern.normalize() {
+        PatternNorm::AsciiByte(byte) => {
+            let byte = byte.get();
+            while let [b, rem @ ..] = bytes {
+                if *b != byte {
+                    break;
+                }
+                bytes = rem;
+            }
+        }
+        PatternNorm::Str(str) => {
+            if str.is_empty() {
+                return bytes;
+            }
+            while bytes_starts_with(bytes, str) {
+                bytes = skip_start(bytes, str.len());
+            }
+        }
+    }
+    bytes
+}
+
+const fn trim_end_matches(mut bytes: &[u8], pattern: Pattern) -> &[u8] {
+    match pattern.normalize() {
+        PatternNorm::AsciiByte(byte) => {
+            let byte = byte.get();
+            while let [rem @ .., b] = bytes {
+                if *b != byte {
+                    break;
+                }
+                bytes = rem;
+            }
+        }
+        PatternNorm::Str(str) => {
+            if str.is_empty() {
+                return bytes;
+            }
+            while bytes_ends_with(bytes, str) {
+                bytes = skip_end(bytes, str.len());
+            }
+        }
+    }
+    bytes
+}
+
+// The length of the utf8 encoding of the whitespace char
+// (as defined by `char::is_whitespace`) at the start of `bytes`, 0 if there's none.
+const fn whitespace_len_at_start(bytes: &[u8]) -> usize {
+    match bytes {
+        [b'\t' | b'\n' | 0x0B | 0x0C | b'\r' | b' ', ..] => 1,
+        // U+0085 and U+00A0
+        [0xC2, 0x85 | 0xA0, ..] => 2,
+        // U+1680
+        [0xE1, 0x9A, 0x80, ..] => 3,
+        // U+2000 to U+200A, U+2028, U+2029, and U+202F
+        [0xE2, 0x80, 0x80..=0x8A | 0xA8 | 0xA9 | 0xAF, ..] => 3,
+        // U+205F
+        [0xE2, 0x81, 0x9F, ..] => 3,
+        // U+3000
+        [0xE3, 0x80, 0x80, ..] => 3,
+        _ => 0,
+    }
+}
+
+// The length of the utf8 encoding of the whitespace char
+// (as defined by `char::is_whitespace`) at the end of `bytes`, 0 if there's none.
+const fn whitespace_len_at_end(bytes: &[u8]) -> usize {
+    match bytes {
+        [.., b'\t' | b'\n' | 0x0B | 0x0C | b'\r' | b' '] => 1,
+        [.., 0xC2, 0x85 | 0xA0] => 2,
+        [.., 0xE1, 0x9A, 0x80] => 3,
+        [.., 0xE2, 0x80, 0x80..=0x8A | 0xA8 | 0xA9 | 0xAF] => 3,
+        [.., 0xE2, 0x81, 0x9F] => 3,
+        [.., 0xE3, 0x80, 0x80] => 3,
+        _ => 0,
+    }
+}
+
+const fn trim_start_whitespace(mut bytes: &[u8]) -> &[u8] {
+    loop {
+        match whitespace_len_at_start(bytes) {
+            0 => return bytes,
+            len => bytes = skip_start(bytes, len),
+        }
+    }
+}
+
+const fn trim_end_whitespace(mut bytes: &[u8]) -> &[u8] {
+    loop {
+        match whitespace_len_at_end(bytes) {
+            0 => return bytes,
+            len => bytes = skip_end(bytes, len),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrayvec::ArrayString;
+
+    use core::fmt::Write;
+
+    #[test]
+    fn whitespace_matches_std() {
+        for c in (0..=0x10FFFFu32).filter_map(core::char::from_u32) {
+            let mut string = ArrayString::<32>::new();
+            write!(string, "{0}{0}a{0}b{0}{0}", c).unwrap();
+            let bytes = string.as_bytes();
+
+            assert_eq!(
+                trim_end_whitespace(trim_start_whitespace(bytes)),
+                string.trim().as_bytes(),
+                "{:?}",
+                c
+            );
+            assert_eq!(
+                trim_start_whitespace(bytes),
+                string.trim_start().as_bytes(),
+                "{:?}",
+                c
+            );
+            assert_eq!(
+                trim_end_whitespace(bytes),
+                string.trim_end().as_bytes(),
+                "{:?}",
+                c
+            );
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::formatting::{hex_as_ascii, HexFormatting};
 
-#[cfg(any(test, feature = "fmt"))]
+mod unicode_printable;
+
 pub(crate) const fn char_display_len(c: char) -> usize {
     match c as u32 {
         0..=127 => 1,
@@ -12,16 +13,59 @@ pub(crate) const fn char_display_len(c: char) -> usize {
 
 #[cfg(any(test, feature = "fmt"))]
 pub(crate) const fn char_debug_len(c: char) -> usize {
-    let inner = match c {
-        '\t' | '\r' | '\n' | '\\' | '\'' | '\"' => 2,
-        '\x00'..='\x1F' => 4,
-        _ => char_display_len(c),
+    char_to_debug(c).len()
+}
+
+/// Counts the `char`s in utf8 encoded `bytes`.
+pub(crate) const fn utf8_char_count(bytes: &[u8]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // Counting all the bytes that aren't continuation bytes
+        count += ((bytes[i] as i8) >= -0x40) as usize;
+        i += 1;
+    }
+    count
+}
+
+/// Truncates `s` to its first `max_chars` `char`s,
+/// returning `s` unmodified if `max_chars` is `None`.
+pub(crate) const fn truncate_str(s: &str, max_chars: Option<usize>) -> &str {
+    let max_chars = match max_chars {
+        Some(x) => x,
+        None => return s,
     };
-    inner + 2
+
+    let bytes = s.as_bytes();
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // Stopping at the first byte of the char after the last one that's kept
+        if (bytes[i] as i8) >= -0x40 {
+            if count == max_chars {
+                break;
+            }
+            count += 1;
+        }
+        i += 1;
+    }
+
+    let mut truncated = bytes;
+    while truncated.len() > i {
+        if let [rem @ .., _] = truncated {
+            truncated = rem;
+        }
+    }
+
+    // Safety: `i` is the index of the start of a `char`, or the length of the string.
+    unsafe { core::str::from_utf8_unchecked(truncated) }
 }
 
 const fn char_to_utf8(char: char) -> ([u8; 4], usize) {
-    let u32 = char as u32;
+    char_to_utf8_u32(char as u32)
+}
+
+pub(crate) const fn char_to_utf8_u32(u32: u32) -> ([u8; 4], usize) {
     match u32 {
         0..=127 => ([u32 as u8, 0, 0, 0], 1),
         0x80..=0x7FF => {
@@ -48,35 +92,21 @@ const fn char_to_utf8(char: char) -> ([u8; 4], usize) {
 pub(crate) const fn char_to_display(char: char) -> FmtChar {
     let ([b0, b1, b2, b3], len) = char_to_utf8(char);
     FmtChar {
-        encoded: [b0, b1, b2, b3, 0, 0],
+        encoded: [b0, b1, b2, b3, 0, 0, 0, 0, 0, 0, 0, 0],
         len: len as u8,
     }
 }
 
 pub(crate) const fn char_to_debug(c: char) -> FmtChar {
-    let ([b0, b1, b2, b3], len) = match c {
-        '\t' => (*br#"\t  "#, 2),
-        '\r' => (*br#"\r  "#, 2),
-        '\n' => (*br#"\n  "#, 2),
-        '\\' => (*br#"\\  "#, 2),
-        '\'' => (*br#"\'  "#, 2),
-        '\"' => (*br#"\"  "#, 2),
-        '\x00'..='\x1F' => {
-            let n = c as u8;
-            (
-                [
-                    b'\\',
-                    b'x',
-                    hex_as_ascii(n >> 4, HexFormatting::Upper),
-                    hex_as_ascii(n & 0b1111, HexFormatting::Upper),
-                ],
-                4,
-            )
-        }
-        _ => char_to_utf8(c),
-    };
+    let (escaped, len) = escape_debug(c as u32, b'\'');
 
-    let mut encoded = [b'\'', b0, b1, b2, b3, 0];
+    let mut encoded = [0u8; 12];
+    encoded[0] = b'\'';
+    let mut i = 0;
+    while i < len {
+        encoded[i + 1] = escaped[i];
+        i += 1;
+    }
     encoded[len + 1] = b'\'';
 
     FmtChar {
@@ -85,16 +115,123 @@ pub(crate) const fn char_to_debug(c: char) -> FmtChar {
     }
 }
 
+/// Whether `c` is written as a `\u{...}` escape with Debug formatting,
+/// because it's a control character, a non-printable character,
+/// or a grapheme extender.
+const fn is_unicode_escaped(c: u32) -> bool {
+    let bounds = unicode_printable::PRINTABLE_BOUNDARIES;
+
+    // Counting the boundaries that are less than or equal to `c`
+    let mut start = 0;
+    let mut end = bounds.len();
+    while start < end {
+        let mid = (start + end) / 2;
+        if bounds[mid] <= c {
+            start = mid + 1;
+        } else {
+            end = mid;
+        }
+    }
+
+    start % 2 == 0
+}
+
+/// Escapes the `c` character the same way that std's Debug formatting does,
+/// `quote` is the quote character that surrounds the `char`/string, which is escaped.
+///
+/// Returns the escaped `char`, and how many bytes of the array are used.
+const fn escape_debug(c: u32, quote: u8) -> ([u8; 10], usize) {
+    let backslash_escape = match c {
+        0 => b'0',
+        0x09 => b't',
+        0x0A => b'n',
+        0x0D => b'r',
+        0x5C => b'\\',
+        _ if c == quote as u32 => quote,
+        _ => 0,
+    };
+
+    if backslash_escape != 0 {
+        ([b'\\', backslash_escape, 0, 0, 0, 0, 0, 0, 0, 0], 2)
+    } else if is_unicode_escaped(c) {
+        let mut out = [b'\\', b'u', b'{', 0, 0, 0, 0, 0, 0, 0];
+        // The amount of hexadecimal digits, at least one for `\u{0}`
+        let digits = (32 - (c | 1).leading_zeros() as usize + 3) / 4;
+
+        let mut i = 0;
+        while i < digits {
+            let nibble = (c >> ((digits - 1 - i) * 4)) & 0xF;
+            out[3 + i] = hex_as_ascii(nibble as u8, HexFormatting::Lower);
+            i += 1;
+        }
+        out[3 + digits] = b'}';
+
+        (out, digits + 4)
+    } else {
+        let ([b0, b1, b2, b3], len) = char_to_utf8_u32(c);
+        ([b0, b1, b2, b3, 0, 0, 0, 0, 0, 0], len)
+    }
+}
+
+/// How a `char` in a string is written with Debug formatting.
+#[doc(hidden)]
+#[derive(Copy, Clone)]
+pub struct StrCharDebug {
+    /// The length of the `char` in the string, in bytes.
+    pub char_len: usize,
+    /// The debug formatted `char`,
+    /// only `&self.encoded[..self.len]` should be copied.
+    pub encoded: [u8; 10],
+    /// The length of the debug formatted `char`, in bytes.
+    pub len: usize,
+}
+
+/// Decodes the `char` that starts at `bytes[index]`,
+/// returning it along with its length in bytes.
+///
+/// `bytes` must be utf8, and `index` must be on a `char` boundary.
+pub(crate) const fn decode_utf8(bytes: &[u8], index: usize) -> (u32, usize) {
+    let first = bytes[index];
+    let (mut c, char_len) = match first {
+        0..=0x7F => return (first as u32, 1),
+        0x80..=0xDF => (first as u32 & 0x1F, 2),
+        0xE0..=0xEF => (first as u32 & 0x0F, 3),
+        _ => (first as u32 & 0x07, 4),
+    };
+
+    let mut i = 1;
+    while i < char_len {
+        c = (c << 6) | (bytes[index + i] & 0x3F) as u32;
+        i += 1;
+    }
+    (c, char_len)
+}
+
+/// Debug formats the `char` that starts at `bytes[index]`,
+/// the same way that std's Debug formatting of `str` does.
+///
+/// `bytes` must be utf8, and `index` must be on a `char` boundary.
+#[doc(hidden)]
+pub const fn str_char_debug(bytes: &[u8], index: usize) -> StrCharDebug {
+    let (c, char_len) = decode_utf8(bytes, index);
+    let (encoded, len) = escape_debug(c, b'"');
+    StrCharDebug {
+        char_len,
+        encoded,
+        len,
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct FmtChar {
-    encoded: [u8; 6],
+    encoded: [u8; 12],
     len: u8,
 }
 
 impl FmtChar {
     /// Array which contains the pre-len display/debug-formatted  `char`,
     /// only `&self.encoded[][..self.len()]` should be copied.
-    pub const fn encoded(&self) -> &[u8; 6] {
+    pub const fn encoded(&self) -> &[u8; 12] {
         &self.encoded
     }
 
@@ -107,26 +244,50 @@ impl FmtChar {
         {
             match self.len() {
                 1 => {
-                    let [ret @ .., _, _, _, _, _] = &self.encoded;
+                    let [ret @ .., _, _, _, _, _, _, _, _, _, _, _] = &self.encoded;
                     ret
                 }
                 2 => {
-                    let [ret @ .., _, _, _, _] = &self.encoded;
+                    let [ret @ .., _, _, _, _, _, _, _, _, _, _] = &self.encoded;
                     ret
                 }
                 3 => {
-                    let [ret @ .., _, _, _] = &self.encoded;
+                    let [ret @ .., _, _, _, _, _, _, _, _, _] = &self.encoded;
                     ret
                 }
                 4 => {
-                    let [ret @ .., _, _] = &self.encoded;
+                    let [ret @ .., _, _, _, _, _, _, _, _] = &self.encoded;
                     ret
                 }
                 5 => {
+                    let [ret @ .., _, _, _, _, _, _, _] = &self.encoded;
+                    ret
+                }
+                6 => {
+                    let [ret @ .., _, _, _, _, _, _] = &self.encoded;
+                    ret
+                }
+                7 => {
+                    let [ret @ .., _, _, _, _, _] = &self.encoded;
+                    ret
+                }
+                8 => {
+                    let [ret @ .., _, _, _, _] = &self.encoded;
+                    ret
+                }
+                9 => {
+                    let [ret @ .., _, _, _] = &self.encoded;
+                    ret
+                }
+                10 => {
+                    let [ret @ .., _, _] = &self.encoded;
+                    ret
+                }
+                11 => {
                     let [ret @ .., _] = &self.encoded;
                     ret
                 }
-                6 => &self.encoded,
+                12 => &self.encoded,
                 x => [/*bug WTF*/][x],
             }
         }
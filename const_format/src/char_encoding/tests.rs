@@ -1,4 +1,4 @@
-use super::{char_debug_len, char_display_len, char_to_debug, char_to_display};
+use super::{char_debug_len, char_display_len, char_to_debug, char_to_display, str_char_debug};
 
 #[test]
 fn char_to_utf8_encoding_test() {
@@ -28,66 +28,70 @@ fn char_to_utf8_display_test() {
 
 #[test]
 fn char_to_utf8_debug_test() {
-    let first_escapes = [
-        ('\x00', r#"'\x00'"#),
-        ('\x01', r#"'\x01'"#),
-        ('\x02', r#"'\x02'"#),
-        ('\x03', r#"'\x03'"#),
-        ('\x04', r#"'\x04'"#),
-        ('\x05', r#"'\x05'"#),
-        ('\x06', r#"'\x06'"#),
-        ('\x07', r#"'\x07'"#),
-        ('\x08', r#"'\x08'"#),
+    let some_escapes = [
+        ('\x00', r#"'\0'"#),
+        ('\x01', r#"'\u{1}'"#),
         ('\t', r#"'\t'"#),
         ('\n', r#"'\n'"#),
-        ('\x0B', r#"'\x0B'"#),
-        ('\x0C', r#"'\x0C'"#),
         ('\r', r#"'\r'"#),
-        ('\x0E', r#"'\x0E'"#),
-        ('\x0F', r#"'\x0F'"#),
-        ('\x10', r#"'\x10'"#),
-        ('\x11', r#"'\x11'"#),
-        ('\x12', r#"'\x12'"#),
-        ('\x13', r#"'\x13'"#),
-        ('\x14', r#"'\x14'"#),
-        ('\x15', r#"'\x15'"#),
-        ('\x16', r#"'\x16'"#),
-        ('\x17', r#"'\x17'"#),
-        ('\x18', r#"'\x18'"#),
-        ('\x19', r#"'\x19'"#),
-        ('\x1A', r#"'\x1A'"#),
-        ('\x1B', r#"'\x1B'"#),
-        ('\x1C', r#"'\x1C'"#),
-        ('\x1D', r#"'\x1D'"#),
-        ('\x1E', r#"'\x1E'"#),
-        ('\x1F', r#"'\x1F'"#),
+        ('\x1F', r#"'\u{1f}'"#),
+        ('\'', r#"'\''"#),
+        ('"', r#"'"'"#),
+        ('\\', r#"'\\'"#),
+        ('\x7F', r#"'\u{7f}'"#),
+        ('\u{AD}', r#"'\u{ad}'"#),
+        ('\u{301}', r#"'\u{301}'"#),
+        ('\u{200B}', r#"'\u{200b}'"#),
+        ('\u{10FFFF}', r#"'\u{10ffff}'"#),
     ];
 
-    for (c, expected) in first_escapes.iter().copied() {
+    for (c, expected) in some_escapes.iter().copied() {
         let utf8_here = char_to_debug(c);
         assert_eq!(expected.as_bytes(), utf8_here.as_bytes(), "{:?}", c);
         assert_eq!(expected.len(), char_debug_len(c), "{:?}", c);
     }
 
-    let other_escapes = [('\'', r#"'\''"#), ('\"', r#"'\"'"#), ('\\', r#"'\\'"#)];
-
     let mut buffer = arrayvec::ArrayString::<12>::new();
-    for c in '\x20'..=core::char::MAX {
+    for c in '\0'..=core::char::MAX {
         let utf8_here = char_to_debug(c);
 
-        if let Some((_, expected)) = Some(c)
-            .filter(|c| *c <= '\x7F')
-            .and_then(|c| other_escapes.iter().copied().find(|x| x.0 == c))
-        {
-            assert_eq!(expected.as_bytes(), utf8_here.as_bytes(), "{:?}", c);
-            assert_eq!(expected.len(), char_debug_len(c), "{:?}", c);
+        buffer.clear();
+        buffer.push('\'');
+        if c == '"' {
+            // `char::escape_debug` escapes double quotes, the Debug impl of `char` doesn't
+            buffer.push(c);
         } else {
-            buffer.clear();
-            buffer.push('\'');
+            c.escape_debug().for_each(|c| buffer.push(c));
+        }
+        buffer.push('\'');
+
+        assert_eq!(buffer.as_bytes(), utf8_here.as_bytes(), "{:?}", c);
+        assert_eq!(buffer.len(), char_debug_len(c), "{:?}", c);
+    }
+}
+
+#[test]
+fn str_char_debug_test() {
+    let mut buffer = arrayvec::ArrayString::<10>::new();
+    for c in '\0'..=core::char::MAX {
+        let mut utf8 = [0u8; 6];
+        let len = c.encode_utf8(&mut utf8[1..]).len();
+
+        buffer.clear();
+        if c == '\'' {
+            // `char::escape_debug` escapes single quotes, the Debug impl of `str` doesn't
             buffer.push(c);
-            buffer.push('\'');
-            assert_eq!(buffer.as_bytes(), utf8_here.as_bytes(), "{:?}", c);
-            assert_eq!(buffer.len(), char_debug_len(c), "{:?}", c);
+        } else {
+            c.escape_debug().for_each(|c| buffer.push(c));
         }
+
+        let escaped = str_char_debug(&utf8[..len + 2], 1);
+        assert_eq!(escaped.char_len, len, "{:?}", c);
+        assert_eq!(
+            &escaped.encoded[..escaped.len],
+            buffer.as_bytes(),
+            "{:?}",
+            c
+        );
     }
 }
@@ -0,0 +1,189 @@
+// Generated from the `char::escape_debug` of the standard library,
+// which uses Unicode 17.0.0.
+//
+// A `char` is printable (and not a grapheme extender)
+// if an odd amount of elements in this array are less than or equal to it,
+// otherwise it's written as a `\u{...}` escape by Debug formatting.
+pub(super) const PRINTABLE_BOUNDARIES: &[u32] = &[
+    0x00020, 0x0007F, 0x000A1, 0x000AD, 0x000AE, 0x00300, 0x00370, 0x00378, 0x0037A, 0x00380,
+    0x00384, 0x0038B, 0x0038C, 0x0038D, 0x0038E, 0x003A2, 0x003A3, 0x00483, 0x0048A, 0x00530,
+    0x00531, 0x00557, 0x00559, 0x0058B, 0x0058D, 0x00590, 0x005BE, 0x005BF, 0x005C0, 0x005C1,
+    0x005C3, 0x005C4, 0x005C6, 0x005C7, 0x005D0, 0x005EB, 0x005EF, 0x005F5, 0x00606, 0x00610,
+    0x0061B, 0x0061C, 0x0061D, 0x0064B, 0x00660, 0x00670, 0x00671, 0x006D6, 0x006DE, 0x006DF,
+    0x006E5, 0x006E7, 0x006E9, 0x006EA, 0x006EE, 0x0070E, 0x00710, 0x00711, 0x00712, 0x00730,
+    0x0074D, 0x007A6, 0x007B1, 0x007B2, 0x007C0, 0x007EB, 0x007F4, 0x007FB, 0x007FE, 0x00816,
+    0x0081A, 0x0081B, 0x00824, 0x00825, 0x00828, 0x00829, 0x00830, 0x0083F, 0x00840, 0x00859,
+    0x0085E, 0x0085F, 0x00860, 0x0086B, 0x00870, 0x00890, 0x008A0, 0x008CA, 0x00903, 0x0093A,
+    0x0093B, 0x0093C, 0x0093D, 0x00941, 0x00949, 0x0094D, 0x0094E, 0x00951, 0x00958, 0x00962,
+    0x00964, 0x00981, 0x00982, 0x00984, 0x00985, 0x0098D, 0x0098F, 0x00991, 0x00993, 0x009A9,
+    0x009AA, 0x009B1, 0x009B2, 0x009B3, 0x009B6, 0x009BA, 0x009BD, 0x009BE, 0x009BF, 0x009C1,
+    0x009C7, 0x009C9, 0x009CB, 0x009CD, 0x009CE, 0x009CF, 0x009DC, 0x009DE, 0x009DF, 0x009E2,
+    0x009E6, 0x009FE, 0x00A03, 0x00A04, 0x00A05, 0x00A0B, 0x00A0F, 0x00A11, 0x00A13, 0x00A29,
+    0x00A2A, 0x00A31, 0x00A32, 0x00A34, 0x00A35, 0x00A37, 0x00A38, 0x00A3A, 0x00A3E, 0x00A41,
+    0x00A59, 0x00A5D, 0x00A5E, 0x00A5F, 0x00A66, 0x00A70, 0x00A72, 0x00A75, 0x00A76, 0x00A77,
+    0x00A83, 0x00A84, 0x00A85, 0x00A8E, 0x00A8F, 0x00A92, 0x00A93, 0x00AA9, 0x00AAA, 0x00AB1,
+    0x00AB2, 0x00AB4, 0x00AB5, 0x00ABA, 0x00ABD, 0x00AC1, 0x00AC9, 0x00ACA, 0x00ACB, 0x00ACD,
+    0x00AD0, 0x00AD1, 0x00AE0, 0x00AE2, 0x00AE6, 0x00AF2, 0x00AF9, 0x00AFA, 0x00B02, 0x00B04,
+    0x00B05, 0x00B0D, 0x00B0F, 0x00B11, 0x00B13, 0x00B29, 0x00B2A, 0x00B31, 0x00B32, 0x00B34,
+    0x00B35, 0x00B3A, 0x00B3D, 0x00B3E, 0x00B40, 0x00B41, 0x00B47, 0x00B49, 0x00B4B, 0x00B4D,
+    0x00B5C, 0x00B5E, 0x00B5F, 0x00B62, 0x00B66, 0x00B78, 0x00B83, 0x00B84, 0x00B85, 0x00B8B,
+    0x00B8E, 0x00B91, 0x00B92, 0x00B96, 0x00B99, 0x00B9B, 0x00B9C, 0x00B9D, 0x00B9E, 0x00BA0,
+    0x00BA3, 0x00BA5, 0x00BA8, 0x00BAB, 0x00BAE, 0x00BBA, 0x00BBF, 0x00BC0, 0x00BC1, 0x00BC3,
+    0x00BC6, 0x00BC9, 0x00BCA, 0x00BCD, 0x00BD0, 0x00BD1, 0x00BE6, 0x00BFB, 0x00C01, 0x00C04,
+    0x00C05, 0x00C0D, 0x00C0E, 0x00C11, 0x00C12, 0x00C29, 0x00C2A, 0x00C3A, 0x00C3D, 0x00C3E,
+    0x00C41, 0x00C45, 0x00C58, 0x00C5B, 0x00C5C, 0x00C5E, 0x00C60, 0x00C62, 0x00C66, 0x00C70,
+    0x00C77, 0x00C81, 0x00C82, 0x00C8D, 0x00C8E, 0x00C91, 0x00C92, 0x00CA9, 0x00CAA, 0x00CB4,
+    0x00CB5, 0x00CBA, 0x00CBD, 0x00CBF, 0x00CC1, 0x00CC2, 0x00CC3, 0x00CC5, 0x00CDC, 0x00CDF,
+    0x00CE0, 0x00CE2, 0x00CE6, 0x00CF0, 0x00CF1, 0x00CF4, 0x00D02, 0x00D0D, 0x00D0E, 0x00D11,
+    0x00D12, 0x00D3B, 0x00D3D, 0x00D3E, 0x00D3F, 0x00D41, 0x00D46, 0x00D49, 0x00D4A, 0x00D4D,
+    0x00D4E, 0x00D50, 0x00D54, 0x00D57, 0x00D58, 0x00D62, 0x00D66, 0x00D80, 0x00D82, 0x00D84,
+    0x00D85, 0x00D97, 0x00D9A, 0x00DB2, 0x00DB3, 0x00DBC, 0x00DBD, 0x00DBE, 0x00DC0, 0x00DC7,
+    0x00DD0, 0x00DD2, 0x00DD8, 0x00DDF, 0x00DE6, 0x00DF0, 0x00DF2, 0x00DF5, 0x00E01, 0x00E31,
+    0x00E32, 0x00E34, 0x00E3F, 0x00E47, 0x00E4F, 0x00E5C, 0x00E81, 0x00E83, 0x00E84, 0x00E85,
+    0x00E86, 0x00E8B, 0x00E8C, 0x00EA4, 0x00EA5, 0x00EA6, 0x00EA7, 0x00EB1, 0x00EB2, 0x00EB4,
+    0x00EBD, 0x00EBE, 0x00EC0, 0x00EC5, 0x00EC6, 0x00EC7, 0x00ED0, 0x00EDA, 0x00EDC, 0x00EE0,
+    0x00F00, 0x00F18, 0x00F1A, 0x00F35, 0x00F36, 0x00F37, 0x00F38, 0x00F39, 0x00F3A, 0x00F48,
+    0x00F49, 0x00F6D, 0x00F7F, 0x00F80, 0x00F85, 0x00F86, 0x00F88, 0x00F8D, 0x00FBE, 0x00FC6,
+    0x00FC7, 0x00FCD, 0x00FCE, 0x00FDB, 0x01000, 0x0102D, 0x01031, 0x01032, 0x01038, 0x01039,
+    0x0103B, 0x0103D, 0x0103F, 0x01058, 0x0105A, 0x0105E, 0x01061, 0x01071, 0x01075, 0x01082,
+    0x01083, 0x01085, 0x01087, 0x0108D, 0x0108E, 0x0109D, 0x0109E, 0x010C6, 0x010C7, 0x010C8,
+    0x010CD, 0x010CE, 0x010D0, 0x01249, 0x0124A, 0x0124E, 0x01250, 0x01257, 0x01258, 0x01259,
+    0x0125A, 0x0125E, 0x01260, 0x01289, 0x0128A, 0x0128E, 0x01290, 0x012B1, 0x012B2, 0x012B6,
+    0x012B8, 0x012BF, 0x012C0, 0x012C1, 0x012C2, 0x012C6, 0x012C8, 0x012D7, 0x012D8, 0x01311,
+    0x01312, 0x01316, 0x01318, 0x0135B, 0x01360, 0x0137D, 0x01380, 0x0139A, 0x013A0, 0x013F6,
+    0x013F8, 0x013FE, 0x01400, 0x01680, 0x01681, 0x0169D, 0x016A0, 0x016F9, 0x01700, 0x01712,
+    0x0171F, 0x01732, 0x01735, 0x01737, 0x01740, 0x01752, 0x01760, 0x0176D, 0x0176E, 0x01771,
+    0x01780, 0x017B4, 0x017B6, 0x017B7, 0x017BE, 0x017C6, 0x017C7, 0x017C9, 0x017D4, 0x017DD,
+    0x017E0, 0x017EA, 0x017F0, 0x017FA, 0x01800, 0x0180B, 0x01810, 0x0181A, 0x01820, 0x01879,
+    0x01880, 0x01885, 0x01887, 0x018A9, 0x018AA, 0x018AB, 0x018B0, 0x018F6, 0x01900, 0x0191F,
+    0x01923, 0x01927, 0x01929, 0x0192C, 0x01930, 0x01932, 0x01933, 0x01939, 0x01940, 0x01941,
+    0x01944, 0x0196E, 0x01970, 0x01975, 0x01980, 0x019AC, 0x019B0, 0x019CA, 0x019D0, 0x019DB,
+    0x019DE, 0x01A17, 0x01A19, 0x01A1B, 0x01A1E, 0x01A56, 0x01A57, 0x01A58, 0x01A61, 0x01A62,
+    0x01A63, 0x01A65, 0x01A6D, 0x01A73, 0x01A80, 0x01A8A, 0x01A90, 0x01A9A, 0x01AA0, 0x01AAE,
+    0x01B04, 0x01B34, 0x01B3E, 0x01B42, 0x01B45, 0x01B4D, 0x01B4E, 0x01B6B, 0x01B74, 0x01B80,
+    0x01B82, 0x01BA2, 0x01BA6, 0x01BA8, 0x01BAE, 0x01BE6, 0x01BE7, 0x01BE8, 0x01BEA, 0x01BED,
+    0x01BEE, 0x01BEF, 0x01BFC, 0x01C2C, 0x01C34, 0x01C36, 0x01C3B, 0x01C4A, 0x01C4D, 0x01C8B,
+    0x01C90, 0x01CBB, 0x01CBD, 0x01CC8, 0x01CD3, 0x01CD4, 0x01CE1, 0x01CE2, 0x01CE9, 0x01CED,
+    0x01CEE, 0x01CF4, 0x01CF5, 0x01CF8, 0x01CFA, 0x01CFB, 0x01D00, 0x01DC0, 0x01E00, 0x01F16,
+    0x01F18, 0x01F1E, 0x01F20, 0x01F46, 0x01F48, 0x01F4E, 0x01F50, 0x01F58, 0x01F59, 0x01F5A,
+    0x01F5B, 0x01F5C, 0x01F5D, 0x01F5E, 0x01F5F, 0x01F7E, 0x01F80, 0x01FB5, 0x01FB6, 0x01FC5,
+    0x01FC6, 0x01FD4, 0x01FD6, 0x01FDC, 0x01FDD, 0x01FF0, 0x01FF2, 0x01FF5, 0x01FF6, 0x01FFF,
+    0x02010, 0x02028, 0x02030, 0x0205F, 0x02070, 0x02072, 0x02074, 0x0208F, 0x02090, 0x0209D,
+    0x020A0, 0x020C2, 0x02100, 0x0218C, 0x02190, 0x0242A, 0x02440, 0x0244B, 0x02460, 0x02B74,
+    0x02B76, 0x02CEF, 0x02CF2, 0x02CF4, 0x02CF9, 0x02D26, 0x02D27, 0x02D28, 0x02D2D, 0x02D2E,
+    0x02D30, 0x02D68, 0x02D6F, 0x02D71, 0x02D80, 0x02D97, 0x02DA0, 0x02DA7, 0x02DA8, 0x02DAF,
+    0x02DB0, 0x02DB7, 0x02DB8, 0x02DBF, 0x02DC0, 0x02DC7, 0x02DC8, 0x02DCF, 0x02DD0, 0x02DD7,
+    0x02DD8, 0x02DDF, 0x02E00, 0x02E5E, 0x02E80, 0x02E9A, 0x02E9B, 0x02EF4, 0x02F00, 0x02FD6,
+    0x02FF0, 0x03000, 0x03001, 0x0302A, 0x03030, 0x03040, 0x03041, 0x03097, 0x0309B, 0x03100,
+    0x03105, 0x03130, 0x03131, 0x0318F, 0x03190, 0x031E6, 0x031EF, 0x0321F, 0x03220, 0x0A48D,
+    0x0A490, 0x0A4C7, 0x0A4D0, 0x0A62C, 0x0A640, 0x0A66F, 0x0A673, 0x0A674, 0x0A67E, 0x0A69E,
+    0x0A6A0, 0x0A6F0, 0x0A6F2, 0x0A6F8, 0x0A700, 0x0A7DD, 0x0A7F1, 0x0A802, 0x0A803, 0x0A806,
+    0x0A807, 0x0A80B, 0x0A80C, 0x0A825, 0x0A827, 0x0A82C, 0x0A830, 0x0A83A, 0x0A840, 0x0A878,
+    0x0A880, 0x0A8C4, 0x0A8CE, 0x0A8DA, 0x0A8F2, 0x0A8FF, 0x0A900, 0x0A926, 0x0A92E, 0x0A947,
+    0x0A952, 0x0A953, 0x0A95F, 0x0A97D, 0x0A983, 0x0A9B3, 0x0A9B4, 0x0A9B6, 0x0A9BA, 0x0A9BC,
+    0x0A9BE, 0x0A9C0, 0x0A9C1, 0x0A9CE, 0x0A9CF, 0x0A9DA, 0x0A9DE, 0x0A9E5, 0x0A9E6, 0x0A9FF,
+    0x0AA00, 0x0AA29, 0x0AA2F, 0x0AA31, 0x0AA33, 0x0AA35, 0x0AA40, 0x0AA43, 0x0AA44, 0x0AA4C,
+    0x0AA4D, 0x0AA4E, 0x0AA50, 0x0AA5A, 0x0AA5C, 0x0AA7C, 0x0AA7D, 0x0AAB0, 0x0AAB1, 0x0AAB2,
+    0x0AAB5, 0x0AAB7, 0x0AAB9, 0x0AABE, 0x0AAC0, 0x0AAC1, 0x0AAC2, 0x0AAC3, 0x0AADB, 0x0AAEC,
+    0x0AAEE, 0x0AAF6, 0x0AB01, 0x0AB07, 0x0AB09, 0x0AB0F, 0x0AB11, 0x0AB17, 0x0AB20, 0x0AB27,
+    0x0AB28, 0x0AB2F, 0x0AB30, 0x0AB6C, 0x0AB70, 0x0ABE5, 0x0ABE6, 0x0ABE8, 0x0ABE9, 0x0ABED,
+    0x0ABF0, 0x0ABFA, 0x0AC00, 0x0D7A4, 0x0D7B0, 0x0D7C7, 0x0D7CB, 0x0D7FC, 0x0F900, 0x0FA6E,
+    0x0FA70, 0x0FADA, 0x0FB00, 0x0FB07, 0x0FB13, 0x0FB18, 0x0FB1D, 0x0FB1E, 0x0FB1F, 0x0FB37,
+    0x0FB38, 0x0FB3D, 0x0FB3E, 0x0FB3F, 0x0FB40, 0x0FB42, 0x0FB43, 0x0FB45, 0x0FB46, 0x0FDD0,
+    0x0FDF0, 0x0FE00, 0x0FE10, 0x0FE1A, 0x0FE30, 0x0FE53, 0x0FE54, 0x0FE67, 0x0FE68, 0x0FE6C,
+    0x0FE70, 0x0FE75, 0x0FE76, 0x0FEFD, 0x0FF01, 0x0FF9E, 0x0FFA0, 0x0FFBF, 0x0FFC2, 0x0FFC8,
+    0x0FFCA, 0x0FFD0, 0x0FFD2, 0x0FFD8, 0x0FFDA, 0x0FFDD, 0x0FFE0, 0x0FFE7, 0x0FFE8, 0x0FFEF,
+    0x0FFFC, 0x0FFFE, 0x10000, 0x1000C, 0x1000D, 0x10027, 0x10028, 0x1003B, 0x1003C, 0x1003E,
+    0x1003F, 0x1004E, 0x10050, 0x1005E, 0x10080, 0x100FB, 0x10100, 0x10103, 0x10107, 0x10134,
+    0x10137, 0x1018F, 0x10190, 0x1019D, 0x101A0, 0x101A1, 0x101D0, 0x101FD, 0x10280, 0x1029D,
+    0x102A0, 0x102D1, 0x102E1, 0x102FC, 0x10300, 0x10324, 0x1032D, 0x1034B, 0x10350, 0x10376,
+    0x10380, 0x1039E, 0x1039F, 0x103C4, 0x103C8, 0x103D6, 0x10400, 0x1049E, 0x104A0, 0x104AA,
+    0x104B0, 0x104D4, 0x104D8, 0x104FC, 0x10500, 0x10528, 0x10530, 0x10564, 0x1056F, 0x1057B,
+    0x1057C, 0x1058B, 0x1058C, 0x10593, 0x10594, 0x10596, 0x10597, 0x105A2, 0x105A3, 0x105B2,
+    0x105B3, 0x105BA, 0x105BB, 0x105BD, 0x105C0, 0x105F4, 0x10600, 0x10737, 0x10740, 0x10756,
+    0x10760, 0x10768, 0x10780, 0x10786, 0x10787, 0x107B1, 0x107B2, 0x107BB, 0x10800, 0x10806,
+    0x10808, 0x10809, 0x1080A, 0x10836, 0x10837, 0x10839, 0x1083C, 0x1083D, 0x1083F, 0x10856,
+    0x10857, 0x1089F, 0x108A7, 0x108B0, 0x108E0, 0x108F3, 0x108F4, 0x108F6, 0x108FB, 0x1091C,
+    0x1091F, 0x1093A, 0x1093F, 0x1095A, 0x10980, 0x109B8, 0x109BC, 0x109D0, 0x109D2, 0x10A01,
+    0x10A10, 0x10A14, 0x10A15, 0x10A18, 0x10A19, 0x10A36, 0x10A40, 0x10A49, 0x10A50, 0x10A59,
+    0x10A60, 0x10AA0, 0x10AC0, 0x10AE5, 0x10AEB, 0x10AF7, 0x10B00, 0x10B36, 0x10B39, 0x10B56,
+    0x10B58, 0x10B73, 0x10B78, 0x10B92, 0x10B99, 0x10B9D, 0x10BA9, 0x10BB0, 0x10C00, 0x10C49,
+    0x10C80, 0x10CB3, 0x10CC0, 0x10CF3, 0x10CFA, 0x10D24, 0x10D30, 0x10D3A, 0x10D40, 0x10D66,
+    0x10D6E, 0x10D86, 0x10D8E, 0x10D90, 0x10E60, 0x10E7F, 0x10E80, 0x10EAA, 0x10EAD, 0x10EAE,
+    0x10EB0, 0x10EB2, 0x10EC2, 0x10EC8, 0x10ED0, 0x10ED9, 0x10F00, 0x10F28, 0x10F30, 0x10F46,
+    0x10F51, 0x10F5A, 0x10F70, 0x10F82, 0x10F86, 0x10F8A, 0x10FB0, 0x10FCC, 0x10FE0, 0x10FF7,
+    0x11000, 0x11001, 0x11002, 0x11038, 0x11047, 0x1104E, 0x11052, 0x11070, 0x11071, 0x11073,
+    0x11075, 0x11076, 0x11082, 0x110B3, 0x110B7, 0x110B9, 0x110BB, 0x110BD, 0x110BE, 0x110C2,
+    0x110D0, 0x110E9, 0x110F0, 0x110FA, 0x11103, 0x11127, 0x1112C, 0x1112D, 0x11136, 0x11148,
+    0x11150, 0x11173, 0x11174, 0x11177, 0x11182, 0x111B6, 0x111BF, 0x111C0, 0x111C1, 0x111C9,
+    0x111CD, 0x111CF, 0x111D0, 0x111E0, 0x111E1, 0x111F5, 0x11200, 0x11212, 0x11213, 0x1122F,
+    0x11232, 0x11234, 0x11238, 0x1123E, 0x1123F, 0x11241, 0x11280, 0x11287, 0x11288, 0x11289,
+    0x1128A, 0x1128E, 0x1128F, 0x1129E, 0x1129F, 0x112AA, 0x112B0, 0x112DF, 0x112E0, 0x112E3,
+    0x112F0, 0x112FA, 0x11302, 0x11304, 0x11305, 0x1130D, 0x1130F, 0x11311, 0x11313, 0x11329,
+    0x1132A, 0x11331, 0x11332, 0x11334, 0x11335, 0x1133A, 0x1133D, 0x1133E, 0x1133F, 0x11340,
+    0x11341, 0x11345, 0x11347, 0x11349, 0x1134B, 0x1134D, 0x11350, 0x11351, 0x1135D, 0x11364,
+    0x11380, 0x1138A, 0x1138B, 0x1138C, 0x1138E, 0x1138F, 0x11390, 0x113B6, 0x113B7, 0x113B8,
+    0x113B9, 0x113BB, 0x113CA, 0x113CB, 0x113CC, 0x113CE, 0x113D1, 0x113D2, 0x113D3, 0x113D6,
+    0x113D7, 0x113D9, 0x11400, 0x11438, 0x11440, 0x11442, 0x11445, 0x11446, 0x11447, 0x1145C,
+    0x1145D, 0x1145E, 0x1145F, 0x11462, 0x11480, 0x114B0, 0x114B1, 0x114B3, 0x114B9, 0x114BA,
+    0x114BB, 0x114BD, 0x114BE, 0x114BF, 0x114C1, 0x114C2, 0x114C4, 0x114C8, 0x114D0, 0x114DA,
+    0x11580, 0x115AF, 0x115B0, 0x115B2, 0x115B8, 0x115BC, 0x115BE, 0x115BF, 0x115C1, 0x115DC,
+    0x11600, 0x11633, 0x1163B, 0x1163D, 0x1163E, 0x1163F, 0x11641, 0x11645, 0x11650, 0x1165A,
+    0x11660, 0x1166D, 0x11680, 0x116AB, 0x116AC, 0x116AD, 0x116AE, 0x116B0, 0x116B8, 0x116BA,
+    0x116C0, 0x116CA, 0x116D0, 0x116E4, 0x11700, 0x1171B, 0x1171E, 0x1171F, 0x11720, 0x11722,
+    0x11726, 0x11727, 0x11730, 0x11747, 0x11800, 0x1182F, 0x11838, 0x11839, 0x1183B, 0x1183C,
+    0x118A0, 0x118F3, 0x118FF, 0x11907, 0x11909, 0x1190A, 0x1190C, 0x11914, 0x11915, 0x11917,
+    0x11918, 0x11930, 0x11931, 0x11936, 0x11937, 0x11939, 0x1193F, 0x11943, 0x11944, 0x11947,
+    0x11950, 0x1195A, 0x119A0, 0x119A8, 0x119AA, 0x119D4, 0x119DC, 0x119E0, 0x119E1, 0x119E5,
+    0x11A00, 0x11A01, 0x11A0B, 0x11A33, 0x11A39, 0x11A3B, 0x11A3F, 0x11A47, 0x11A50, 0x11A51,
+    0x11A57, 0x11A59, 0x11A5C, 0x11A8A, 0x11A97, 0x11A98, 0x11A9A, 0x11AA3, 0x11AB0, 0x11AF9,
+    0x11B00, 0x11B0A, 0x11B61, 0x11B62, 0x11B65, 0x11B66, 0x11B67, 0x11B68, 0x11BC0, 0x11BE2,
+    0x11BF0, 0x11BFA, 0x11C00, 0x11C09, 0x11C0A, 0x11C30, 0x11C3E, 0x11C3F, 0x11C40, 0x11C46,
+    0x11C50, 0x11C6D, 0x11C70, 0x11C90, 0x11CA9, 0x11CAA, 0x11CB1, 0x11CB2, 0x11CB4, 0x11CB5,
+    0x11D00, 0x11D07, 0x11D08, 0x11D0A, 0x11D0B, 0x11D31, 0x11D46, 0x11D47, 0x11D50, 0x11D5A,
+    0x11D60, 0x11D66, 0x11D67, 0x11D69, 0x11D6A, 0x11D8F, 0x11D93, 0x11D95, 0x11D96, 0x11D97,
+    0x11D98, 0x11D99, 0x11DA0, 0x11DAA, 0x11DB0, 0x11DDC, 0x11DE0, 0x11DEA, 0x11EE0, 0x11EF3,
+    0x11EF5, 0x11EF9, 0x11F02, 0x11F11, 0x11F12, 0x11F36, 0x11F3E, 0x11F40, 0x11F43, 0x11F5A,
+    0x11FB0, 0x11FB1, 0x11FC0, 0x11FF2, 0x11FFF, 0x1239A, 0x12400, 0x1246F, 0x12470, 0x12475,
+    0x12480, 0x12544, 0x12F90, 0x12FF3, 0x13000, 0x13430, 0x13441, 0x13447, 0x13460, 0x143FB,
+    0x14400, 0x14647, 0x16100, 0x1611E, 0x1612A, 0x1612D, 0x16130, 0x1613A, 0x16800, 0x16A39,
+    0x16A40, 0x16A5F, 0x16A60, 0x16A6A, 0x16A6E, 0x16ABF, 0x16AC0, 0x16ACA, 0x16AD0, 0x16AEE,
+    0x16AF5, 0x16AF6, 0x16B00, 0x16B30, 0x16B37, 0x16B46, 0x16B50, 0x16B5A, 0x16B5B, 0x16B62,
+    0x16B63, 0x16B78, 0x16B7D, 0x16B90, 0x16D40, 0x16D7A, 0x16E40, 0x16E9B, 0x16EA0, 0x16EB9,
+    0x16EBB, 0x16ED4, 0x16F00, 0x16F4B, 0x16F50, 0x16F88, 0x16F93, 0x16FA0, 0x16FE0, 0x16FE4,
+    0x16FF2, 0x16FF7, 0x17000, 0x18CD6, 0x18CFF, 0x18D1F, 0x18D80, 0x18DF3, 0x1AFF0, 0x1AFF4,
+    0x1AFF5, 0x1AFFC, 0x1AFFD, 0x1AFFF, 0x1B000, 0x1B123, 0x1B132, 0x1B133, 0x1B150, 0x1B153,
+    0x1B155, 0x1B156, 0x1B164, 0x1B168, 0x1B170, 0x1B2FC, 0x1BC00, 0x1BC6B, 0x1BC70, 0x1BC7D,
+    0x1BC80, 0x1BC89, 0x1BC90, 0x1BC9A, 0x1BC9C, 0x1BC9D, 0x1BC9F, 0x1BCA0, 0x1CC00, 0x1CCFD,
+    0x1CD00, 0x1CEB4, 0x1CEBA, 0x1CED1, 0x1CEE0, 0x1CEF1, 0x1CF50, 0x1CFC4, 0x1D000, 0x1D0F6,
+    0x1D100, 0x1D127, 0x1D129, 0x1D165, 0x1D16A, 0x1D16D, 0x1D183, 0x1D185, 0x1D18C, 0x1D1AA,
+    0x1D1AE, 0x1D1EB, 0x1D200, 0x1D242, 0x1D245, 0x1D246, 0x1D2C0, 0x1D2D4, 0x1D2E0, 0x1D2F4,
+    0x1D300, 0x1D357, 0x1D360, 0x1D379, 0x1D400, 0x1D455, 0x1D456, 0x1D49D, 0x1D49E, 0x1D4A0,
+    0x1D4A2, 0x1D4A3, 0x1D4A5, 0x1D4A7, 0x1D4A9, 0x1D4AD, 0x1D4AE, 0x1D4BA, 0x1D4BB, 0x1D4BC,
+    0x1D4BD, 0x1D4C4, 0x1D4C5, 0x1D506, 0x1D507, 0x1D50B, 0x1D50D, 0x1D515, 0x1D516, 0x1D51D,
+    0x1D51E, 0x1D53A, 0x1D53B, 0x1D53F, 0x1D540, 0x1D545, 0x1D546, 0x1D547, 0x1D54A, 0x1D551,
+    0x1D552, 0x1D6A6, 0x1D6A8, 0x1D7CC, 0x1D7CE, 0x1DA00, 0x1DA37, 0x1DA3B, 0x1DA6D, 0x1DA75,
+    0x1DA76, 0x1DA84, 0x1DA85, 0x1DA8C, 0x1DF00, 0x1DF1F, 0x1DF25, 0x1DF2B, 0x1E030, 0x1E06E,
+    0x1E100, 0x1E12D, 0x1E137, 0x1E13E, 0x1E140, 0x1E14A, 0x1E14E, 0x1E150, 0x1E290, 0x1E2AE,
+    0x1E2C0, 0x1E2EC, 0x1E2F0, 0x1E2FA, 0x1E2FF, 0x1E300, 0x1E4D0, 0x1E4EC, 0x1E4F0, 0x1E4FA,
+    0x1E5D0, 0x1E5EE, 0x1E5F0, 0x1E5FB, 0x1E5FF, 0x1E600, 0x1E6C0, 0x1E6DF, 0x1E6E0, 0x1E6E3,
+    0x1E6E4, 0x1E6E6, 0x1E6E7, 0x1E6EE, 0x1E6F0, 0x1E6F5, 0x1E6FE, 0x1E700, 0x1E7E0, 0x1E7E7,
+    0x1E7E8, 0x1E7EC, 0x1E7ED, 0x1E7EF, 0x1E7F0, 0x1E7FF, 0x1E800, 0x1E8C5, 0x1E8C7, 0x1E8D0,
+    0x1E900, 0x1E944, 0x1E94B, 0x1E94C, 0x1E950, 0x1E95A, 0x1E95E, 0x1E960, 0x1EC71, 0x1ECB5,
+    0x1ED01, 0x1ED3E, 0x1EE00, 0x1EE04, 0x1EE05, 0x1EE20, 0x1EE21, 0x1EE23, 0x1EE24, 0x1EE25,
+    0x1EE27, 0x1EE28, 0x1EE29, 0x1EE33, 0x1EE34, 0x1EE38, 0x1EE39, 0x1EE3A, 0x1EE3B, 0x1EE3C,
+    0x1EE42, 0x1EE43, 0x1EE47, 0x1EE48, 0x1EE49, 0x1EE4A, 0x1EE4B, 0x1EE4C, 0x1EE4D, 0x1EE50,
+    0x1EE51, 0x1EE53, 0x1EE54, 0x1EE55, 0x1EE57, 0x1EE58, 0x1EE59, 0x1EE5A, 0x1EE5B, 0x1EE5C,
+    0x1EE5D, 0x1EE5E, 0x1EE5F, 0x1EE60, 0x1EE61, 0x1EE63, 0x1EE64, 0x1EE65, 0x1EE67, 0x1EE6B,
+    0x1EE6C, 0x1EE73, 0x1EE74, 0x1EE78, 0x1EE79, 0x1EE7D, 0x1EE7E, 0x1EE7F, 0x1EE80, 0x1EE8A,
+    0x1EE8B, 0x1EE9C, 0x1EEA1, 0x1EEA4, 0x1EEA5, 0x1EEAA, 0x1EEAB, 0x1EEBC, 0x1EEF0, 0x1EEF2,
+    0x1F000, 0x1F02C, 0x1F030, 0x1F094, 0x1F0A0, 0x1F0AF, 0x1F0B1, 0x1F0C0, 0x1F0C1, 0x1F0D0,
+    0x1F0D1, 0x1F0F6, 0x1F100, 0x1F1AE, 0x1F1E6, 0x1F203, 0x1F210, 0x1F23C, 0x1F240, 0x1F249,
+    0x1F250, 0x1F252, 0x1F260, 0x1F266, 0x1F300, 0x1F6D9, 0x1F6DC, 0x1F6ED, 0x1F6F0, 0x1F6FD,
+    0x1F700, 0x1F7DA, 0x1F7E0, 0x1F7EC, 0x1F7F0, 0x1F7F1, 0x1F800, 0x1F80C, 0x1F810, 0x1F848,
+    0x1F850, 0x1F85A, 0x1F860, 0x1F888, 0x1F890, 0x1F8AE, 0x1F8B0, 0x1F8BC, 0x1F8C0, 0x1F8C2,
+    0x1F8D0, 0x1F8D9, 0x1F900, 0x1FA58, 0x1FA60, 0x1FA6E, 0x1FA70, 0x1FA7D, 0x1FA80, 0x1FA8B,
+    0x1FA8E, 0x1FAC7, 0x1FAC8, 0x1FAC9, 0x1FACD, 0x1FADD, 0x1FADF, 0x1FAEB, 0x1FAEF, 0x1FAF9,
+    0x1FB00, 0x1FB93, 0x1FB94, 0x1FBFB, 0x20000, 0x2A6E0, 0x2A700, 0x2B81E, 0x2B820, 0x2CEAE,
+    0x2CEB0, 0x2EBE1, 0x2EBF0, 0x2EE5E, 0x2F800, 0x2FA1E, 0x30000, 0x3134B, 0x31350, 0x3347A,
+];
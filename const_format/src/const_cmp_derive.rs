@@ -0,0 +1,134 @@
+/// Derives const ordering comparison for a type.
+///
+/// Defines a `const fn const_cmp(&self, other: &Self) -> core::cmp::Ordering` inherent method,
+/// which compares the type like the `PartialOrd` derive does:
+/// the fields are compared lexicographically in declaration order,
+/// and the variants of an enum are ordered by their declaration order.
+///
+/// This is the method that the [`assertc_lt`], [`assertc_le`],
+/// [`assertc_gt`], and [`assertc_ge`] macros
+/// use to compare user-defined types
+/// (those macros also require the type to derive [`ConstDebug`]).
+///
+/// # Features
+///
+/// This derive macro is only available with the "derive" and "assertc" features,
+/// and Rust 1.83.0,
+/// because the fields are compared with the `const_cmp` methods of [`PWrapper`].
+///
+/// # Comparing fields
+///
+/// The fields are compared by coercing them the same way that [`coerce_to_fmt`] does,
+/// then calling `const_cmp` on them.
+/// So the types of the fields must be either:
+///
+/// - Standard library types for which [`PWrapper`] wrapping that type has a `const_cmp` method.
+///   This includes all integer types, `char`, `bool`, `&str`,
+///   and slices/arrays of those.
+///
+/// - Non-standard-library types that implement [`FormatMarker`],
+///   and have a `const fn const_cmp(&self, other: &Self) -> Ordering` inherent method,
+///   eg: types that derive both [`ConstDebug`] and this macro.
+///
+/// Arrays and slices of any of those types are compared lexicographically,
+/// while Options are compared with `None` being less than `Some`.
+///
+/// # Attributes
+///
+/// This derive uses the `#[ccmp(...)]` attribute,
+/// which supports the same arguments as the `#[ceq(...)]` attribute of the [`ConstEq`] derive,
+/// except that the functions/macros/wrappers passed to
+/// `with`/`with_macro`/`with_wrapper` must return an `Ordering`
+/// (a wrapper must have a `const_cmp` method).
+///
+/// `#[ccmp(ignore)]` fields are treated as always equal.
+///
+/// # Examples
+///
+/// ### Sorting check
+///
+/// ```rust
+///
+/// use const_format::{ConstCmp, ConstDebug, assertc_lt};
+///
+/// use std::cmp::Ordering;
+///
+/// #[derive(ConstDebug, ConstCmp)]
+/// struct Version {
+///     major: u16,
+///     minor: u16,
+/// }
+///
+/// const fn version(major: u16, minor: u16) -> Version {
+///     Version { major, minor }
+/// }
+///
+/// const VERSIONS: &[Version] = &[
+///     version(1, 0),
+///     version(1, 2),
+///     version(1, 10),
+///     version(2, 0),
+/// ];
+///
+/// const fn is_sorted(versions: &[Version]) -> bool {
+///     let mut i = 1;
+///     while i < versions.len() {
+///         if let Ordering::Greater = versions[i - 1].const_cmp(&versions[i]) {
+///             return false;
+///         }
+///         i += 1;
+///     }
+///     true
+/// }
+///
+/// const _: () = assert!(is_sorted(VERSIONS));
+///
+/// assertc_lt!(version(1, 2), version(1, 10));
+///
+/// ```
+///
+/// ### Enums
+///
+/// ```rust
+///
+/// use const_format::ConstCmp;
+///
+/// use std::cmp::Ordering;
+///
+/// #[derive(ConstCmp)]
+/// enum Level {
+///     Off,
+///     Some { level: u8 },
+///     Named(&'static str),
+/// }
+///
+/// const CMPS: [Ordering; 4] = [
+///     Level::Off.const_cmp(&Level::Some { level: 0 }),
+///     Level::Some { level: 5 }.const_cmp(&Level::Some { level: 3 }),
+///     Level::Named("debug").const_cmp(&Level::Named("trace")),
+///     Level::Named("").const_cmp(&Level::Off),
+/// ];
+///
+/// assert_eq!(
+///     CMPS,
+///     [Ordering::Less, Ordering::Greater, Ordering::Less, Ordering::Greater],
+/// );
+///
+/// ```
+///
+/// [`ConstDebug`]: ./derive.ConstDebug.html
+/// [`ConstEq`]: ./derive.ConstEq.html
+/// [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
+/// [`PWrapper`]: ./struct.PWrapper.html
+/// [`assertc_lt`]: ./macro.assertc_lt.html
+/// [`assertc_le`]: ./macro.assertc_le.html
+/// [`assertc_gt`]: ./macro.assertc_gt.html
+/// [`assertc_ge`]: ./macro.assertc_ge.html
+/// [`coerce_to_fmt`]: ./macro.coerce_to_fmt.html
+///
+#[cfg_attr(
+    feature = "__docsrs",
+    doc(cfg(all(feature = "derive", feature = "assertc")))
+)]
+#[cfg(all(feature = "derive", feature = "assertc"))]
+pub use const_format_proc_macros::ConstCmp;
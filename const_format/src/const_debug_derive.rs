@@ -64,6 +64,70 @@
 /// In this example, there's exactly three impls of 
 /// the `const_debug_fmt` method and [`FormatMarker`] trait.
 ///
+/// <span id = "cdeb_non_exhaustive"> </span>
+///
+/// ### `#[cdeb(non_exhaustive)]`
+///
+/// Prints a trailing `..` in the variants that have [`#[cdeb(ignore)]`](#cdeb_ignore) fields,
+/// like std's `DebugStruct::finish_non_exhaustive` method does.
+///
+/// Example:
+///
+/// ```rust
+/// use const_format::{formatc, ConstDebug};
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(non_exhaustive)]
+/// struct Foo {
+///     a: u32,
+///     #[cdeb(ignore)]
+///     b: [u8; 64],
+/// }
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(non_exhaustive)]
+/// enum Bar {
+///     Baz(u32, #[cdeb(ignore)] &'static [u8]),
+///     Qux(u32),
+/// }
+///
+/// const FOO: &str = formatc!("{:?}", Foo { a: 3, b: [0; 64] });
+/// const BAZ: &str = formatc!("{:?}", Bar::Baz(5, &[8, 13]));
+/// const QUX: &str = formatc!("{:?}", Bar::Qux(21));
+///
+/// assert_eq!(FOO, "Foo { a: 3, .. }");
+/// assert_eq!(BAZ, "Baz(5, ..)");
+/// assert_eq!(QUX, "Qux(21)");
+///
+/// ```
+///
+/// ### `#[cdeb(std_debug)]`
+///
+/// Also implements the [`core::fmt::Debug`] trait,
+/// by formatting with the `const_debug_fmt` method through a [`StdAdapter`].
+///
+/// With the [`#[cdeb(impls(....))]`](#cdebimpls) attribute,
+/// this implements `Debug` for each of the listed types.
+///
+/// Example:
+///
+/// ```rust
+/// use const_format::{formatc, ConstDebug};
+///
+/// #[derive(ConstDebug)]
+/// #[cdeb(std_debug)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// const POINT: Point = Point { x: 3, y: 5 };
+///
+/// assert_eq!(formatc!("{:?}", POINT), "Point { x: 3, y: 5 }");
+/// assert_eq!(format!("{:?}", POINT), "Point { x: 3, y: 5 }");
+///
+/// ```
+///
 /// ### `#[cdeb(crate = "foo::bar")]`
 ///
 /// The path to the `const_format` crate, useful if you want to reexport the ConstDebug macro,
@@ -76,9 +140,12 @@
 ///
 /// # Field attributes
 ///
+/// <span id = "cdeb_ignore"> </span>
+///
 /// ### `#[cdeb(ignore)]`
 ///
-/// Ignores the field, pretending that it doesn't exist.
+/// Ignores the field, pretending that it doesn't exist,
+/// unless the [`#[cdeb(non_exhaustive)]`](#cdeb_non_exhaustive) attribute is used.
 ///
 /// ### `#[cdeb(with = "module::function")]`
 ///
@@ -314,6 +381,7 @@
 ///
 /// [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
 /// [`impls attribute`]: #cdebimpls
+/// [`StdAdapter`]: ./fmt/struct.StdAdapter.html
 ///
 ///
 ///
@@ -0,0 +1,148 @@
+/// Derives const display formatting for a type, from format strings in attributes.
+///
+/// Defines an `const_display_fmt` inherent method to format a type at compile-time,
+/// and optionally derives the [`FormatMarker`] trait.
+///
+/// # Features
+///
+/// This derive macro is only available with the "derive" feature,
+/// and Rust 1.83.0, because it uses mutable references in const.
+///
+/// # Format strings
+///
+/// The format string of a struct goes on the struct itself,
+/// while the format strings of an enum go on each of its variants.
+///
+/// The format strings use the same syntax as [`formatc`],
+/// with these differences in what the arguments refer to:
+///
+/// - Named arguments (eg: `"{foo}"`) refer to the fields of a braced struct/variant,
+///   and to constants in scope if there is no field with that name.
+///
+/// - Positional arguments (eg: `"{}"`, `"{0}"`) refer to the fields of
+///   a tuple struct/variant.
+///
+/// The fields are formatted by reference,
+/// so their types must implement const formatting like the arguments of [`formatc`].
+///
+/// # Container Attributes
+///
+/// These attributes go on the type itself, rather than the variants.
+///
+/// ### `#[cdisplay("...")]`
+///
+/// The format string of a struct.
+///
+/// ### `#[cdisplay(debug_print)]`
+///
+/// Panics with the output of the expanded derive.
+///
+/// ### `#[cdisplay(impls(....))]`
+///
+/// Allows users to implement const display formatting for multiple different
+/// concrete instances of the type,
+/// it works the same as the `impls` attribute of the [`ConstDebug`] derive.
+///
+/// ### `#[cdisplay(format_marker)]`
+///
+/// Derives the [`FormatMarker`] trait, which is required to format the type with
+/// the formatting macros (eg: [`formatc`]).
+///
+/// This is not the default because the [`ConstDebug`] derive implements [`FormatMarker`] too,
+/// so this attribute must only be used when `ConstDisplay` is derived without `ConstDebug`.
+///
+/// ### `#[cdisplay(crate = "foo::bar")]`
+///
+/// The path to the `const_format` crate, useful if you want to reexport the ConstDisplay macro,
+/// or rename the `const_format` crate in the Cargo.toml .
+///
+/// # Variant attributes
+///
+/// ### `#[cdisplay("...")]`
+///
+/// The format string of an enum variant.
+///
+/// # Examples
+///
+/// ### Error enum
+///
+/// ```rust
+///
+/// use const_format::{ConstDisplay, formatc};
+///
+/// #[derive(ConstDisplay)]
+/// #[cdisplay(format_marker)]
+/// enum ParseError {
+///     #[cdisplay("unexpected character {found:?} at byte {index}")]
+///     Unexpected { found: char, index: usize },
+///     #[cdisplay("the input is longer than {MAX_LEN} bytes")]
+///     TooLong,
+///     #[cdisplay("expected {0} digits, found {1}")]
+///     Digits(u8, u8),
+/// }
+///
+/// const MAX_LEN: usize = 64;
+///
+/// const UNEXPECTED: &str = formatc!("{}", ParseError::Unexpected { found: '-', index: 3 });
+/// const TOO_LONG: &str = formatc!("{}", ParseError::TooLong);
+/// const DIGITS: &str = formatc!("error: {}", ParseError::Digits(4, 2));
+///
+/// assert_eq!(UNEXPECTED, "unexpected character '-' at byte 3");
+/// assert_eq!(TOO_LONG, "the input is longer than 64 bytes");
+/// assert_eq!(DIGITS, "error: expected 4 digits, found 2");
+///
+/// ```
+///
+/// ### Deriving both `ConstDebug` and `ConstDisplay`
+///
+/// ```rust
+///
+/// use const_format::{ConstDebug, ConstDisplay, formatc};
+///
+/// #[derive(ConstDebug, ConstDisplay)]
+/// #[cdisplay("{major}.{minor}.{patch}")]
+/// struct Version {
+///     major: u16,
+///     minor: u16,
+///     patch: u16,
+/// }
+///
+/// const VERSION: Version = Version { major: 1, minor: 57, patch: 0 };
+///
+/// assert_eq!(formatc!("{}", VERSION), "1.57.0");
+/// assert_eq!(
+///     formatc!("{:?}", VERSION),
+///     "Version { major: 1, minor: 57, patch: 0 }",
+/// );
+///
+/// ```
+///
+/// ### Formatting options
+///
+/// ```rust
+///
+/// use const_format::{ConstDisplay, formatc};
+///
+/// #[derive(ConstDisplay)]
+/// #[cdisplay("#{0:02X}{1:02X}{2:02X}", format_marker)]
+/// struct Rgb(u8, u8, u8);
+///
+/// #[derive(ConstDisplay)]
+/// #[cdisplay("[{name:>width$}]", format_marker)]
+/// struct Padded {
+///     name: &'static str,
+///     width: usize,
+/// }
+///
+/// assert_eq!(formatc!("{}", Rgb(255, 128, 0)), "#FF8000");
+/// assert_eq!(formatc!("{}", Padded { name: "foo", width: 5 }), "[  foo]");
+///
+/// ```
+///
+/// [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
+/// [`ConstDebug`]: ./derive.ConstDebug.html
+/// [`formatc`]: ./macro.formatc.html
+///
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use const_format_proc_macros::ConstDisplay;
@@ -0,0 +1,219 @@
+/// Derives const equality comparison for a type.
+///
+/// Defines a `const fn const_eq(&self, other: &Self) -> bool` inherent method,
+/// which compares the type field-by-field,
+/// returning false if `self` and `other` are different enum variants.
+///
+/// This is the method that the [`assertc_eq`] and [`assertc_ne`] macros
+/// use to compare user-defined types
+/// (those macros also require the type to derive [`ConstDebug`]).
+///
+/// # Features
+///
+/// This derive macro is only available with the "derive" and "assertc" features,
+/// and Rust 1.83.0,
+/// because the fields are compared with the same `const_eq` methods as [`assertc_eq`].
+///
+/// # Comparing fields
+///
+/// The fields are compared by coercing them the same way that [`coerce_to_fmt`] does,
+/// then calling `const_eq` on them.
+/// So the types of the fields must be either:
+///
+/// - Standard library types for which [`PWrapper`] wrapping that type has a `const_eq` method.
+///   This includes all integer types, `&str`, slices/arrays of integers/`&str`,
+///   Options of integers/`&str`, etc.
+///
+/// - Non-standard-library types that implement [`FormatMarker`],
+///   and have a `const fn const_eq(&self, other: &Self) -> bool` inherent method,
+///   eg: types that derive both [`ConstDebug`] and this macro.
+///
+/// Arrays, slices, and Options are detected by the syntax of the field type,
+/// comparing their elements individually,
+/// the `#[ceq(is_a(...))]` attribute can be used when they are not detected.
+///
+/// # Container Attributes
+///
+/// These attributes go on the type itself, rather than the fields.
+///
+/// ### `#[ceq(debug_print)]`
+///
+/// Panics with the output of the expanded derive.
+///
+/// ### `#[ceq(impls(....))]`
+///
+/// Allows users to define the `const_eq` method for multiple different
+/// concrete instances of the type,
+/// it works the same as the `impls` attribute of the [`ConstDebug`] derive.
+///
+/// ### `#[ceq(crate = "foo::bar")]`
+///
+/// The path to the `const_format` crate, useful if you want to reexport the ConstEq macro,
+/// or rename the `const_format` crate in the Cargo.toml .
+///
+/// # Field attributes
+///
+/// ### `#[ceq(ignore)]`
+///
+/// Doesn't compare the field, treating it as always equal.
+///
+/// ### `#[ceq(is_a(....))]`
+///
+/// Gives the derive macro a hint of what the type is,
+/// accepting the same arguments as the `is_a` attribute of the [`ConstDebug`] derive:
+///
+/// - `array`/`slice`: compares the length, and then the elements one by one.
+///
+/// - `Option`/`option`: compares the values if both are `Some`.
+///
+/// - `newtype`: compares the single field of a single-field tuple struct.
+///
+/// - `non_std`/`not_std`: compares the field as a non-standard-library type.
+///
+/// ### `#[ceq(with = "module::function")]`
+///
+/// Compares the field with the passed-in function,
+/// which must be callable as `function(&field_of_self, &field_of_other)`,
+/// returning a `bool`.
+///
+/// ### `#[ceq(with_wrapper = "module::Wrapper")]`
+///
+/// Compares the field with the `const_eq` method of the `Wrapper` single-field tuple struct,
+/// which wraps a reference to the field,
+/// called as `Wrapper(&field_of_self).const_eq(&Wrapper(&field_of_other))`.
+///
+/// ### `#[ceq(with_macro = "module::the_macro")]`
+///
+/// Compares the field with the passed-in macro,
+/// which must be callable as `the_macro!(&field_of_self, &field_of_other)`,
+/// evaluating to a `bool`.
+///
+/// # Examples
+///
+/// ### Basic
+///
+/// ```rust
+///
+/// use const_format::{ConstDebug, ConstEq};
+///
+/// // `ConstDebug` is derived so that `Point` can be a field of `Shape`
+/// #[derive(ConstDebug, ConstEq)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+///     label: &'static str,
+/// }
+///
+/// #[derive(ConstEq)]
+/// enum Shape {
+///     Circle(Point, u32),
+///     Polygon { vertices: &'static [Point] },
+///     Empty,
+/// }
+///
+/// const fn point(x: u32, y: u32) -> Point {
+///     Point { x, y, label: "" }
+/// }
+///
+/// const CIRCLE: Shape = Shape::Circle(point(3, 5), 8);
+/// const TRIANGLE: Shape = Shape::Polygon { vertices: &[point(0, 0), point(4, 0), point(0, 3)] };
+///
+/// const ARE_EQ: [bool; 5] = [
+///     CIRCLE.const_eq(&Shape::Circle(point(3, 5), 8)),
+///     CIRCLE.const_eq(&Shape::Circle(point(3, 5), 9)),
+///     CIRCLE.const_eq(&TRIANGLE),
+///     TRIANGLE.const_eq(&Shape::Polygon { vertices: &[point(0, 0), point(4, 0)] }),
+///     Shape::Empty.const_eq(&Shape::Empty),
+/// ];
+///
+/// assert_eq!(ARE_EQ, [true, false, false, false, true]);
+///
+/// ```
+///
+/// ### Assertions
+///
+/// This example demonstrates how this derive is used alongside [`ConstDebug`]
+/// to compare user-defined types with [`assertc_eq`] and [`assertc_ne`].
+///
+/// ```rust
+///
+/// use const_format::{assertc_eq, assertc_ne, ConstDebug, ConstEq};
+///
+/// #[derive(ConstDebug, ConstEq)]
+/// struct Config {
+///     name: &'static str,
+///     retries: Option<u8>,
+///     #[cdeb(ignore)]
+///     #[ceq(ignore)]
+///     cache: [u64; 4],
+/// }
+///
+/// const DEFAULT: Config = Config { name: "default", retries: Some(3), cache: [0; 4] };
+///
+/// assertc_eq!(
+///     DEFAULT,
+///     Config { name: "default", retries: Some(3), cache: [1; 4] },
+/// );
+///
+/// assertc_ne!(DEFAULT, Config { name: "default", retries: None, cache: [0; 4] });
+///
+/// ```
+///
+/// ### Custom comparison
+///
+/// ```rust
+///
+/// use const_format::ConstEq;
+///
+/// macro_rules! approx_eq {
+///     ($left:expr, $right:expr) => {
+///         (*$left / 10) == (*$right / 10)
+///     };
+/// }
+///
+/// #[derive(ConstEq)]
+/// struct Word {
+///     #[ceq(with = "eq_ignore_case")]
+///     text: &'static str,
+///     #[ceq(with_macro = "approx_eq")]
+///     weight: u64,
+/// }
+///
+/// const fn eq_ignore_case(left: &&str, right: &&str) -> bool {
+///     let (left, right) = (left.as_bytes(), right.as_bytes());
+///     if left.len() != right.len() {
+///         return false;
+///     }
+///     let mut i = 0;
+///     while i < left.len() {
+///         if left[i].to_ascii_lowercase() != right[i].to_ascii_lowercase() {
+///             return false;
+///         }
+///         i += 1;
+///     }
+///     true
+/// }
+///
+/// const ARE_EQ: [bool; 3] = [
+///     Word { text: "Hello", weight: 31 }.const_eq(&Word { text: "hELLO", weight: 35 }),
+///     Word { text: "Hello", weight: 31 }.const_eq(&Word { text: "Hell", weight: 31 }),
+///     Word { text: "Hello", weight: 31 }.const_eq(&Word { text: "Hello", weight: 41 }),
+/// ];
+///
+/// assert_eq!(ARE_EQ, [true, false, false]);
+///
+/// ```
+///
+/// [`ConstDebug`]: ./derive.ConstDebug.html
+/// [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
+/// [`PWrapper`]: ./struct.PWrapper.html
+/// [`assertc_eq`]: ./macro.assertc_eq.html
+/// [`assertc_ne`]: ./macro.assertc_ne.html
+/// [`coerce_to_fmt`]: ./macro.coerce_to_fmt.html
+///
+#[cfg_attr(
+    feature = "__docsrs",
+    doc(cfg(all(feature = "derive", feature = "assertc")))
+)]
+#[cfg(all(feature = "derive", feature = "assertc"))]
+pub use const_format_proc_macros::ConstEq;
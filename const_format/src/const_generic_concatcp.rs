@@ -12,11 +12,64 @@ pub const fn __priv_concatenate<const LEN: usize>(input: &[PArgument]) -> LenAnd
     crate::__for_range! { outer_i in 0..input.len() =>
         let current = &input[outer_i];
 
+        let fill = crate::char_encoding::char_to_display(current.fmt_flags.fill());
+        crate::__write_pvariant!(fill, fill, current.padding.before => out);
+
         match current.elem {
             PVariant::Str(s) => crate::__write_pvariant!(str, current, s => out),
             PVariant::Int(int) => crate::__write_pvariant!(int, current, int => out),
             PVariant::Char(c) => crate::__write_pvariant!(char, current, c => out),
+            PVariant::Float(f) => crate::__write_pvariant!(float, current, f => out),
+            PVariant::Bytes(b) => crate::__write_pvariant!(bytes, current, b => out),
+            PVariant::Byte(b) => crate::__write_pvariant!(byte, current, b => out),
+        }
+
+        crate::__write_pvariant!(fill, fill, current.padding.after => out);
+    }
+
+    out
+}
+
+/// Concatenates `input` into an array with a capacity of `CAP` bytes,
+/// panicking if the concatenated string is longer than that.
+#[doc(hidden)]
+pub const fn __priv_concatenate_cap<const CAP: usize>(
+    input: &[PArgument],
+) -> LenAndArray<[u8; CAP]> {
+    if PArgument::calc_len(input) > CAP {
+        panic!("the formatted string is longer than the capacity passed to the macro");
+    }
+
+    __priv_concatenate(input)
+}
+
+/// Gets the written part of the array that `__priv_concatenate_cap` returned.
+#[doc(hidden)]
+pub const fn __priv_cap_to_str<const CAP: usize>(arr: &LenAndArray<[u8; CAP]>) -> &str {
+    let mut bytes: &[u8] = &arr.array;
+    while bytes.len() > arr.len {
+        if let [rem @ .., _] = bytes {
+            bytes = rem;
+        }
+    }
+
+    // Safety: `__priv_concatenate` only writes complete utf8 sequences.
+    unsafe { core::str::from_utf8_unchecked(bytes) }
+}
+
+/// Copies `string` into a nul-terminated array of `LEN` bytes,
+/// panicking if `string` contains a nul byte.
+#[doc(hidden)]
+#[cfg(feature = "rust_1_64")]
+pub const fn __priv_to_cstr_bytes<const LEN: usize>(string: &str) -> [u8; LEN] {
+    let string = string.as_bytes();
+    let mut out = [0u8; LEN];
+
+    crate::__for_range! { i in 0..string.len() =>
+        if string[i] == 0 {
+            panic!("the arguments contain an interior nul byte, which can't be in a CStr");
         }
+        out[i] = string[i];
     }
 
     out
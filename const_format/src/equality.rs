@@ -70,6 +70,44 @@ slice_of_equal_op_impl! {
     usize, isize,
 }
 
+macro_rules! slice_of_const_cmp {($($elem:ty),* $(,)?) => (
+    $(
+        impl PWrapper<&[$elem]> {
+            /// Compares `self` and `other` lexicographically.
+            ///
+            /// This method is only available with the "assertc" feature.
+            pub const fn const_cmp(&self, other: &[$elem]) -> Ordering {
+                let min_len = if self.0.len() < other.len() {
+                    self.0.len()
+                } else {
+                    other.len()
+                };
+
+                __for_range!{i in 0..min_len =>
+                    match PWrapper(self.0[i]).const_cmp(&other[i]) {
+                        Ordering::Equal => {}
+                        ordering => return ordering,
+                    }
+                }
+
+                PWrapper(self.0.len()).const_cmp(&other.len())
+            }
+        }
+    )*
+)}
+
+slice_of_const_cmp! {
+    &str,
+    bool,
+    char,
+    u8, i8,
+    u16, i16,
+    u32, i32,
+    u64, i64,
+    u128, i128,
+    usize, isize,
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 macro_rules! impl_eq_for_option_prim {
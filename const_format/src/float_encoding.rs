@@ -0,0 +1,860 @@
+//! Formatting of `f32` and `f64`, in const contexts.
+//!
+//! This writes the shortest decimal representation that round-trips
+//! back to the same float, or the exact value rounded to a precision,
+//! using the same algorithm as `core::fmt`
+//! (the Dragon4-based exact algorithm, with big integers).
+//!
+//! This also does the exponential formatting of integers,
+//! since it's written the same way as that of floats.
+
+use crate::formatting::{FormattingFlags, HexFormatting, NumberFormatting};
+
+use core::cmp::Ordering;
+
+#[cfg(all(test, not(miri)))]
+mod tests;
+
+/// The maximum amount of significant digits in the exact decimal value of an `f64`,
+/// the shortest round-tripping digits only need up to 17 digits.
+const MAX_DIGITS: usize = 767;
+
+/// Describes the bit representation of a floating point type.
+struct FloatInfo {
+    mantissa_bits: u32,
+    exponent_bits: u32,
+    /// The bits of the positive numbers that `Debug` formatting writes
+    /// with an exponent when they are smaller than it.
+    debug_exp_lower: u64,
+    /// The bits of the positive numbers that `Debug` formatting writes
+    /// with an exponent when they are greater than or equal to it.
+    debug_exp_upper: u64,
+}
+
+const F32_INFO: FloatInfo = FloatInfo {
+    mantissa_bits: 23,
+    exponent_bits: 8,
+    // 1e-4f32
+    debug_exp_lower: 0x38d1b717,
+    // 1e16f32
+    debug_exp_upper: 0x5a0e1bca,
+};
+
+const F64_INFO: FloatInfo = FloatInfo {
+    mantissa_bits: 52,
+    exponent_bits: 11,
+    // 1e-4f64
+    debug_exp_lower: 0x3f1a36e2eb1c432d,
+    // 1e16f64
+    debug_exp_upper: 0x4341c37937e08000,
+};
+
+#[cfg(feature = "rust_1_83")]
+#[allow(clippy::incompatible_msrv)]
+pub(crate) const fn f32_to_bits(n: f32) -> u32 {
+    n.to_bits()
+}
+
+#[cfg(feature = "rust_1_83")]
+#[allow(clippy::incompatible_msrv)]
+pub(crate) const fn f64_to_bits(n: f64) -> u64 {
+    n.to_bits()
+}
+
+// `to_bits` is only a const fn since Rust 1.83
+#[cfg(not(feature = "rust_1_83"))]
+#[allow(unknown_lints, unnecessary_transmutes)]
+pub(crate) const fn f32_to_bits(n: f32) -> u32 {
+    // Safety: all bit patterns are valid `u32`s, and `f32` is the same size as `u32`
+    unsafe { core::mem::transmute::<f32, u32>(n) }
+}
+
+#[cfg(not(feature = "rust_1_83"))]
+#[allow(unknown_lints, unnecessary_transmutes)]
+pub(crate) const fn f64_to_bits(n: f64) -> u64 {
+    // Safety: all bit patterns are valid `u64`s, and `f64` is the same size as `u64`
+    unsafe { core::mem::transmute::<f64, u64>(n) }
+}
+
+pub(crate) const fn f32_to_display(n: f32, flags: FormattingFlags) -> FmtFloat {
+    FmtFloat::display(f32_to_bits(n) as u64, &F32_INFO, flags)
+}
+
+pub(crate) const fn f32_to_debug(n: f32, flags: FormattingFlags) -> FmtFloat {
+    FmtFloat::debug(f32_to_bits(n) as u64, &F32_INFO, flags)
+}
+
+pub(crate) const fn f64_to_display(n: f64, flags: FormattingFlags) -> FmtFloat {
+    FmtFloat::display(f64_to_bits(n), &F64_INFO, flags)
+}
+
+pub(crate) const fn f64_to_debug(n: f64, flags: FormattingFlags) -> FmtFloat {
+    FmtFloat::debug(f64_to_bits(n), &F64_INFO, flags)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The number of `u32`s in a `BigUint`,
+/// enough to hold `f64::MAX` and `10^324 * 2^55` multiplied by 10.
+const BIG_LIMBS: usize = 40;
+
+/// An unsigned integer big enough to do exact arithmetic with any finite `f64`.
+#[derive(Copy, Clone)]
+struct BigUint {
+    /// The digits of the integer, in base `2^32`, least significant first.
+    limbs: [u32; BIG_LIMBS],
+}
+
+impl BigUint {
+    const fn from_u64(n: u64) -> Self {
+        let mut limbs = [0; BIG_LIMBS];
+        limbs[0] = n as u32;
+        limbs[1] = (n >> 32) as u32;
+        Self { limbs }
+    }
+
+    const fn mul_small(mut self, m: u32) -> Self {
+        let mut carry = 0u64;
+        let mut i = 0;
+        while i < BIG_LIMBS {
+            let x = (self.limbs[i] as u64) * (m as u64) + carry;
+            self.limbs[i] = x as u32;
+            carry = x >> 32;
+            i += 1;
+        }
+        self
+    }
+
+    const fn mul_pow10(mut self, mut n: u32) -> Self {
+        while n >= 9 {
+            self = self.mul_small(1_000_000_000);
+            n -= 9;
+        }
+        self.mul_small(10u32.pow(n))
+    }
+
+    const fn mul_pow2(self, n: u32) -> Self {
+        let limb_shift = (n / 32) as usize;
+        let bit_shift = n % 32;
+
+        let mut limbs = [0; BIG_LIMBS];
+        let mut i = limb_shift;
+        while i < BIG_LIMBS {
+            let from = i - limb_shift;
+            limbs[i] = self.limbs[from] << bit_shift;
+            if bit_shift != 0 && from != 0 {
+                limbs[i] |= self.limbs[from - 1] >> (32 - bit_shift);
+            }
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    const fn add(mut self, other: &Self) -> Self {
+        let mut carry = 0u64;
+        let mut i = 0;
+        while i < BIG_LIMBS {
+            let x = (self.limbs[i] as u64) + (other.limbs[i] as u64) + carry;
+            self.limbs[i] = x as u32;
+            carry = x >> 32;
+            i += 1;
+        }
+        self
+    }
+
+    /// Subtracts `other` from `self`, requires `self >= other`.
+    const fn sub(mut self, other: &Self) -> Self {
+        let mut borrow = 0u64;
+        let mut i = 0;
+        while i < BIG_LIMBS {
+            let x = (self.limbs[i] as u64)
+                .wrapping_sub(other.limbs[i] as u64)
+                .wrapping_sub(borrow);
+            self.limbs[i] = x as u32;
+            borrow = (x >> 63) & 1;
+            i += 1;
+        }
+        self
+    }
+
+    const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < BIG_LIMBS {
+            if self.limbs[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const fn cmp(&self, other: &Self) -> Ordering {
+        let mut i = BIG_LIMBS;
+        while i != 0 {
+            i -= 1;
+            if self.limbs[i] < other.limbs[i] {
+                return Ordering::Less;
+            } else if self.limbs[i] > other.limbs[i] {
+                return Ordering::Greater;
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+/// Whether `l` is less than `r`, or equal if `inclusive` is true.
+const fn is_lt_or_le(l: &BigUint, r: &BigUint, inclusive: bool) -> bool {
+    match l.cmp(r) {
+        Ordering::Less => true,
+        Ordering::Equal => inclusive,
+        Ordering::Greater => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// A finite, non-zero float, decoded into `mant * 2^exp`,
+/// with `(mant - minus) * 2^exp` and `(mant + plus) * 2^exp` being
+/// the bounds of the values that round to it.
+#[derive(Copy, Clone)]
+struct Decoded {
+    mant: u64,
+    minus: u64,
+    plus: u64,
+    exp: i16,
+    /// Whether the bounds themselves round to this float.
+    inclusive: bool,
+}
+
+#[derive(Copy, Clone)]
+enum FullDecoded {
+    Nan,
+    Infinite,
+    Zero,
+    Finite(Decoded),
+}
+
+/// Decodes the bits of a float into its sign and value.
+const fn decode(bits: u64, info: &FloatInfo) -> (bool, FullDecoded) {
+    let mantissa_mask = (1u64 << info.mantissa_bits) - 1;
+    let max_biased_exp = (1u64 << info.exponent_bits) - 1;
+    let exp_bias = (max_biased_exp >> 1) as i16;
+
+    let is_negative = (bits >> (info.mantissa_bits + info.exponent_bits)) & 1 == 1;
+    let biased_exp = (bits >> info.mantissa_bits) & max_biased_exp;
+    let fraction = bits & mantissa_mask;
+
+    // The mantissa and exponent are computed the same way that
+    // `integer_decode` does it in `core`,
+    // so that the output is the same as std's.
+    let min_normal_mant = 1u64 << info.mantissa_bits;
+    let (mant, exp) = if biased_exp == 0 {
+        (fraction << 1, -exp_bias - info.mantissa_bits as i16)
+    } else {
+        (
+            fraction | min_normal_mant,
+            biased_exp as i16 - exp_bias - info.mantissa_bits as i16,
+        )
+    };
+    let inclusive = mant & 1 == 0;
+
+    let decoded = if biased_exp == max_biased_exp {
+        if fraction == 0 {
+            FullDecoded::Infinite
+        } else {
+            FullDecoded::Nan
+        }
+    } else if biased_exp == 0 {
+        if fraction == 0 {
+            FullDecoded::Zero
+        } else {
+            FullDecoded::Finite(Decoded {
+                mant,
+                minus: 1,
+                plus: 1,
+                exp,
+                inclusive,
+            })
+        }
+    } else if mant == min_normal_mant {
+        // the float below this one is closer than the one above
+        FullDecoded::Finite(Decoded {
+            mant: mant << 2,
+            minus: 1,
+            plus: 2,
+            exp: exp - 2,
+            inclusive,
+        })
+    } else {
+        FullDecoded::Finite(Decoded {
+            mant: mant << 1,
+            minus: 1,
+            plus: 1,
+            exp: exp - 1,
+            inclusive,
+        })
+    };
+
+    (is_negative, decoded)
+}
+
+/// Computes `k` such that `10^(k-1) < mant * 2^exp <= 10^(k+1)`.
+const fn estimate_scaling_factor(mant: u64, exp: i16) -> i16 {
+    let nbits = 64 - (mant - 1).leading_zeros() as i64;
+    // 1292913986 = floor(2^32 * log_10 2)
+    (((nbits + exp as i64) * 1292913986) >> 32) as i16
+}
+
+/// The decimal digits of a float, the value being `0.<digits> * 10^exp`.
+#[derive(Copy, Clone)]
+struct Digits {
+    digits: [u8; MAX_DIGITS],
+    len: usize,
+    exp: i16,
+}
+
+/// Computes the shortest digits that round-trip back to the decoded float.
+const fn shortest_digits(d: Decoded) -> Digits {
+    let inclusive = d.inclusive;
+    let mut k = estimate_scaling_factor(d.mant + d.plus, d.exp);
+
+    // The value is `mant / scale`, the bounds are `(mant - minus) / scale`
+    // and `(mant + plus) / scale`.
+    let mut mant = BigUint::from_u64(d.mant);
+    let mut minus = BigUint::from_u64(d.minus);
+    let mut plus = BigUint::from_u64(d.plus);
+    let mut scale = BigUint::from_u64(1);
+
+    if d.exp < 0 {
+        scale = scale.mul_pow2(-d.exp as u32);
+    } else {
+        mant = mant.mul_pow2(d.exp as u32);
+        minus = minus.mul_pow2(d.exp as u32);
+        plus = plus.mul_pow2(d.exp as u32);
+    }
+
+    if k >= 0 {
+        scale = scale.mul_pow10(k as u32);
+    } else {
+        mant = mant.mul_pow10(-k as u32);
+        minus = minus.mul_pow10(-k as u32);
+        plus = plus.mul_pow10(-k as u32);
+    }
+
+    // Making sure that `scale < mant + plus <= scale * 10`
+    if is_lt_or_le(&scale, &mant.add(&plus), inclusive) {
+        k += 1;
+    } else {
+        mant = mant.mul_small(10);
+        minus = minus.mul_small(10);
+        plus = plus.mul_small(10);
+    }
+
+    let mut digits = [b'0'; MAX_DIGITS];
+    let mut len = 0;
+
+    let (down, up) = loop {
+        let mut digit = 0;
+        while let Ordering::Greater | Ordering::Equal = mant.cmp(&scale) {
+            mant = mant.sub(&scale);
+            digit += 1;
+        }
+        digits[len] = b'0' + digit;
+        len += 1;
+
+        let down = is_lt_or_le(&mant, &minus, inclusive);
+        let up = is_lt_or_le(&scale, &mant.add(&plus), inclusive);
+        if down || up {
+            break (down, up);
+        }
+
+        mant = mant.mul_small(10);
+        minus = minus.mul_small(10);
+        plus = plus.mul_small(10);
+    };
+
+    // Rounds up if the remainder is closer to the next digit,
+    // or if it's exactly halfway.
+    let round_up = up
+        && (!down
+            || matches!(
+                mant.mul_pow2(1).cmp(&scale),
+                Ordering::Greater | Ordering::Equal
+            ));
+
+    if round_up {
+        let mut i = len;
+        loop {
+            if i == 0 {
+                // All the digits were nines, turning `999` into `1000`.
+                digits[0] = b'1';
+                digits[len] = b'0';
+                len += 1;
+                k += 1;
+                break;
+            }
+            i -= 1;
+            if digits[i] == b'9' {
+                digits[i] = b'0';
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+    }
+
+    Digits {
+        digits,
+        len,
+        exp: k,
+    }
+}
+
+/// How many digits `exact_digits` computes.
+#[derive(Copy, Clone)]
+enum DigitLimit {
+    /// The amount of digits after the decimal point.
+    Fractional(usize),
+    /// The amount of significant digits.
+    Significant(usize),
+}
+
+/// Computes the digits of the exact value of the decoded float,
+/// rounded to the `limit` amount of digits (rounding ties to even).
+///
+/// The returned digits don't include trailing zeros.
+const fn exact_digits(d: Decoded, limit: DigitLimit) -> Digits {
+    let mut k = estimate_scaling_factor(d.mant, d.exp);
+
+    // The value is `mant / scale`
+    let mut mant = BigUint::from_u64(d.mant);
+    let mut scale = BigUint::from_u64(1);
+
+    if d.exp < 0 {
+        scale = scale.mul_pow2(-d.exp as u32);
+    } else {
+        mant = mant.mul_pow2(d.exp as u32);
+    }
+
+    if k >= 0 {
+        scale = scale.mul_pow10(k as u32);
+    } else {
+        mant = mant.mul_pow10(-k as u32);
+    }
+
+    // Making sure that `mant < scale`,
+    // so that the value is `0.<digits> * 10^k`, with a non-zero first digit.
+    while let Ordering::Greater | Ordering::Equal = mant.cmp(&scale) {
+        scale = scale.mul_small(10);
+        k += 1;
+    }
+
+    // The amount of digits up to the last digit that's written.
+    let limit = match limit {
+        DigitLimit::Fractional(frac_digits) => k as isize + frac_digits as isize,
+        DigitLimit::Significant(sig_digits) => sig_digits as isize,
+    };
+
+    let mut digits = [b'0'; MAX_DIGITS];
+    let mut len = 0;
+
+    while (len as isize) < limit && !mant.is_zero() {
+        mant = mant.mul_small(10);
+
+        let mut digit = 0;
+        while let Ordering::Greater | Ordering::Equal = mant.cmp(&scale) {
+            mant = mant.sub(&scale);
+            digit += 1;
+        }
+        digits[len] = b'0' + digit;
+        len += 1;
+    }
+
+    // `mant / scale` is the remainder after the last digit,
+    // when `limit` is negative the float is too small to round up to the last digit.
+    let round_up = limit >= 0
+        && match mant.mul_pow2(1).cmp(&scale) {
+            Ordering::Greater => true,
+            Ordering::Equal => len != 0 && digits[len - 1] % 2 == 1,
+            Ordering::Less => false,
+        };
+
+    if round_up {
+        let mut i = len;
+        loop {
+            if i == 0 {
+                // All the digits were nines, turning `0.999` into `1.000`.
+                digits[0] = b'1';
+                if len == 0 {
+                    len = 1;
+                }
+                k += 1;
+                break;
+            }
+            i -= 1;
+            if digits[i] == b'9' {
+                digits[i] = b'0';
+            } else {
+                digits[i] += 1;
+                break;
+            }
+        }
+    }
+
+    Digits {
+        digits,
+        len,
+        // the float was rounded down to zero
+        exp: if len == 0 { 0 } else { k },
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Copy, Clone)]
+enum FloatLayout {
+    /// Writes `digits` as is, used for `NaN` and `inf`.
+    Literal,
+    /// Writes the digits with an integer part and an optional fractional part.
+    Decimal,
+    /// Writes one digit before the decimal point, and then the exponent.
+    Exponential,
+}
+
+/// Computes the digits of an integer,
+/// rounded to `precision` digits after the decimal point of its exponential form.
+///
+/// This rounds ties to even, like `core::fmt` does.
+const fn integer_digits(n: u128, precision: Option<usize>) -> Digits {
+    let mut digits = [b'0'; MAX_DIGITS];
+    let mut len = 0;
+
+    // The power of ten of the most significant digit,
+    // the value being `0.<digits> * 10^exp`.
+    let mut div = 1u128;
+    let mut exp = 1i16;
+    while n / div >= 10 {
+        div *= 10;
+        exp += 1;
+    }
+
+    // The digits are written from the most significant one,
+    // without trailing zeros.
+    let mut rem = n;
+    while rem != 0 {
+        digits[len] = b'0' + (rem / div) as u8;
+        len += 1;
+        rem %= div;
+        div /= 10;
+    }
+
+    match precision {
+        Some(precision) if len > precision + 1 => {
+            let kept = precision + 1;
+            let first_dropped = digits[kept];
+            // Whether there are non-zero digits after `first_dropped`,
+            // the last digit is never zero.
+            let is_above_half = kept + 1 < len;
+
+            let round_up = first_dropped > b'5'
+                || first_dropped == b'5' && (is_above_half || digits[kept - 1] % 2 == 1);
+
+            // the digits after `len` are never read
+            len = kept;
+
+            if round_up {
+                let mut i = len;
+                loop {
+                    if i == 0 {
+                        // All the digits were nines, turning `999` into `1000`.
+                        digits[0] = b'1';
+                        exp += 1;
+                        break;
+                    }
+                    i -= 1;
+                    if digits[i] == b'9' {
+                        digits[i] = b'0';
+                    } else {
+                        digits[i] += 1;
+                        break;
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Digits { digits, len, exp }
+}
+
+/// The byte of the sign of a number, `0` if there's no sign.
+const fn sign_byte(is_negative: bool, flags: FormattingFlags) -> u8 {
+    if is_negative {
+        b'-'
+    } else if flags.sign_plus() {
+        b'+'
+    } else {
+        0
+    }
+}
+
+/// A display/debug-formatted float,
+/// which is a sign followed by the text that `byte_at` returns.
+#[derive(Copy, Clone)]
+pub struct FmtFloat {
+    /// The byte of the sign, `0` if there's no sign.
+    sign: u8,
+    layout: FloatLayout,
+    digits: [u8; MAX_DIGITS],
+    digits_len: usize,
+    /// Where the decimal point is, relative to the start of the digits.
+    point: isize,
+    int_len: usize,
+    frac_len: usize,
+    exponent: [u8; 5],
+    exponent_len: usize,
+}
+
+impl FmtFloat {
+    const fn display(bits: u64, info: &FloatInfo, flags: FormattingFlags) -> Self {
+        let (is_negative, decoded) = decode(bits, info);
+        let sign = sign_byte(is_negative, flags);
+        Self::decimal(sign, decoded, flags.precision(), 0)
+    }
+
+    const fn debug(bits: u64, info: &FloatInfo, flags: FormattingFlags) -> Self {
+        let (is_negative, decoded) = decode(bits, info);
+        let sign = sign_byte(is_negative, flags);
+        let precision = flags.precision();
+
+        if let NumberFormatting::Exponential = flags.num_fmt() {
+            let digits = match decoded {
+                FullDecoded::Nan => return Self::literal(0, b"NaN"),
+                FullDecoded::Infinite => return Self::literal(sign, b"inf"),
+                FullDecoded::Zero => Digits {
+                    digits: [0; MAX_DIGITS],
+                    len: 0,
+                    exp: 0,
+                },
+                FullDecoded::Finite(d) => match precision {
+                    Some(precision) => exact_digits(d, DigitLimit::Significant(precision + 1)),
+                    None => shortest_digits(d),
+                },
+            };
+            return Self::exponential(sign, digits, flags);
+        }
+
+        if precision.is_some() {
+            return Self::decimal(sign, decoded, precision, 0);
+        }
+
+        let abs_bits = bits & !(1 << (info.mantissa_bits + info.exponent_bits));
+
+        match decoded {
+            FullDecoded::Finite(d)
+                if abs_bits < info.debug_exp_lower || abs_bits >= info.debug_exp_upper =>
+            {
+                Self::exponential(sign, shortest_digits(d), FormattingFlags::__LOWEXP)
+            }
+            _ => Self::decimal(sign, decoded, None, 1),
+        }
+    }
+
+    /// Formats an integer as `<digit>.<digits>e<exponent>`.
+    pub(crate) const fn integer_exponential(
+        is_negative: bool,
+        n: u128,
+        flags: FormattingFlags,
+    ) -> Self {
+        let digits = integer_digits(n, flags.precision());
+        Self::exponential(sign_byte(is_negative, flags), digits, flags)
+    }
+
+    const fn literal(sign: u8, lit: &[u8; 3]) -> Self {
+        let mut this = Self::new(sign, FloatLayout::Literal);
+        this.digits[0] = lit[0];
+        this.digits[1] = lit[1];
+        this.digits[2] = lit[2];
+        this.digits_len = 3;
+        this
+    }
+
+    const fn new(sign: u8, layout: FloatLayout) -> Self {
+        Self {
+            sign,
+            layout,
+            digits: [0; MAX_DIGITS],
+            digits_len: 0,
+            point: 0,
+            int_len: 0,
+            frac_len: 0,
+            exponent: [0; 5],
+            exponent_len: 0,
+        }
+    }
+
+    /// Formats the float without an exponent.
+    ///
+    /// If `precision` is `Some`, this writes the exact value of the float,
+    /// rounded to that many digits after the decimal point.
+    /// Otherwise, this writes the shortest digits that round-trip,
+    /// with at least `min_frac_digits` digits after the decimal point.
+    const fn decimal(
+        sign: u8,
+        decoded: FullDecoded,
+        precision: Option<usize>,
+        mut min_frac_digits: usize,
+    ) -> Self {
+        if let Some(precision) = precision {
+            min_frac_digits = precision;
+        }
+
+        let digits = match decoded {
+            FullDecoded::Nan => return Self::literal(0, b"NaN"),
+            FullDecoded::Infinite => return Self::literal(sign, b"inf"),
+            FullDecoded::Zero => Digits {
+                digits: [0; MAX_DIGITS],
+                len: 0,
+                exp: 0,
+            },
+            FullDecoded::Finite(d) => match precision {
+                Some(precision) => exact_digits(d, DigitLimit::Fractional(precision)),
+                None => shortest_digits(d),
+            },
+        };
+
+        let mut this = Self::new(sign, FloatLayout::Decimal);
+        this.digits = digits.digits;
+        this.digits_len = digits.len;
+        this.point = digits.exp as isize;
+
+        this.int_len = if digits.exp > 0 {
+            digits.exp as usize
+        } else {
+            1
+        };
+
+        let frac_digits = digits.len as isize - digits.exp as isize;
+        this.frac_len = if frac_digits > min_frac_digits as isize {
+            frac_digits as usize
+        } else {
+            min_frac_digits
+        };
+
+        this
+    }
+
+    /// Formats the number as `<digit>.<digits>e<exponent>`,
+    /// with at least as many digits after the decimal point as the precision in `flags`.
+    ///
+    /// The case of the `e` is determined by the number formatting in `flags`.
+    const fn exponential(sign: u8, digits: Digits, flags: FormattingFlags) -> Self {
+        let mut this = Self::new(sign, FloatLayout::Exponential);
+        this.digits = digits.digits;
+        this.digits_len = digits.len;
+        this.point = 1;
+        this.int_len = 1;
+        this.frac_len = match flags.precision() {
+            Some(precision) => precision,
+            None if digits.len > 1 => digits.len - 1,
+            None => 0,
+        };
+
+        // `0.1234 * 10^exp` is `1.234 * 10^(exp - 1)`, zero is written as `0e0`
+        let exp = if digits.len == 0 {
+            0
+        } else {
+            digits.exp as i32 - 1
+        };
+        let mut abs_exp = if exp < 0 { -exp } else { exp } as u32;
+
+        this.exponent[0] = match flags.hex_fmt() {
+            HexFormatting::Upper => b'E',
+            HexFormatting::Lower => b'e',
+        };
+        this.exponent_len = 1;
+        if exp < 0 {
+            this.exponent[1] = b'-';
+            this.exponent_len = 2;
+        }
+
+        let exp_digits = if abs_exp >= 100 {
+            3
+        } else if abs_exp >= 10 {
+            2
+        } else {
+            1
+        };
+        this.exponent_len += exp_digits;
+
+        let mut i = this.exponent_len;
+        while i > this.exponent_len - exp_digits {
+            i -= 1;
+            this.exponent[i] = b'0' + (abs_exp % 10) as u8;
+            abs_exp /= 10;
+        }
+
+        this
+    }
+
+    /// The length of the formatted float, in bytes.
+    pub const fn len(&self) -> usize {
+        self.sign_len()
+            + match self.layout {
+                FloatLayout::Literal => self.digits_len,
+                FloatLayout::Decimal | FloatLayout::Exponential => {
+                    let point_len = (self.frac_len != 0) as usize;
+                    self.int_len + point_len + self.frac_len + self.exponent_len
+                }
+            }
+    }
+
+    /// The length of the sign, zero padding is written after it.
+    pub const fn sign_len(&self) -> usize {
+        (self.sign != 0) as usize
+    }
+
+    /// Gets the digit at `index` in the digits, `'0'` if it's out of bounds.
+    const fn digit_at(&self, index: isize) -> u8 {
+        if 0 <= index && index < self.digits_len as isize {
+            self.digits[index as usize]
+        } else {
+            b'0'
+        }
+    }
+
+    /// Gets the byte at `index` in the formatted float.
+    ///
+    /// # Panics
+    ///
+    /// This may panic if `index >= self.len()`.
+    pub const fn byte_at(&self, mut index: usize) -> u8 {
+        if index < self.sign_len() {
+            return self.sign;
+        }
+        index -= self.sign_len();
+
+        if let FloatLayout::Literal = self.layout {
+            return self.digits[index];
+        }
+
+        if index < self.int_len {
+            return self.digit_at(index as isize + self.point - self.int_len as isize);
+        }
+        index -= self.int_len;
+
+        if self.frac_len != 0 {
+            if index == 0 {
+                return b'.';
+            }
+            index -= 1;
+
+            if index < self.frac_len {
+                return self.digit_at(self.point + index as isize);
+            }
+            index -= self.frac_len;
+        }
+
+        self.exponent[index]
+    }
+}
@@ -0,0 +1,335 @@
+use super::{f32_to_debug, f32_to_display, f64_to_debug, f64_to_display, FmtFloat};
+
+use crate::formatting::FormattingFlags;
+
+use arrayvec::ArrayString;
+
+use core::fmt::Write;
+
+const FLAGS: FormattingFlags = FormattingFlags::NEW;
+const LOWEXP: FormattingFlags = FormattingFlags::NEW.set_lower_exponential();
+
+fn to_string(float: FmtFloat) -> ArrayString<512> {
+    let mut out = ArrayString::<512>::new();
+    for i in 0..float.len() {
+        out.push(float.byte_at(i) as char);
+    }
+    out
+}
+
+fn check_f64(n: f64) {
+    let mut std_out = ArrayString::<512>::new();
+
+    write!(std_out, "{}", n).unwrap();
+    assert_eq!(
+        to_string(f64_to_display(n, FLAGS)).as_str(),
+        std_out.as_str()
+    );
+
+    std_out.clear();
+    write!(std_out, "{:?}", n).unwrap();
+    assert_eq!(to_string(f64_to_debug(n, FLAGS)).as_str(), std_out.as_str());
+
+    let is_negative = n.is_sign_negative() && !n.is_nan();
+    assert_eq!(f64_to_display(n, FLAGS).sign_len(), is_negative as usize);
+
+    std_out.clear();
+    write!(std_out, "{:+}", n).unwrap();
+    let plus = FLAGS.set_sign_plus(true);
+    assert_eq!(
+        to_string(f64_to_display(n, plus)).as_str(),
+        std_out.as_str()
+    );
+
+    std_out.clear();
+    write!(std_out, "{:e}", n).unwrap();
+    assert_eq!(
+        to_string(f64_to_debug(n, LOWEXP)).as_str(),
+        std_out.as_str()
+    );
+
+    std_out.clear();
+    write!(std_out, "{:+E}", n).unwrap();
+    let upper_exp = FLAGS.set_exponential().set_sign_plus(true);
+    assert_eq!(
+        to_string(f64_to_debug(n, upper_exp)).as_str(),
+        std_out.as_str()
+    );
+}
+
+fn check_f32(n: f32) {
+    let mut std_out = ArrayString::<512>::new();
+
+    write!(std_out, "{}", n).unwrap();
+    assert_eq!(
+        to_string(f32_to_display(n, FLAGS)).as_str(),
+        std_out.as_str()
+    );
+
+    std_out.clear();
+    write!(std_out, "{:?}", n).unwrap();
+    assert_eq!(to_string(f32_to_debug(n, FLAGS)).as_str(), std_out.as_str());
+
+    std_out.clear();
+    write!(std_out, "{:e}", n).unwrap();
+    assert_eq!(
+        to_string(f32_to_debug(n, LOWEXP)).as_str(),
+        std_out.as_str()
+    );
+}
+
+fn check_f64_precision(n: f64, precision: usize) {
+    let mut std_out = ArrayString::<2048>::new();
+
+    write!(std_out, "{:.*}", precision, n).unwrap();
+    let display = to_long_string(f64_to_display(n, FLAGS.set_precision(precision)));
+    assert_eq!(display.as_str(), std_out.as_str(), "{:e} {}", n, precision);
+
+    let debug = to_long_string(f64_to_debug(n, FLAGS.set_precision(precision)));
+    assert_eq!(debug.as_str(), std_out.as_str(), "{:e} {}", n, precision);
+
+    std_out.clear();
+    write!(std_out, "{:.*e}", precision, n).unwrap();
+    let exp = to_long_string(f64_to_debug(n, LOWEXP.set_precision(precision)));
+    assert_eq!(exp.as_str(), std_out.as_str(), "{:e} {}", n, precision);
+}
+
+fn check_f32_precision(n: f32, precision: usize) {
+    let mut std_out = ArrayString::<2048>::new();
+
+    write!(std_out, "{:.*}", precision, n).unwrap();
+    let display = to_long_string(f32_to_display(n, FLAGS.set_precision(precision)));
+    assert_eq!(display.as_str(), std_out.as_str(), "{:e} {}", n, precision);
+}
+
+fn check_integer(is_negative: bool, n: u128) {
+    let mut std_out = ArrayString::<2048>::new();
+    let exp = |flags| to_long_string(FmtFloat::integer_exponential(is_negative, n, flags));
+    let sign = if is_negative { "-" } else { "" };
+
+    write!(std_out, "{}{:e}", sign, n).unwrap();
+    assert_eq!(exp(LOWEXP).as_str(), std_out.as_str());
+
+    std_out.clear();
+    write!(std_out, "{}{:E}", sign, n).unwrap();
+    assert_eq!(exp(FLAGS.set_exponential()).as_str(), std_out.as_str());
+
+    for &precision in &[0, 1, 2, 3, 5, 10, 20, 38, 39, 50] {
+        std_out.clear();
+        write!(std_out, "{}{:.*e}", sign, precision, n).unwrap();
+        let flags = LOWEXP.set_precision(precision);
+        assert_eq!(exp(flags).as_str(), std_out.as_str(), "{} {}", n, precision);
+    }
+}
+
+fn to_long_string(float: FmtFloat) -> ArrayString<2048> {
+    let mut out = ArrayString::<2048>::new();
+    for i in 0..float.len() {
+        out.push(float.byte_at(i) as char);
+    }
+    out
+}
+
+#[test]
+fn special_values() {
+    for &n in &[
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        0.1,
+        0.5,
+        1.5,
+        100.0,
+        123456.789,
+        1e-4,
+        9.999999999999999e-5,
+        1e-5,
+        1e15,
+        9999999999999998.0,
+        1e16,
+        1e21,
+        1e22,
+        1e23,
+        f64::MAX,
+        f64::MIN,
+        f64::MIN_POSITIVE,
+        f64::EPSILON,
+        5e-324,
+        -5e-324,
+        2.225073858507201e-308,
+        f64::NAN,
+        -f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        core::f64::consts::PI,
+        core::f64::consts::E,
+    ] {
+        check_f64(n);
+    }
+
+    for &n in &[
+        0.0,
+        -0.0,
+        1.0,
+        0.1,
+        0.3,
+        1e-4,
+        1e-5,
+        16777216.0,
+        1e16,
+        f32::MAX,
+        f32::MIN,
+        f32::MIN_POSITIVE,
+        f32::EPSILON,
+        1e-45,
+        f32::NAN,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        core::f32::consts::PI,
+    ] {
+        check_f32(n);
+    }
+}
+
+fn pow10(exp: i32) -> f64 {
+    let mut buff = ArrayString::<16>::new();
+    write!(buff, "1e{}", exp).unwrap();
+    buff.parse().unwrap()
+}
+
+#[test]
+fn powers_of_ten_and_two() {
+    for exp in -330..=310 {
+        let n = pow10(exp);
+        check_f64(n);
+        check_f64(-n);
+        check_f32(n as f32);
+    }
+    for exp in -1074..=1023 {
+        let n = if exp < -1022 {
+            f64::from_bits(1 << (exp + 1074))
+        } else {
+            f64::from_bits(((exp + 1023) as u64) << 52)
+        };
+        check_f64(n);
+    }
+    for exp in -149..=127 {
+        let n = if exp < -126 {
+            f32::from_bits(1 << (exp + 149))
+        } else {
+            f32::from_bits(((exp + 127) as u32) << 23)
+        };
+        check_f32(n);
+    }
+}
+
+#[test]
+fn random_bits() {
+    let rng = fastrand::Rng::new();
+
+    for _ in 0..20_000 {
+        check_f64(f64::from_bits(rng.u64(..)));
+        check_f32(f32::from_bits(rng.u32(..)));
+    }
+
+    // numbers with few significant digits
+    for _ in 0..5_000 {
+        let n = rng.i64(-1_000_000..1_000_000) as f64 / pow10(rng.i32(-20..20));
+        check_f64(n);
+        check_f32(n as f32);
+    }
+}
+
+#[test]
+fn precision() {
+    for &n in &[
+        0.0,
+        -0.0,
+        0.5,
+        1.5,
+        2.5,
+        -2.5,
+        0.125,
+        0.375,
+        0.35,
+        0.995,
+        9.5,
+        99.99,
+        0.1,
+        1e-7,
+        123456.789,
+        1e21,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        5e-324,
+        // the float with the most significant digits
+        f64::from_bits(0x000F_FFFF_FFFF_FFFF),
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        for precision in (0..20).chain([100, 330, 1080].iter().copied()) {
+            check_f64_precision(n, precision);
+            check_f32_precision(n as f32, precision);
+        }
+    }
+}
+
+#[test]
+fn random_bits_precision() {
+    let rng = fastrand::Rng::new();
+
+    for _ in 0..3_000 {
+        let precision = rng.usize(0..30);
+        check_f64_precision(f64::from_bits(rng.u64(..)), precision);
+        check_f32_precision(f32::from_bits(rng.u32(..)), precision);
+    }
+
+    // numbers that are likely to be halfway between two digits
+    for _ in 0..3_000 {
+        let n = rng.i64(-1_000_000..1_000_000) as f64 / (1u64 << rng.u32(0..40)) as f64;
+        check_f64_precision(n, rng.usize(0..12));
+    }
+}
+
+#[test]
+fn integer_exponential() {
+    for &n in &[
+        0,
+        1,
+        5,
+        9,
+        10,
+        15,
+        25,
+        35,
+        99,
+        100,
+        125,
+        135,
+        1200,
+        1234,
+        1251,
+        9995,
+        99999,
+        1_000_000,
+        u64::MAX as u128,
+        u128::MAX,
+    ] {
+        check_integer(false, n);
+        check_integer(true, n);
+    }
+
+    let rng = fastrand::Rng::new();
+
+    for _ in 0..3_000 {
+        check_integer(false, rng.u128(..) >> rng.u32(0..128));
+    }
+
+    for _ in 0..3_000 {
+        // numbers with few significant digits, and lots of trailing zeros
+        let n = rng.u128(0..100_000) * 10u128.pow(rng.u32(0..30));
+        check_integer(false, n);
+    }
+}
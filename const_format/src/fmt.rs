@@ -61,7 +61,7 @@
 //!
 //! - Debug formatting (eg: `formatc!("{:?}", 0u8)` ):<br>
 //! Similar to how Debug formatting in the standard library works,
-//! except that it does not escape unicode characters.
+//! `char`s and `&str`s are escaped byte-for-byte the same as the standard library.
 //!
 //! - Display formatting (eg: `formatc!("{}", 0u8)`, `formatc!("{:}", 0u8)` )
 //!
@@ -76,6 +76,13 @@
 //! - Binary formatting (eg: `formatc!("{:b}", 0u8)`):<br>
 //! This can be combined with debug formatting with the `"{:b?}"` formatter.
 //!
+//! - Octal formatting (eg: `formatc!("{:o}", 0u8)`):<br>
+//! This can be combined with debug formatting with the `"{:o?}"` formatter.
+//!
+//! - Exponential formatting (eg: `formatc!("{:e}", 1500u32)`, `formatc!("{:E}", 1.5)`):<br>
+//! Writes integers and floats in scientific notation, eg: `1.5e3`,
+//! using `E` instead of `e` with the `"{:E}"` formatter.
+//!
 //! ### Alternate flag
 //!
 //! The alternate flag allows types to format themselves in an alternate way,
@@ -92,10 +99,48 @@
 //! - The binary formater (eg: `formatc!("{:#b}", FOO)`):
 //! prefixes numbers with `0b`.
 //!
-//! ### Additional specifiers
+//! - The octal formater (eg: `formatc!("{:#o}", FOO)`):
+//! prefixes numbers with `0o`.
+//!
+//! ### Width, fill, and alignment
+//!
+//! The width, fill, and alignment specifiers work like in the standard library,
+//! eg: `"{:8}"`, `"{:<8}"`, `"{:-^8}"`, `"{:>width$}"`, `"{:08x}"`.
+//!
+//! The width can come from an argument, with `"{:1$}"` or `"{:width$}"`,
+//! in which case the argument must be a `usize`.
+//!
+//! The width is stored in the [`FormattingFlags`] of the [`Formatter`],
+//! the impls for primitive types pad themselves to it,
+//! and other types can query it with [`FormattingFlags::width`].
+//!
+//! Like in the standard library,
+//! the width is ignored when Debug formatting `str`s and `char`s.
+//!
+//! ### Precision
+//!
+//! The precision specifier works like in the standard library,
+//! eg: `"{:.3}"`, `"{:8.2}"`, `"{:.prec$}"`, `"{:.*}"`.
+//!
+//! For floats, it's the amount of digits written after the decimal point.
+//! For Display formatted `&str`, `char`, and `bool`,
+//! it's the maximum amount of `char`s written, truncating the rest.
+//! For exponential formatting, it's the amount of digits after the decimal point
+//! of the mantissa, for both integers and floats.
+//! Other integers and Debug formatted strings ignore it.
+//!
+//! The precision is stored in the [`FormattingFlags`] of the [`Formatter`],
+//! and other types can query it with [`FormattingFlags::precision`].
+//!
+//! ### Sign
+//!
+//! The `+` flag (eg: `"{:+}"`, `"{:+08.2}"`) writes a `+` before non-negative numbers,
+//! like in the standard library.
+//! Hexadecimal, octal, and binary formatted numbers always get a `+`,
+//! since they're formatted as their two's complement.
 //!
-//! `const_format` macros don't support width, fill, alignment, sign,
-//! or precision specifiers.
+//! The flag is stored in the [`FormattingFlags`] of the [`Formatter`],
+//! and other types can query it with [`FormattingFlags::sign_plus`].
 //!
 //! <span id="custom-formatting-section"></span>
 //! ### Custom formatting
@@ -310,15 +355,20 @@
 
 mod error;
 mod formatter;
+mod std_adapter;
 mod std_type_impls;
 mod str_writer;
 mod str_writer_mut;
 
-pub use crate::formatting::{FormattingFlags, NumberFormatting};
+pub use crate::formatting::{Alignment, FormattingFlags, NumberFormatting};
 
 pub use self::{
     error::{Error, Result, ToResult},
-    formatter::{ComputeStrLength, DebugList, DebugSet, DebugStruct, DebugTuple, Formatter},
+    formatter::{
+        ComputeStrLength, DebugList, DebugMap, DebugMapEntry, DebugSet, DebugStruct, DebugTuple,
+        Formatter,
+    },
+    std_adapter::StdAdapter,
     str_writer::StrWriter,
     str_writer_mut::{NoEncoding, StrWriterMut, Utf8Encoding},
 };
@@ -14,6 +14,9 @@ pub enum Error {
     /// Attempted to index a string arguent by an range where one of the bounds
     /// was not on a char boundary.
     NotOnCharBoundary,
+    /// A [`DebugMap`](./struct.DebugMap.html) value was written without a key before it,
+    /// or a key was written without a value after it.
+    InvalidMapEntry,
 }
 
 impl Display for Error {
@@ -26,6 +29,9 @@ impl Display for Error {
             Self::NotOnCharBoundary => {
                 fmt.write_str("Attempted to index a byte that's not on a char boundary.")
             }
+            Self::InvalidMapEntry => {
+                fmt.write_str("Attempted to write a map key without a value, or vice versa.")
+            }
         }
     }
 }
@@ -51,6 +57,7 @@ impl Error {
             NotEnoughSpace,
             NotAscii,
             NotOnCharBoundary,
+            InvalidMapEntry,
         };
 
         match self {
@@ -59,6 +66,9 @@ impl Error {
             Error::NotOnCharBoundary => {
                 ["Attempted to index a byte that's not on a char boundary."][i]
             }
+            Error::InvalidMapEntry => {
+                ["Attempted to write a map key without a value, or vice versa."][i]
+            }
         };
         loop {}
     }
@@ -1,5 +1,9 @@
 use crate::{
-    fmt::{Error, FormattingFlags, NoEncoding, StrWriter, StrWriterMut},
+    __str_methods::{escaped_len, Escape},
+    fmt::{
+        std_adapter::ChunkWriter, str_writer_mut::is_valid_str_range, Error, FormattingFlags,
+        NoEncoding, StrWriter, StrWriterMut,
+    },
     utils::saturate_range,
     wrapper_types::{AsciiStr, PWrapper},
 };
@@ -93,6 +97,23 @@ impl ComputeStrLength {
 enum WriterBackend<'w> {
     Str(StrWriterMut<'w, NoEncoding>),
     Length(&'w mut ComputeStrLength),
+    Chunk(ChunkWriter<'w>),
+}
+
+/// Matches on a `WriterBackend`,
+/// running the `write` branch for both of the backends that write output.
+macro_rules! match_backend {
+    (
+        $backend:expr;
+        length($fmt_len:ident) => $length_branch:expr,
+        write($writer:ident) => $write_branch:expr $(,)?
+    ) => {
+        match $backend {
+            WriterBackend::Length($fmt_len) => $length_branch,
+            WriterBackend::Str($writer) => $write_branch,
+            WriterBackend::Chunk($writer) => $write_branch,
+        }
+    };
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -382,6 +403,16 @@ impl<'w> Formatter<'w> {
         }
     }
 
+    /// Constructs a `Formatter` that writes one chunk of the output at a time,
+    /// used by [`StdAdapter`](crate::fmt::StdAdapter).
+    pub(crate) const fn from_chunk(writer: ChunkWriter<'w>, flags: FormattingFlags) -> Self {
+        Self {
+            margin: 0,
+            flags,
+            writer: WriterBackend::Chunk(writer),
+        }
+    }
+
     /// Gets the formatting flags associated with this `Formatter`.
     #[inline(always)]
     pub const fn flags(&self) -> FormattingFlags {
@@ -403,6 +434,41 @@ impl<'w> Formatter<'w> {
     const fn decrement_margin(&mut self) {
         self.margin -= 4;
     }
+
+    /// Writes the formatted float, with `zeros` `'0'`s after the sign.
+    pub(crate) const fn write_fmt_float(
+        &mut self,
+        float: &crate::float_encoding::FmtFloat,
+        zeros: usize,
+    ) -> Result<(), Error> {
+        match &mut self.writer {
+            WriterBackend::Length(fmt_len) => {
+                fmt_len.add_len(float.len() + zeros);
+                Ok(())
+            }
+            WriterBackend::Str(writer) => writer.write_fmt_float(float, zeros),
+            WriterBackend::Chunk(writer) => writer.write_fmt_float(float, zeros),
+        }
+    }
+
+    /// Writes the fill character from the formatting flags `repeated` times.
+    pub(crate) const fn write_fill(&mut self, mut repeated: usize) -> Result<(), Error> {
+        let fill = self.flags.fill();
+        match &mut self.writer {
+            WriterBackend::Length(fmt_len) => {
+                fmt_len.add_len(repeated * crate::char_encoding::char_display_len(fill));
+                Ok(())
+            }
+            WriterBackend::Str(writer) => {
+                while repeated != 0 {
+                    try_!(writer.write_char(fill));
+                    repeated -= 1;
+                }
+                Ok(())
+            }
+            WriterBackend::Chunk(writer) => writer.write_char_repeated(fill, repeated),
+        }
+    }
 }
 
 impl<'w> Formatter<'w> {
@@ -479,6 +545,7 @@ impl<'w> Formatter<'w> {
             writer: match &mut self.writer {
                 WriterBackend::Str(x) => WriterBackend::Str(x.reborrow()),
                 WriterBackend::Length(x) => WriterBackend::Length(x),
+                WriterBackend::Chunk(x) => WriterBackend::Chunk(x.reborrow()),
             },
         }
     }
@@ -553,6 +620,24 @@ impl<'w> Formatter<'w> {
             err: Ok(()),
         }
     }
+
+    /// For debug writing a map.
+    ///
+    /// # Examples
+    ///
+    /// For examples of using this method, you can look at the docs for [`DebugMap`]
+    ///
+    /// [`DebugMap`]: ./struct.DebugMap.html
+    ///
+    #[inline]
+    pub const fn debug_map(&mut self) -> DebugMap<'_, 'w> {
+        DebugMap {
+            fmt: self.increment_margin(),
+            wrote_field: false,
+            pending_key: false,
+            err: Ok(()),
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -568,6 +653,7 @@ macro_rules! trys {
 const COLON_SPACE_LEN: usize = ": ".len();
 const COMMA_SPACE_LEN: usize = ", ".len();
 const COMMA_NL_LEN: usize = ",\n".len();
+const DOTS_NL_LEN: usize = "..\n".len();
 
 macro_rules! field_method_impl {
     ($
@@ -575,8 +661,9 @@ macro_rules! field_method_impl {
         len(|$fmt_len:ident| $($write_name_len:tt)*)
         fmt(|$writer:ident| $($write_name_fmt:tt)*)
     ) => ({
-        match &mut $self.fmt.writer {
-            WriterBackend::Length($fmt_len)=>{
+        match_backend!{
+            &mut $self.fmt.writer;
+            length($fmt_len) => {
                 let $fmt_len = &mut **$fmt_len;
 
                 const OPEN_SPACE: usize = $open_space.len();
@@ -590,8 +677,8 @@ macro_rules! field_method_impl {
                     (true , true) => COMMA_NL_LEN + $self.fmt.margin as usize,
                 });
                 $($write_name_len)*
-            }
-            WriterBackend::Str($writer)=>{
+            },
+            write($writer) => {
                 let $writer = &mut *$writer;
 
                 let is_alternate = $self.fmt.flags.is_alternate();
@@ -606,7 +693,7 @@ macro_rules! field_method_impl {
                     trys!($writer.write_ascii_repeated(b' ', $self.fmt.margin as usize), $self);
                 }
                 $($write_name_fmt)*
-            }
+            },
         }
         $self.wrote_field = true;
 
@@ -622,8 +709,9 @@ macro_rules! finish_method_impl {
 
         $self.fmt.decrement_margin();
         if $self.wrote_field {
-            match &mut $self.fmt.writer {
-                WriterBackend::Length(fmt_len) => {
+            match_backend! {
+                &mut $self.fmt.writer;
+                length(fmt_len) => {
                     let fmt_len = &mut **fmt_len;
 
                     const CLOSE_TOKEN: usize = $close_token.len();
@@ -635,8 +723,8 @@ macro_rules! finish_method_impl {
                         fmt_len.add_len(SPACE_CLOSE);
                     }
                     Ok(())
-                }
-                WriterBackend::Str(writer) => {
+                },
+                write(writer) => {
                     let writer = &mut *writer;
 
                     if $self.fmt.flags.is_alternate() {
@@ -646,7 +734,7 @@ macro_rules! finish_method_impl {
                     } else {
                         writer.write_str($space_close)
                     }
-                }
+                },
             }
         } else {
             Ok(())
@@ -654,6 +742,58 @@ macro_rules! finish_method_impl {
     }};
 }
 
+macro_rules! finish_non_exhaustive_method_impl {
+    ($self: ident, $close_token:expr, $comma_dots_close:expr, $dots:expr) => {{
+        if let result @ Err(_) = $self.err {
+            return result;
+        }
+
+        let inner_margin = $self.fmt.margin as usize;
+        $self.fmt.decrement_margin();
+        let is_alternate = $self.fmt.flags.is_alternate();
+        match_backend! {
+            &mut $self.fmt.writer;
+            length(fmt_len) => {
+                let fmt_len = &mut **fmt_len;
+
+                const CLOSE_TOKEN: usize = $close_token.len();
+                const COMMA_DOTS_CLOSE: usize = $comma_dots_close.len();
+                const DOTS: usize = $dots.len();
+
+                if !$self.wrote_field {
+                    fmt_len.add_len(DOTS);
+                } else if is_alternate {
+                    fmt_len.add_len(
+                        COMMA_NL_LEN
+                            + inner_margin
+                            + DOTS_NL_LEN
+                            + $self.fmt.margin as usize
+                            + CLOSE_TOKEN,
+                    );
+                } else {
+                    fmt_len.add_len(COMMA_DOTS_CLOSE);
+                }
+                Ok(())
+            },
+            write(writer) => {
+                let writer = &mut *writer;
+
+                if !$self.wrote_field {
+                    writer.write_str($dots)
+                } else if is_alternate {
+                    try_!(writer.write_str(",\n"));
+                    try_!(writer.write_ascii_repeated(b' ', inner_margin));
+                    try_!(writer.write_str("..\n"));
+                    try_!(writer.write_ascii_repeated(b' ', $self.fmt.margin as usize));
+                    writer.write_str($close_token)
+                } else {
+                    writer.write_str($comma_dots_close)
+                }
+            },
+        }
+    }};
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 /// A helper struct for debug formatting a braced struct, or braced variant.
@@ -749,6 +889,13 @@ impl<'f, 'w> DebugStruct<'f, 'w> {
     pub const fn finish(self) -> Result<(), Error> {
         finish_method_impl!(self, "}", " }")
     }
+
+    /// Finishes writing the struct/variant with a trailing `..`,
+    /// to denote that some fields weren't written,
+    /// and if anything went wrong in the `field` method, returns an error.
+    pub const fn finish_non_exhaustive(self) -> Result<(), Error> {
+        finish_non_exhaustive_method_impl!(self, "}", ", .. }", " { .. }")
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -830,6 +977,13 @@ impl<'f, 'w> DebugTuple<'f, 'w> {
     pub const fn finish(self) -> Result<(), Error> {
         finish_method_impl!(self, ")", ")")
     }
+
+    /// Finishes writing the tuple struct/variant with a trailing `..`,
+    /// to denote that some fields weren't written,
+    /// and if anything went wrong in the `field` method, returns an error.
+    pub const fn finish_non_exhaustive(self) -> Result<(), Error> {
+        finish_non_exhaustive_method_impl!(self, ")", ", ..)", "(..)")
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -840,8 +994,9 @@ macro_rules! finish_listset_method_impl {
             return result;
         }
 
-        match &mut $self.fmt.writer {
-            WriterBackend::Length(fmt_len) => {
+        match_backend! {
+            &mut $self.fmt.writer;
+            length(fmt_len) => {
                 let fmt_len = &mut **fmt_len;
                 const CLOSE_TOKEN: usize = $close_token.len();
                 const OPEN_CLOSE: usize = $open_close.len();
@@ -856,8 +1011,8 @@ macro_rules! finish_listset_method_impl {
                     fmt_len.add_len(OPEN_CLOSE);
                 }
                 Ok(())
-            }
-            WriterBackend::Str(writer) => {
+            },
+            write(writer) => {
                 let writer = &mut *writer;
 
                 $self.fmt.margin -= MARGIN_STEP;
@@ -871,7 +1026,7 @@ macro_rules! finish_listset_method_impl {
                 } else {
                     writer.write_str($open_close)
                 }
-            }
+            },
         }
     }};
 }
@@ -994,6 +1149,160 @@ impl<'f, 'w> DebugSet<'f, 'w> {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// For debug formatting a map.
+///
+/// # Example
+///
+/// This example demonstrates how you can debug format a lookup table as a map.
+///
+/// ```rust
+///
+/// use const_format::{Error, Formatter};
+/// use const_format::{formatc, impl_fmt, try_};
+///
+/// fn main() {
+///     const MAP: &str = formatc!("{:?}", Table(&[("foo", 3), ("bar", 5)]));
+///     
+///     assert_eq!(MAP, r#"{"foo": 3, "bar": 5}"#);
+///
+///     const CODES: &str = formatc!("{:?}", Codes(&[(200, "OK"), (404, "Not Found")]));
+///     
+///     assert_eq!(CODES, r#"{200: "OK", 404: "Not Found"}"#);
+/// }
+///
+/// struct Table(&'static [(&'static str, u32)]);
+///
+/// impl_fmt!{
+///     impl Table;
+///     
+///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.debug_map();
+///         let mut i = 0;
+///         while i < self.0.len() {
+///             let (key, value) = self.0[i];
+///             try_!(f.str_entry(key).write_u32_debug(value));
+///             i+=1;
+///         }
+///         f.finish()
+///     }
+/// }
+///
+/// struct Codes(&'static [(u16, &'static str)]);
+///
+/// impl_fmt!{
+///     impl Codes;
+///     
+///     const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+///         let mut f = f.debug_map();
+///         let mut i = 0;
+///         while i < self.0.len() {
+///             let (key, value) = self.0[i];
+///             let mut entry = f.entry();
+///             try_!(entry.key().write_u16_debug(key));
+///             try_!(entry.value().write_str_debug(value));
+///             i+=1;
+///         }
+///         f.finish()
+///     }
+/// }
+///
+/// ```
+///
+pub struct DebugMap<'f, 'w> {
+    fmt: &'f mut Formatter<'w>,
+    wrote_field: bool,
+    // whether a key was written without its value
+    pending_key: bool,
+    err: Result<(), Error>,
+}
+
+impl<'f, 'w> DebugMap<'f, 'w> {
+    /// Adds the key of a map entry to the formatted output,
+    /// which must be followed by a call to the `value` method.
+    ///
+    /// Calling this method again before calling `value`
+    /// causes the `finish` method to return an `Error::InvalidMapEntry`.
+    pub const fn key(&mut self) -> &mut Formatter<'w> {
+        if self.pending_key {
+            self.err = Err(Error::InvalidMapEntry);
+        }
+        self.pending_key = true;
+        field_method_impl!(self, "{", "{\n"; len(|fmt_len|) fmt(|writer|) )
+    }
+
+    /// Adds the value of a map entry to the formatted output,
+    /// this must be called after the `key` method.
+    ///
+    /// Calling this method without calling `key` first
+    /// causes the `finish` method to return an `Error::InvalidMapEntry`.
+    pub const fn value(&mut self) -> &mut Formatter<'w> {
+        if !self.pending_key {
+            self.err = Err(Error::InvalidMapEntry);
+        }
+        self.pending_key = false;
+        match_backend! {
+            &mut self.fmt.writer;
+            length(fmt_len) => fmt_len.add_len(COLON_SPACE_LEN),
+            write(writer) => trys!(writer.write_str(": "), self),
+        }
+        self.fmt
+    }
+
+    /// Starts a map entry, whose key and value are written with the
+    /// [`key`](./struct.DebugMapEntry.html#method.key) and
+    /// [`value`](./struct.DebugMapEntry.html#method.value) methods of the returned
+    /// [`DebugMapEntry`](./struct.DebugMapEntry.html).
+    pub const fn entry(&mut self) -> DebugMapEntry<'_, 'f, 'w> {
+        DebugMapEntry { map: self }
+    }
+
+    /// Adds a map entry with a `&str` key to the formatted output,
+    /// returning the `Formatter` to write the value with.
+    ///
+    /// This is equivalent to debug formatting `key` with the `key` method,
+    /// and then calling the `value` method.
+    pub const fn str_entry(&mut self, key: &str) -> &mut Formatter<'w> {
+        trys!(self.key().write_str_debug(key), self);
+        self.value()
+    }
+
+    /// Finishes writing the map,
+    /// and if anything went wrong in the `key`/`value`/`entry` methods, returns an error.
+    ///
+    /// This returns an `Error::InvalidMapEntry` if the last key has no value.
+    pub const fn finish(self) -> Result<(), Error> {
+        if self.pending_key && self.err.is_ok() {
+            return Err(Error::InvalidMapEntry);
+        }
+        finish_listset_method_impl!(self, "}", "{}")
+    }
+}
+
+/// A map entry, returned by [`DebugMap::entry`].
+///
+/// For examples of using this type, you can look at the docs for [`DebugMap`]
+///
+/// [`DebugMap`]: ./struct.DebugMap.html
+/// [`DebugMap::entry`]: ./struct.DebugMap.html#method.entry
+pub struct DebugMapEntry<'m, 'f, 'w> {
+    map: &'m mut DebugMap<'f, 'w>,
+}
+
+impl<'m, 'f, 'w> DebugMapEntry<'m, 'f, 'w> {
+    /// Adds the key of the entry to the formatted output.
+    pub const fn key(&mut self) -> &mut Formatter<'w> {
+        self.map.key()
+    }
+
+    /// Adds the value of the entry to the formatted output,
+    /// this must be called after the `key` method.
+    pub const fn value(self) -> &'m mut Formatter<'w> {
+        self.map.value()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 macro_rules! delegate_write_methods {
     (
         shared_attrs $shared_attrs:tt
@@ -1001,6 +1310,7 @@ macro_rules! delegate_write_methods {
             $(#[$attrs:meta])*
             fn $method:ident($($arg:ident: $arg_ty:ty ),* $(,)* )
             length = $len:expr;
+            $(chunk = |$chunk_writer:ident| $chunk:expr;)?
         )*
     ) => (
         impl Formatter<'_>{
@@ -1011,6 +1321,7 @@ macro_rules! delegate_write_methods {
                     $(#[$attrs])*
                     fn $method($($arg: $arg_ty ),* )
                     length = $len;
+                    chunk($(|$chunk_writer| $chunk)?)
                 }
             )*
         }
@@ -1023,6 +1334,7 @@ macro_rules! delegate_write_methods {
         $(#[$attrs:meta])*
         fn $method:ident($($arg:ident: $arg_ty:ty ),* $(,)* )
         length = $len:expr;
+        chunk($(|$chunk_writer:ident| $chunk:expr)?)
     ) => (
         $( #[$shared_attrs] )*
         $(#[$attrs])*
@@ -1035,9 +1347,27 @@ macro_rules! delegate_write_methods {
                 WriterBackend::Str(writer)=>{
                     writer.$method($($arg,)*)
                 }
+                WriterBackend::Chunk(writer)=>{
+                    delegate_write_methods!{
+                        @chunk writer, $method($($arg),*), $len $(, |$chunk_writer| $chunk)?
+                    }
+                }
             }
         }
-    )
+    );
+    (@chunk $writer:ident, $method:ident($($arg:ident),*), $len:expr) => (
+        match $writer.piece($len) {
+            Some(writer) => writer.$method($($arg,)*),
+            None => Ok(()),
+        }
+    );
+    (
+        @chunk $writer:ident, $method:ident($($arg:ident),*), $len:expr,
+        |$chunk_writer:ident| $chunk:expr
+    ) => ({
+        let $chunk_writer = $writer;
+        $chunk
+    });
 }
 
 delegate_write_methods! {
@@ -1064,6 +1394,14 @@ delegate_write_methods! {
     ///
     fn write_str_range(string: &str, range: Range<usize>)
     length = calculate_display_len(string.as_bytes(), &range);
+    chunk = |writer| {
+        let bytes = string.as_bytes();
+        let Range { start, end } = saturate_range(bytes, &range);
+        if !is_valid_str_range(bytes, start..end) {
+            return Err(Error::NotOnCharBoundary);
+        }
+        writer.write_bytes(bytes, start, end)
+    };
 
     /// Writes `string` into this Formatter.
     ///
@@ -1084,6 +1422,7 @@ delegate_write_methods! {
     ///
     fn write_str(string: &str)
     length = string.len();
+    chunk = |writer| writer.write_str(string);
 
     /// Writes `character` into this Formatter.
     ///
@@ -1128,6 +1467,10 @@ delegate_write_methods! {
     ///
     fn write_ascii_range(ascii: AsciiStr<'_>, range: Range<usize>)
     length = calculate_display_len(ascii.as_bytes(), &range);
+    chunk = |writer| {
+        let Range { start, end } = saturate_range(ascii.as_bytes(), &range);
+        writer.write_bytes(ascii.as_bytes(), start, end)
+    };
 
     /// Writes `ascii` into this formatter.
     ///
@@ -1148,6 +1491,7 @@ delegate_write_methods! {
     ///
     fn write_ascii(ascii: AsciiStr<'_>)
     length = ascii.len();
+    chunk = |writer| writer.write_bytes(ascii.as_bytes(), 0, ascii.len());
 
     /// Writes the ascii `character` into this formatter `repeated` times.
     ///
@@ -1171,6 +1515,7 @@ delegate_write_methods! {
     ///
     fn write_ascii_repeated(character: u8,repeated: usize)
     length = repeated;
+    chunk = |writer| writer.write_ascii_repeated(character, repeated);
 
     /// Writes `string` into this formatter, with debug formatting.
     ///
@@ -1193,6 +1538,14 @@ delegate_write_methods! {
     ///
     fn write_str_range_debug(string: &str, range: Range<usize>)
     length = calculate_display_len_debug_range(string.as_bytes(), &range);
+    chunk = |writer| {
+        let bytes = string.as_bytes();
+        let Range { start, end } = saturate_range(bytes, &range);
+        if !is_valid_str_range(bytes, start..end) {
+            return Err(Error::NotOnCharBoundary);
+        }
+        writer.write_str_debug(bytes, start, end)
+    };
 
     /// Writes `string` into this formatter, with debug formatting.
     ///
@@ -1213,6 +1566,7 @@ delegate_write_methods! {
     ///
     fn write_str_debug(string: &str)
     length = PWrapper(string.as_bytes()).compute_utf8_debug_len();
+    chunk = |writer| writer.write_str_debug(string.as_bytes(), 0, string.len());
 
     /// Writes `character` into this Formatter, with debug formatting.
     ///
@@ -1263,6 +1617,10 @@ delegate_write_methods! {
     ///
     fn write_ascii_range_debug(ascii: AsciiStr<'_>,range: Range<usize>)
     length = calculate_display_len_debug_range(ascii.as_bytes(), &range);
+    chunk = |writer| {
+        let Range { start, end } = saturate_range(ascii.as_bytes(), &range);
+        writer.write_str_debug(ascii.as_bytes(), start, end)
+    };
 
     /// Writes `ascii` into this formatter, with debug formatting.
     ///
@@ -1283,8 +1641,66 @@ delegate_write_methods! {
     ///
     fn write_ascii_debug(ascii: AsciiStr<'_>)
     length = PWrapper(ascii.as_bytes()).compute_utf8_debug_len();
+    chunk = |writer| writer.write_str_debug(ascii.as_bytes(), 0, ascii.len());
 
 
+    /// Writes `&string[range]` into this formatter, escaped as determined by `escape`.
+    ///
+    /// This is a workaround for being unable to do `&foo[start..end]` at compile time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Escape, Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_str_range_escaped("FOO<BAR>BAZ", 3..8, Escape::Html);
+    ///
+    /// assert_eq!(writer.as_str(), "&lt;BAR&gt;");
+    ///
+    /// ```
+    ///
+    fn write_str_range_escaped(string: &str, range: Range<usize>, escape: Escape)
+    length = {
+        let Range { start, end } = saturate_range(string.as_bytes(), &range);
+        escaped_len(escape, string.as_bytes(), start, end)
+    };
+    chunk = |writer| {
+        let bytes = string.as_bytes();
+        let Range { start, end } = saturate_range(bytes, &range);
+        if !is_valid_str_range(bytes, start..end) {
+            return Err(Error::NotOnCharBoundary);
+        }
+        writer.write_str_escaped(bytes, start, end, escape)
+    };
+
+    /// Writes `string` into this formatter, escaped as determined by `escape`.
+    ///
+    /// This escapes the string the same way as the [`str_escape`] macro.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Escape, Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 32]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_str_escaped("/a b/ñ", Escape::Percent);
+    ///
+    /// assert_eq!(writer.as_str(), "%2Fa%20b%2F%C3%B1");
+    ///
+    /// ```
+    ///
+    /// [`str_escape`]: ../macro.str_escape.html
+    fn write_str_escaped(string: &str, escape: Escape)
+    length = escaped_len(escape, string.as_bytes(), 0, string.len());
+    chunk = |writer| writer.write_str_escaped(string.as_bytes(), 0, string.len(), escape);
+
     /// Write `n` with display formatting.
     ///
     /// # Example
@@ -1352,6 +1768,76 @@ delegate_write_methods! {
     length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
 }
 
+delegate_write_methods! {
+    shared_attrs()
+
+    /// Writes `n` with display formatting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 32]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_f64_display(1.5);
+    /// let _ = fmt.write_str(" ");
+    /// let _ = fmt.write_f64_display(3.0);
+    /// let _ = fmt.write_str(" ");
+    /// let _ = fmt.write_f64_display(f64::NAN);
+    ///
+    /// assert_eq!(writer.as_str(), "1.5 3 NaN");
+    ///
+    /// ```
+    ///
+    fn write_f64_display(n: f64)
+    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
+
+    /// Writes `n` with debug formatting.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Formatter, FormattingFlags, StrWriter};
+    ///
+    /// let writer: &mut StrWriter = &mut StrWriter::new([0; 32]);
+    /// let mut fmt = writer.make_formatter(FormattingFlags::NEW);
+    ///
+    /// let _ = fmt.write_f64_debug(1.5);
+    /// let _ = fmt.write_str(" ");
+    /// let _ = fmt.write_f64_debug(3.0);
+    /// let _ = fmt.write_str(" ");
+    /// let _ = fmt.write_f64_debug(1e20);
+    ///
+    /// assert_eq!(writer.as_str(), "1.5 3.0 1e20");
+    ///
+    /// ```
+    ///
+    fn write_f64_debug(n: f64)
+    length = PWrapper(n).compute_debug_len(FormattingFlags::NEW);
+
+    /// Writes `n` with display formatting
+    ///
+    /// For an example,
+    /// you can look at the one for the [`write_f64_display`] method.
+    ///
+    /// [`write_f64_display`]: #method.write_f64_display
+    fn write_f32_display(n: f32)
+    length = PWrapper(n).compute_display_len(FormattingFlags::NEW);
+
+    /// Writes `n` with debug formatting
+    ///
+    /// For an example,
+    /// you can look at the one for the [`write_f64_debug`] method.
+    ///
+    /// [`write_f64_debug`]: #method.write_f64_debug
+    fn write_f32_debug(n: f32)
+    length = PWrapper(n).compute_debug_len(FormattingFlags::NEW);
+}
+
 macro_rules! delegate_integer_debug_methods {
     (
         shared_attrs $shared_attrs:tt
@@ -1395,6 +1881,12 @@ macro_rules! delegate_integer_debug_methods {
                 WriterBackend::Str(writer)=>{
                     writer.$method($($arg,)* $flags)
                 }
+                WriterBackend::Chunk(writer)=>{
+                    match writer.piece($len) {
+                        Some(writer) => writer.$method($($arg,)* $flags),
+                        None => Ok(()),
+                    }
+                }
             }
         }
     )
@@ -0,0 +1,441 @@
+use crate::{
+    __str_methods::{escape_byte, Escape},
+    char_encoding::{char_to_display, str_char_debug},
+    float_encoding::FmtFloat,
+    fmt::{
+        Alignment, ComputeStrLength, Error, Formatter, FormattingFlags, NoEncoding, StrWriterMut,
+    },
+    utils::min_usize,
+    wrapper_types::PWrapper,
+};
+
+use core::{fmt, ops::Range};
+
+/// How many bytes of output `StdAdapter` writes to the `core::fmt::Formatter` at a time.
+///
+/// This must be larger than the longest piece of output that
+/// [`ChunkWriter::piece`] is used for, which are floats and integers.
+const CHUNK_LEN: usize = 1024;
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Adapter that implements the [`core::fmt::Debug`] and [`core::fmt::Display`] traits
+/// by calling a `const_debug_fmt`/`const_display_fmt`-like function
+/// with a [`Formatter`].
+///
+/// The const formatting function is first called to compute the length of the output.
+/// If the output fits in a (currently 1024 bytes long) stack buffer,
+/// the function is called once more to write it into the buffer,
+/// which is then passed to the `core::fmt::Formatter`.
+///
+/// Longer output is written one buffer-sized chunk at a time,
+/// calling the function once for every chunk.
+/// Every call formats the value from the start, skipping the output of previous chunks,
+/// so the cost of formatting long output grows quadratically with its length.
+/// `StdAdapter` is meant for values whose output is at most a few chunks long,
+/// for larger output it's better to format into a [`StrWriter`] instead.
+///
+/// Because the function is called more than once,
+/// it needs to output the same text every time it's called.
+///
+/// Both the `Debug` and `Display` impls call the function that was passed to
+/// [`StdAdapter::new`], with these flags from the `core::fmt::Formatter`:
+///
+/// - alternate (`#`)
+/// - sign (`+`)
+/// - zero padding (`0`)
+/// - width
+/// - precision
+/// - fill and alignment
+///
+/// The `{:x?}` and `{:X?}` formatting flags are not detectable
+/// from the `core::fmt::Formatter`,
+/// so `StdAdapter` always formats integers in decimal.
+///
+/// # Errors
+///
+/// `StdAdapter` returns a [`core::fmt::Error`]
+/// if the const formatting function returns an error,
+/// or if the length of its output changes between calls.
+///
+/// # Example
+///
+#[cfg_attr(feature = "derive", doc = "```rust")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+///
+/// use const_format::{ConstDebug, formatc};
+/// use const_format::fmt::StdAdapter;
+///
+/// #[derive(ConstDebug)]
+/// struct Point {
+///     x: u32,
+///     y: u32,
+/// }
+///
+/// let point = Point { x: 3, y: 5 };
+///
+/// let adapter = StdAdapter::new(&point, Point::const_debug_fmt);
+///
+/// assert_eq!(format!("{:?}", adapter), "Point { x: 3, y: 5 }");
+/// assert_eq!(format!("{:#?}", adapter), "Point {\n    x: 3,\n    y: 5,\n}");
+///
+/// // `StdAdapter` outputs the same as the `formatc` macro
+/// const POINT: Point = Point { x: 8, y: 13 };
+/// assert_eq!(
+///     formatc!("{:?}", POINT),
+///     format!("{:?}", StdAdapter::new(&POINT, Point::const_debug_fmt)),
+/// );
+///
+/// ```
+///
+/// [`Formatter`]: ./struct.Formatter.html
+/// [`StrWriter`]: ./struct.StrWriter.html
+/// [`StdAdapter::new`]: #method.new
+pub struct StdAdapter<'a, T: ?Sized> {
+    value: &'a T,
+    fmt: fn(&T, &mut Formatter<'_>) -> Result<(), Error>,
+}
+
+impl<'a, T: ?Sized> StdAdapter<'a, T> {
+    /// Constructs a `StdAdapter`,
+    /// which formats `value` by passing it to `fmt`.
+    ///
+    /// `fmt` is usually the `const_debug_fmt` or `const_display_fmt`
+    /// method of the type.
+    pub const fn new(value: &'a T, fmt: fn(&T, &mut Formatter<'_>) -> Result<(), Error>) -> Self {
+        Self { value, fmt }
+    }
+
+    /// Gets the value that this formats.
+    pub const fn value(&self) -> &'a T {
+        self.value
+    }
+
+    fn write_to(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let flags = flags_from_std(f);
+
+        let mut compute = ComputeStrLength::new();
+        if (self.fmt)(self.value, &mut compute.make_formatter(flags)).is_err() {
+            return Err(fmt::Error);
+        }
+        let total_len = compute.len();
+
+        let mut buffer = [0u8; CHUNK_LEN];
+
+        if total_len <= CHUNK_LEN {
+            let mut len = 0;
+            let mut fmt = Formatter::from_custom(&mut buffer[..total_len], &mut len, flags);
+            // an error here means that the output is longer than in the first call
+            if (self.fmt)(self.value, &mut fmt).is_err() || len != total_len {
+                return Err(fmt::Error);
+            }
+            return f.write_str(core::str::from_utf8(&buffer[..len]).map_err(|_| fmt::Error)?);
+        }
+
+        let mut state = ChunkState {
+            start: 0,
+            position: 0,
+            full: false,
+        };
+
+        loop {
+            state.position = 0;
+            state.full = false;
+
+            let mut len = 0;
+            let writer = ChunkWriter {
+                buffer: StrWriterMut::from_custom(&mut buffer, &mut len),
+                state: &mut state,
+            };
+            if (self.fmt)(self.value, &mut Formatter::from_chunk(writer, flags)).is_err() {
+                return Err(fmt::Error);
+            }
+
+            // The output is different from the one in previous calls
+            if state.position > total_len || !state.full && state.position != total_len {
+                return Err(fmt::Error);
+            }
+
+            let chunk = core::str::from_utf8(&buffer[..len]).map_err(|_| fmt::Error)?;
+            f.write_str(chunk)?;
+
+            if !state.full {
+                return Ok(());
+            } else if len == 0 {
+                // A piece of output that can't be split up is longer than the buffer
+                return Err(fmt::Error);
+            }
+
+            state.start = state.position;
+        }
+    }
+}
+
+impl<T: ?Sized> Copy for StdAdapter<'_, T> {}
+
+impl<T: ?Sized> Clone for StdAdapter<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for StdAdapter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+impl<T: ?Sized> fmt::Display for StdAdapter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
+fn flags_from_std(f: &fmt::Formatter<'_>) -> FormattingFlags {
+    let mut flags = FormattingFlags::NEW
+        .set_alternate(f.alternate())
+        .set_sign_plus(f.sign_plus())
+        .set_sign_aware_zero_pad(f.sign_aware_zero_pad())
+        .set_fill(f.fill());
+
+    if let Some(width) = f.width() {
+        flags = flags.set_width(width);
+    }
+    if let Some(precision) = f.precision() {
+        flags = flags.set_precision(precision);
+    }
+    if let Some(alignment) = f.align() {
+        flags = flags.set_alignment(match alignment {
+            fmt::Alignment::Left => Alignment::Left,
+            fmt::Alignment::Right => Alignment::Right,
+            fmt::Alignment::Center => Alignment::Center,
+        });
+    }
+
+    flags
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Which part of the output is written in the current pass of `StdAdapter`.
+pub(crate) struct ChunkState {
+    /// Where in the output the current chunk starts.
+    start: usize,
+    /// How much output has been written (or skipped) in the current pass.
+    position: usize,
+    /// Whether the chunk buffer is full,
+    /// in which case `position` is where the next chunk starts.
+    full: bool,
+}
+
+/// Writes the part of the output that starts at `ChunkState::start`,
+/// skipping everything before it, and stopping when the buffer is full.
+pub(crate) struct ChunkWriter<'w> {
+    buffer: StrWriterMut<'w, NoEncoding>,
+    state: &'w mut ChunkState,
+}
+
+impl<'w> ChunkWriter<'w> {
+    pub(crate) const fn reborrow(&mut self) -> ChunkWriter<'_> {
+        ChunkWriter {
+            buffer: self.buffer.reborrow(),
+            state: self.state,
+        }
+    }
+
+    /// Reserves `len` bytes for a piece of output that can't be split between chunks,
+    /// returning the writer to write it with if it's part of the current chunk.
+    pub(crate) const fn piece(&mut self, len: usize) -> Option<&mut StrWriterMut<'w, NoEncoding>> {
+        let state = &mut *self.state;
+        if state.full {
+            None
+        } else if state.position < state.start {
+            state.position += len;
+            None
+        } else if len <= self.buffer.remaining_capacity() {
+            state.position += len;
+            Some(&mut self.buffer)
+        } else {
+            state.full = true;
+            None
+        }
+    }
+
+    /// Reserves `len` bytes for a piece of output that can be split between chunks,
+    /// returning the range of the piece that's part of the current chunk.
+    const fn window(&mut self, len: usize) -> Range<usize> {
+        let state = &mut *self.state;
+        if state.full {
+            return 0..0;
+        }
+
+        let from = min_usize(state.start.saturating_sub(state.position), len);
+        let to = from + min_usize(len - from, self.buffer.remaining_capacity());
+
+        state.position += to;
+        state.full = to != len;
+
+        from..to
+    }
+
+    /// Like `window`, but only splits the piece every `unit_len` bytes.
+    const fn units_window(&mut self, unit_len: usize, len: usize) -> Range<usize> {
+        let Range { start, end } = self.window(len);
+        let trimmed = end % unit_len;
+        self.state.position -= trimmed;
+        start..end - trimmed
+    }
+
+    /// Writes `bytes[start..end]`, which must be valid utf8.
+    pub(crate) const fn write_bytes(
+        &mut self,
+        bytes: &[u8],
+        start: usize,
+        end: usize,
+    ) -> Result<(), Error> {
+        let Range {
+            start: from,
+            end: mut to,
+        } = self.window(end - start);
+
+        // Only splitting the string on char boundaries
+        let mut trimmed = 0;
+        while to > from && start + to != bytes.len() && (bytes[start + to] as i8) < -0x40 {
+            to -= 1;
+            trimmed += 1;
+        }
+        self.state.position -= trimmed;
+
+        self.buffer.write_str_inner(bytes, start + from, start + to)
+    }
+
+    /// Writes `s`, the same as `StrWriterMut::write_str`.
+    pub(crate) const fn write_str(&mut self, s: &str) -> Result<(), Error> {
+        self.write_bytes(s.as_bytes(), 0, s.len())
+    }
+
+    /// Writes `character` `repeated` times,
+    /// the same as `StrWriterMut::write_ascii_repeated`.
+    pub(crate) const fn write_ascii_repeated(
+        &mut self,
+        character: u8,
+        repeated: usize,
+    ) -> Result<(), Error> {
+        let Range { start, end } = self.window(repeated);
+        self.buffer.write_ascii_repeated(character, end - start)
+    }
+
+    /// Writes `character` `repeated` times.
+    pub(crate) const fn write_char_repeated(
+        &mut self,
+        character: char,
+        repeated: usize,
+    ) -> Result<(), Error> {
+        let fmt_char = char_to_display(character);
+        let char_len = fmt_char.len();
+        let Range { start, end } = self.units_window(char_len, char_len * repeated);
+
+        let mut written = start;
+        while written < end {
+            try_!(self.buffer.write_str_inner(fmt_char.encoded(), 0, char_len));
+            written += char_len;
+        }
+        Ok(())
+    }
+
+    /// Writes `bytes[start..end]` with debug formatting,
+    /// the range must be valid utf8.
+    pub(crate) const fn write_str_debug(
+        &mut self,
+        bytes: &[u8],
+        mut start: usize,
+        end: usize,
+    ) -> Result<(), Error> {
+        let len = PWrapper(bytes).compute_utf8_debug_len_in_range(start..end);
+
+        {
+            let state = &mut *self.state;
+            if state.full {
+                return Ok(());
+            } else if state.position + len <= state.start {
+                state.position += len;
+                return Ok(());
+            } else if state.start <= state.position && len <= self.buffer.remaining_capacity() {
+                state.position += len;
+                return self.buffer.write_str_debug_inner(bytes, start, end);
+            }
+        }
+
+        // The string is split between chunks, so it's written one escape at a time
+        if let Some(writer) = self.piece(1) {
+            try_!(writer.write_str("\""));
+        }
+        while start < end {
+            let escaped = str_char_debug(bytes, start);
+            if let Some(writer) = self.piece(escaped.len) {
+                try_!(writer.write_str_inner(&escaped.encoded, 0, escaped.len));
+            }
+            start += escaped.char_len;
+        }
+        if let Some(writer) = self.piece(1) {
+            try_!(writer.write_str("\""));
+        }
+        Ok(())
+    }
+
+    /// Writes `bytes[start..end]` escaped as determined by `escape`,
+    /// the range must be valid utf8.
+    pub(crate) const fn write_str_escaped(
+        &mut self,
+        bytes: &[u8],
+        mut start: usize,
+        end: usize,
+        escape: Escape,
+    ) -> Result<(), Error> {
+        while start < end {
+            let b = bytes[start];
+            if b >= 128 && !escape.escapes_non_ascii() {
+                // Unescaped non-ascii chars can't be split between chunks
+                let mut char_end = start + 1;
+                while char_end < end && (bytes[char_end] as i8) < -0x40 {
+                    char_end += 1;
+                }
+                if let Some(writer) = self.piece(char_end - start) {
+                    try_!(writer.write_str_inner(bytes, start, char_end));
+                }
+                start = char_end;
+            } else {
+                let escaped = escape_byte(escape, b);
+                if let Some(writer) = self.piece(escaped.len) {
+                    try_!(writer.write_str_inner(&escaped.encoded, 0, escaped.len));
+                }
+                start += 1;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the formatted float, with `zeros` `'0'`s after the sign.
+    pub(crate) const fn write_fmt_float(
+        &mut self,
+        float: &FmtFloat,
+        zeros: usize,
+    ) -> Result<(), Error> {
+        let sign_len = float.sign_len();
+        let Range { start, end } = self.window(float.len() + zeros);
+
+        let mut i = start;
+        while i < end {
+            let byte = if i < sign_len {
+                float.byte_at(i)
+            } else if i < sign_len + zeros {
+                b'0'
+            } else {
+                float.byte_at(i - zeros)
+            };
+            try_!(self.buffer.write_ascii_repeated(byte, 1));
+            i += 1;
+        }
+        Ok(())
+    }
+}
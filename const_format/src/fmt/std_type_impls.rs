@@ -1,7 +1,9 @@
 #![allow(missing_docs)]
 
 use crate::{
+    char_encoding::{truncate_str, utf8_char_count},
     fmt::{Error, Formatter},
+    formatting::Alignment,
     marker_traits::IsStdKind,
     wrapper_types::PWrapper,
 };
@@ -12,7 +14,17 @@ mod ranges;
 
 impl PWrapper<&str> {
     pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        f.write_str(self.0)
+        let str = truncate_str(self.0, f.flags().precision());
+
+        if f.flags().width().is_none() {
+            return f.write_str(str);
+        }
+
+        let char_count = utf8_char_count(str.as_bytes());
+        let padding = f.flags().padding(char_count, Alignment::Left);
+        try_!(f.write_fill(padding.before));
+        try_!(f.write_str(str));
+        f.write_fill(padding.after)
     }
 
     pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
@@ -22,7 +34,7 @@ impl PWrapper<&str> {
 
 impl PWrapper<bool> {
     pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        f.write_str(if self.0 { "true" } else { "false" })
+        PWrapper(if self.0 { "true" } else { "false" }).const_display_fmt(f)
     }
 
     #[inline(always)]
@@ -31,9 +43,45 @@ impl PWrapper<bool> {
     }
 }
 
+macro_rules! impl_float_fmt {
+    ($( ($ty:ty, $to_display:ident, $to_debug:ident) )*) => (
+        $(
+            impl PWrapper<$ty> {
+                pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    let float = crate::float_encoding::$to_display(self.0, f.flags());
+                    let padding = f.flags().int_padding(float.len());
+                    try_!(f.write_fill(padding.before));
+                    try_!(f.write_fmt_float(&float, padding.zeros));
+                    f.write_fill(padding.after)
+                }
+
+                pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+                    let float = crate::float_encoding::$to_debug(self.0, f.flags());
+                    let padding = f.flags().int_padding(float.len());
+                    try_!(f.write_fill(padding.before));
+                    try_!(f.write_fmt_float(&float, padding.zeros));
+                    f.write_fill(padding.after)
+                }
+            }
+        )*
+    );
+}
+
+impl_float_fmt! {
+    (f32, f32_to_display, f32_to_debug)
+    (f64, f64_to_display, f64_to_debug)
+}
+
 impl PWrapper<char> {
     pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-        f.write_char(self.0)
+        if let Some(0) = f.flags().precision() {
+            return PWrapper("").const_display_fmt(f);
+        }
+
+        let padding = f.flags().padding(1, Alignment::Left);
+        try_!(f.write_fill(padding.before));
+        try_!(f.write_char(self.0));
+        f.write_fill(padding.after)
     }
 
     #[inline(always)]
@@ -67,6 +115,7 @@ slice_of_std_impl! {
     u64, i64,
     u128, i128,
     usize, isize,
+    f32, f64,
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -110,6 +159,8 @@ impl_fmt! {
     impl[] Option<isize>;
     impl[] Option<bool>;
     impl[] Option<char>;
+    impl[] Option<f32>;
+    impl[] Option<f64>;
     impl['a,] Option<&'a str>;
 
     pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
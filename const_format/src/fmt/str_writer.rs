@@ -1,6 +1,6 @@
 use super::{Error, Formatter, FormattingFlags, StrWriterMut, Utf8Encoding};
 
-use core::marker::PhantomData;
+use core::{fmt, marker::PhantomData};
 
 ////////////////////////////////////////////////////////////////////////////////
 
@@ -573,3 +573,31 @@ impl<A: ?Sized> StrWriter<A> {
         self
     }
 }
+
+/// Writes the string that has been written to this `StrWriter` so far.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::StrWriter;
+///
+/// let writer: &mut StrWriter = &mut StrWriter::new([0; 16]);
+/// writer.as_mut().write_str("Hello, world!")?;
+///
+/// assert_eq!(format!("{}", writer), "Hello, world!");
+/// assert_eq!(format!("{:>15}", writer), "  Hello, world!");
+///
+/// # Ok::<(), const_format::Error>(())
+/// ```
+impl fmt::Display for StrWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+/// Writes the string that has been written to this `StrWriter` so far.
+impl<const N: usize> fmt::Display for StrWriter<[u8; N]> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.unsize().as_str(), f)
+    }
+}
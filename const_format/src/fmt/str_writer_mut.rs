@@ -1,14 +1,16 @@
 use crate::{
-    formatting::{
-        hex_as_ascii, ForEscaping, FormattingFlags, HexFormatting, NumberFormatting, FOR_ESCAPING,
-    },
+    __str_methods::{escape_byte, escaped_len, Escape},
+    char_encoding::str_char_debug,
+    float_encoding::{f32_to_debug, f32_to_display, f64_to_debug, f64_to_display, FmtFloat},
+    formatting::{hex_as_ascii, FormattingFlags, NumberFormatting, FOR_ESCAPING},
+    pargument::PConvWrapper,
     utils::{min_usize, saturate_range, Constructor},
     wrapper_types::{AsciiStr, PWrapper},
 };
 
 use super::{Error, Formatter, StrWriter};
 
-use core::{marker::PhantomData, ops::Range};
+use core::{fmt, marker::PhantomData, ops::Range};
 
 /// For writing a formatted string into a `[u8]`.
 ///
@@ -726,15 +728,87 @@ macro_rules! write_integer_fn {
             /// Writes a
             #[doc = $ty_name]
             /// with Display formatting.
+            ///
+            /// This pads the number according to the width in the formatting flags.
             pub const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-                f.$display_fn(self.0)
+                let flags = f.flags();
+                if flags.width().is_none() {
+                    return self.write_zero_padded_decimal(f, 0);
+                }
+
+                let padding = flags.int_padding(PWrapper(self.0).compute_display_len(flags));
+                try_!(f.write_fill(padding.before));
+                try_!(self.write_zero_padded_decimal(f, padding.zeros));
+                f.write_fill(padding.after)
             }
 
             /// Writes a
             #[doc = $ty_name]
             /// with Debug formatting.
+            ///
+            /// This pads the number according to the width in the formatting flags.
             pub const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
-                f.$debug_fn(self.0)
+                let flags = f.flags();
+                if flags.width().is_none() {
+                    return f.$debug_fn(self.0);
+                }
+
+                if let NumberFormatting::Exponential = flags.num_fmt() {
+                    let float = PWrapper(PConvWrapper(self.0).to_integer()).to_fmt_float(flags);
+                    let padding = flags.int_padding(float.len());
+                    try_!(f.write_fill(padding.before));
+                    try_!(f.write_fmt_float(&float, padding.zeros));
+                    return f.write_fill(padding.after);
+                }
+
+                let padding = flags.int_padding(PWrapper(self.0).compute_debug_len(flags));
+                try_!(f.write_fill(padding.before));
+                if padding.zeros == 0 {
+                    try_!(f.$debug_fn(self.0));
+                } else {
+                    match flags.num_fmt() {
+                        NumberFormatting::Decimal => {
+                            try_!(self.write_zero_padded_decimal(f, padding.zeros));
+                        }
+                        num_fmt => {
+                            if flags.sign_plus() {
+                                try_!(f.write_str("+"));
+                            }
+                            if flags.is_alternate() {
+                                try_!(f.write_str(match num_fmt {
+                                    NumberFormatting::Binary => "0b",
+                                    NumberFormatting::Octal => "0o",
+                                    _ => "0x",
+                                }));
+                            }
+                            try_!(f.write_ascii_repeated(b'0', padding.zeros));
+                            let flags = flags.set_alternate(false).set_sign_plus(false);
+                            try_!(f.make_formatter(flags).$debug_fn(self.0));
+                        }
+                    }
+                }
+                f.write_fill(padding.after)
+            }
+
+            const fn write_zero_padded_decimal(
+                &self,
+                f: &mut Formatter<'_>,
+                zeros: usize,
+            ) -> Result<(), Error> {
+                let sign_plus = f.flags().sign_plus();
+                if zeros == 0 && !sign_plus {
+                    return f.$display_fn(self.0);
+                }
+
+                if write_integer_fn!(@is_negative $sign, self.0) {
+                    try_!(f.write_str("-"));
+                } else if sign_plus {
+                    try_!(f.write_str("+"));
+                }
+                try_!(f.write_ascii_repeated(b'0', zeros));
+                PWrapper(self.unsigned_abs()).const_display_fmt(
+                    &mut f.make_formatter(FormattingFlags::NEW)
+                )
             }
         }
     };
@@ -798,9 +872,14 @@ macro_rules! write_integer_fn {
                     return Err(Error::NotEnoughSpace);
                 }
 
+                let mut prefix_at = *this_len;
+                if f.sign_plus() {
+                    this_buffer[prefix_at] = b'+';
+                    prefix_at += 1;
+                }
                 if is_alternate {
-                    this_buffer[*this_len] = b'0';
-                    this_buffer[*this_len + 1] = b'x';
+                    this_buffer[prefix_at] = b'0';
+                    this_buffer[prefix_at + 1] = b'x';
                 }
 
                 write_integer_fn!(@as_unsigned $sign, n, $Unsigned);
@@ -833,9 +912,14 @@ macro_rules! write_integer_fn {
                     return Err(Error::NotEnoughSpace);
                 }
 
+                let mut prefix_at = *this_len;
+                if f.sign_plus() {
+                    this_buffer[prefix_at] = b'+';
+                    prefix_at += 1;
+                }
                 if is_alternate {
-                    this_buffer[*this_len] = b'0';
-                    this_buffer[*this_len + 1] = b'b';
+                    this_buffer[prefix_at] = b'0';
+                    this_buffer[prefix_at + 1] = b'b';
                 }
 
                 write_integer_fn!(@as_unsigned $sign, n, $Unsigned);
@@ -852,10 +936,60 @@ macro_rules! write_integer_fn {
                 Ok(())
             }
 
+            const fn octal<E>(
+                this: &mut StrWriterMut<'_, E>,
+                n: $ty,
+                f: FormattingFlags,
+            ) -> Result<(), Error> {
+                borrow_fields!(this, this_len, this_buffer);
+
+                let is_alternate = f.is_alternate();
+                let len = PWrapper(n).octal_len(f);
+
+                let mut cursor = *this_len + len;
+
+                if cursor > this_buffer.len() {
+                    return Err(Error::NotEnoughSpace);
+                }
+
+                let mut prefix_at = *this_len;
+                if f.sign_plus() {
+                    this_buffer[prefix_at] = b'+';
+                    prefix_at += 1;
+                }
+                if is_alternate {
+                    this_buffer[prefix_at] = b'0';
+                    this_buffer[prefix_at + 1] = b'o';
+                }
+
+                write_integer_fn!(@as_unsigned $sign, n, $Unsigned);
+
+                loop {
+                    cursor-=1;
+                    let digit = (n & 0b111) as u8;
+                    this_buffer[cursor] = b'0' + digit;
+                    n >>= 3;
+                    if n == 0 { break }
+                }
+
+                *this_len+=len;
+                Ok(())
+            }
+
             match flags.num_fmt() {
-                NumberFormatting::Decimal=>self.$display_fn(number),
+                NumberFormatting::Decimal=>{
+                    if flags.sign_plus() && !write_integer_fn!(@is_negative $sign, number) {
+                        try_!(self.write_str("+"));
+                    }
+                    self.$display_fn(number)
+                }
                 NumberFormatting::Hexadecimal=>hex(self, number, flags),
                 NumberFormatting::Binary=>binary(self, number, flags),
+                NumberFormatting::Octal=>octal(self, number, flags),
+                NumberFormatting::Exponential=>{
+                    let float = PWrapper(PConvWrapper(number).to_integer()).to_fmt_float(flags);
+                    self.write_fmt_float(&float, 0)
+                }
             }
         }
     };
@@ -877,12 +1011,14 @@ macro_rules! write_integer_fn {
         }
     });
     (@write_sign unsigned, $self_len:ident, $self_buffer:ident, $n:ident) => ({});
+    (@is_negative signed, $n:expr) => ($n < 0);
+    (@is_negative unsigned, $n:expr) => (false);
 }
 
 /// Checks that a range is valid for indexing a string,
 /// assuming that the range is in-bounds, and start <= end.
 #[inline]
-const fn is_valid_str_range(s: &[u8], Range { start, end }: Range<usize>) -> bool {
+pub(super) const fn is_valid_str_range(s: &[u8], Range { start, end }: Range<usize>) -> bool {
     let len = s.len();
 
     (end == len || ((s[end] as i8) >= -0x40)) && (start == len || ((s[start] as i8) >= -0x40))
@@ -1085,7 +1221,7 @@ impl<'w, E> StrWriterMut<'w, E> {
     }
 
     #[inline(always)]
-    const fn write_str_inner(
+    pub(super) const fn write_str_inner(
         &mut self,
         bytes: &[u8],
         mut start: usize,
@@ -1265,7 +1401,7 @@ impl<'w, E> StrWriterMut<'w, E> {
     }
 
     #[inline(always)]
-    const fn write_str_debug_inner(
+    pub(super) const fn write_str_debug_inner(
         &mut self,
         bytes: &[u8],
         mut start: usize,
@@ -1290,41 +1426,28 @@ impl<'w, E> StrWriterMut<'w, E> {
 
         while start != end {
             let c = bytes[start];
-            let mut written_c = c;
 
-            let mut shifted = 0;
-
-            if c < 128
-                && ({
-                    shifted = 1 << c;
-                    (FOR_ESCAPING.is_escaped & shifted) != 0
-                })
-            {
-                self_buffer[written] = b'\\';
+            if c < 128 && (FOR_ESCAPING.is_escaped & (1 << c)) == 0 {
+                self_buffer[written] = c;
                 written += 1;
+                start += 1;
+                continue;
+            }
 
-                if (FOR_ESCAPING.is_backslash_escaped & shifted) != 0 {
-                    remaining_for_escapes -= 1;
-                    if remaining_for_escapes < 0 {
-                        return Err(Error::NotEnoughSpace);
-                    }
-                    written_c = ForEscaping::get_backslash_escape(c);
-                } else {
-                    remaining_for_escapes -= 3;
-                    if remaining_for_escapes < 0 {
-                        return Err(Error::NotEnoughSpace);
-                    }
-                    self_buffer[written] = b'x';
-                    written += 1;
-                    self_buffer[written] = hex_as_ascii(c >> 4, HexFormatting::Upper);
-                    written += 1;
-                    written_c = hex_as_ascii(c & 0xF, HexFormatting::Upper);
-                };
+            let escaped = str_char_debug(bytes, start);
+
+            remaining_for_escapes -= (escaped.len - escaped.char_len) as isize;
+            if remaining_for_escapes < 0 {
+                return Err(Error::NotEnoughSpace);
             }
 
-            self_buffer[written] = written_c;
-            written += 1;
-            start += 1;
+            let mut i = 0;
+            while i < escaped.len {
+                self_buffer[written] = escaped.encoded[i];
+                written += 1;
+                i += 1;
+            }
+            start += escaped.char_len;
         }
 
         self_buffer[written] = b'"';
@@ -1336,6 +1459,106 @@ impl<'w, E> StrWriterMut<'w, E> {
     }
 }
 
+/// Escaped string writing
+impl<'w, E> StrWriterMut<'w, E> {
+    /// Writes a subslice of `s`, escaped as determined by `escape`.
+    ///
+    /// This is a workaround for being unable to do `&foo[start..end]` at compile time.
+    ///
+    /// # Additional Errors
+    ///
+    /// This method returns `Error::NotOnCharBoundary` if the range is not
+    /// on a character boundary.
+    ///
+    /// Out of bounds range bounds are treated as being at `s.len()`,
+    /// this only returns an error on an in-bounds index that is not on a character boundary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Escape, StrWriterMut};
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let _ = writer.write_str_range_escaped("FOO<BAR>BAZ", 3..8, Escape::Html);
+    ///
+    /// assert_eq!(writer.as_str(), "&lt;BAR&gt;");
+    ///
+    /// ```
+    ///
+    pub const fn write_str_range_escaped(
+        &mut self,
+        s: &str,
+        range: Range<usize>,
+        escape: Escape,
+    ) -> Result<(), Error> {
+        let bytes = s.as_bytes();
+        let Range { start, end } = saturate_range(bytes, &range);
+
+        if !is_valid_str_range(bytes, start..end) {
+            return Err(Error::NotOnCharBoundary);
+        }
+
+        self.write_str_escaped_inner(bytes, start, end, escape)
+    }
+
+    /// Writes `s`, escaped as determined by `escape`.
+    ///
+    /// This escapes the string the same way as the [`str_escape`] macro.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::{Escape, StrWriterMut};
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let _ = writer.write_str_escaped("FOO \"BAR\"\n", Escape::Json);
+    ///
+    /// assert_eq!(writer.as_str(), r#"FOO \"BAR\"\n"#);
+    ///
+    /// ```
+    ///
+    /// [`str_escape`]: ../macro.str_escape.html
+    pub const fn write_str_escaped(&mut self, s: &str, escape: Escape) -> Result<(), Error> {
+        let bytes = s.as_bytes();
+        self.write_str_escaped_inner(bytes, 0, s.len(), escape)
+    }
+
+    pub(super) const fn write_str_escaped_inner(
+        &mut self,
+        bytes: &[u8],
+        mut start: usize,
+        end: usize,
+        escape: Escape,
+    ) -> Result<(), Error> {
+        borrow_fields!(self, self_len, self_buffer);
+
+        if *self_len + escaped_len(escape, bytes, start, end) > self_buffer.len() {
+            return Err(Error::NotEnoughSpace);
+        }
+
+        while start < end {
+            let escaped = escape_byte(escape, bytes[start]);
+            let mut i = 0;
+            while i < escaped.len {
+                self_buffer[*self_len] = escaped.encoded[i];
+                *self_len += 1;
+                i += 1;
+            }
+            start += 1;
+        }
+
+        Ok(())
+    }
+}
+
 write_integer_fn! {
     display_attrs(
         /// Write `number` with display formatting.
@@ -1426,3 +1649,152 @@ write_integer_fn! {
     (write_i128_display, write_i128_debug, signed, i128, u128)
     (write_isize_display, write_isize_debug, signed, isize, usize)
 }
+
+impl<'w, E> StrWriterMut<'w, E> {
+    /// Writes `number` with display formatting.
+    ///
+    /// This writes the shortest representation that round-trips back to `number`,
+    /// the same way that the `Display` impl for `f64` does.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::StrWriterMut;
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let _ = writer.write_f64_display(0.1);
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_display(-2.0);
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_display(1e-7);
+    ///
+    /// assert_eq!(writer.as_str(), "0.1 -2 0.0000001");
+    ///
+    /// ```
+    ///
+    pub const fn write_f64_display(&mut self, number: f64) -> Result<(), Error> {
+        self.write_fmt_float(&f64_to_display(number, FormattingFlags::NEW), 0)
+    }
+
+    /// Writes `number` with debug formatting.
+    ///
+    /// This writes the shortest representation that round-trips back to `number`,
+    /// the same way that the `Debug` impl for `f64` does.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    ///
+    /// use const_format::StrWriterMut;
+    ///
+    /// let mut len = 0;
+    /// let mut buffer = [0; 64];
+    /// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+    ///
+    /// let _ = writer.write_f64_debug(0.1);
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_debug(-2.0);
+    /// let _ = writer.write_str(" ");
+    /// let _ = writer.write_f64_debug(1e-7);
+    ///
+    /// assert_eq!(writer.as_str(), "0.1 -2.0 1e-7");
+    ///
+    /// ```
+    ///
+    pub const fn write_f64_debug(&mut self, number: f64) -> Result<(), Error> {
+        self.write_fmt_float(&f64_to_debug(number, FormattingFlags::NEW), 0)
+    }
+
+    /// Writes `number` with display formatting
+    ///
+    /// For an example,
+    /// you can look at the one for the [`write_f64_display`] method.
+    ///
+    /// [`write_f64_display`]: #method.write_f64_display
+    pub const fn write_f32_display(&mut self, number: f32) -> Result<(), Error> {
+        self.write_fmt_float(&f32_to_display(number, FormattingFlags::NEW), 0)
+    }
+
+    /// Writes `number` with debug formatting
+    ///
+    /// For an example,
+    /// you can look at the one for the [`write_f64_debug`] method.
+    ///
+    /// [`write_f64_debug`]: #method.write_f64_debug
+    pub const fn write_f32_debug(&mut self, number: f32) -> Result<(), Error> {
+        self.write_fmt_float(&f32_to_debug(number, FormattingFlags::NEW), 0)
+    }
+
+    /// Writes the formatted float, with `zeros` `'0'`s after the sign.
+    pub(crate) const fn write_fmt_float(
+        &mut self,
+        float: &FmtFloat,
+        mut zeros: usize,
+    ) -> Result<(), Error> {
+        borrow_fields!(self, self_len, self_buffer);
+
+        let len = float.len();
+        let sign_len = float.sign_len();
+
+        if *self_len + len + zeros > self_buffer.len() {
+            return Err(Error::NotEnoughSpace);
+        }
+
+        let mut i = 0;
+        while i < len {
+            if i == sign_len {
+                while zeros != 0 {
+                    self_buffer[*self_len] = b'0';
+                    *self_len += 1;
+                    zeros -= 1;
+                }
+            }
+
+            self_buffer[*self_len] = float.byte_at(i);
+            *self_len += 1;
+            i += 1;
+        }
+
+        Ok(())
+    }
+}
+
+/// Allows using `StrWriterMut` with the `core::write` macro at runtime.
+///
+/// The methods of this impl return `core::fmt::Error`
+/// wherever the inherent methods would return an [`Error`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::StrWriterMut;
+///
+/// use core::fmt::Write;
+///
+/// let mut len = 0;
+/// let mut buffer = [0; 16];
+/// let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+///
+/// write!(writer, "{} + {} = {}", 3, 5, 3 + 5)?;
+/// assert_eq!(writer.as_str(), "3 + 5 = 8");
+///
+/// assert!(write!(writer, "{}", "too long to fit").is_err());
+/// assert_eq!(writer.as_str(), "3 + 5 = 8");
+///
+/// # Ok::<(), core::fmt::Error>(())
+/// ```
+///
+/// [`Error`]: ./enum.Error.html
+impl<E> fmt::Write for StrWriterMut<'_, E> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        StrWriterMut::write_str(self, s).map_err(|_| fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        StrWriterMut::write_char(self, c).map_err(|_| fmt::Error)
+    }
+}
@@ -15,8 +15,8 @@ impl Formatting {
 
 /// How numbers are formatted in debug formatters.
 ///
-/// Hexadecimal or binary formatting in the formatting string from this crate imply
-/// debug formatting.
+/// Hexadecimal, octal, binary, or exponential formatting
+/// in the formatting string from this crate imply debug formatting.
 ///
 ///
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -27,6 +27,10 @@ pub enum NumberFormatting {
     Hexadecimal,
     /// Formats numbers as binary
     Binary,
+    /// Formats numbers as octal
+    Octal,
+    /// Formats numbers in scientific notation (eg: `1.5e3`)
+    Exponential,
 }
 
 #[doc(hidden)]
@@ -41,13 +45,59 @@ pub enum HexFormatting {
     Lower = b'a' - 10,
 }
 
+/// How a value is aligned inside of the width set with
+/// [`FormattingFlags::set_width`].
+///
+/// If no alignment is set, strings, chars, and bools are left aligned,
+/// while numbers are right aligned.
+///
+/// [`FormattingFlags::set_width`]: ./struct.FormattingFlags.html#method.set_width
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Alignment {
+    /// Aligns the value to the left (eg: `formatc!("{:<8}", FOO)`)
+    Left,
+    /// Centers the value (eg: `formatc!("{:^8}", FOO)`)
+    Center,
+    /// Aligns the value to the right (eg: `formatc!("{:>8}", FOO)`)
+    Right,
+}
+
+#[doc(hidden)]
+/// How many fill characters, and how many `'0'` characters are written around a value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Padding {
+    /// The amount of fill characters written before the value
+    pub before: usize,
+    /// The amount of `'0'` characters written after the sign/prefix of a number
+    pub zeros: usize,
+    /// The amount of fill characters written after the value
+    pub after: usize,
+}
+
+impl Padding {
+    #[doc(hidden)]
+    pub const NONE: Self = Self {
+        before: 0,
+        zeros: 0,
+        after: 0,
+    };
+
+    /// The amount of bytes that this padding takes when `fill` is the fill character.
+    #[doc(hidden)]
+    pub const fn byte_len(self, fill: char) -> usize {
+        (self.before + self.after) * crate::char_encoding::char_display_len(fill) + self.zeros
+    }
+}
+
 impl NumberFormatting {
     #[cfg(test)]
     #[cfg(feature = "fmt")]
-    pub(crate) const ALL: &'static [Self; 3] = &[
+    pub(crate) const ALL: &'static [Self; 5] = &[
         NumberFormatting::Decimal,
         NumberFormatting::Hexadecimal,
         NumberFormatting::Binary,
+        NumberFormatting::Octal,
+        NumberFormatting::Exponential,
     ];
 }
 
@@ -71,12 +121,20 @@ impl NumberFormatting {
 /// - `NumberFormatting::Binary` (eg: `formatc!("{:b}", FOO)`):
 /// formats numbers as binary.
 ///
-/// Hexadecimal or binary formatting in the formatting string from this crate imply
-/// debug formatting,
+/// - `NumberFormatting::Octal` (eg: `formatc!("{:o}", FOO)`):
+/// formats numbers as octal.
+///
+/// - `NumberFormatting::Exponential` (eg: `formatc!("{:e}", FOO)`):
+/// formats numbers in scientific notation, eg: `1234` is formatted as `1.234e3`.
+///
+/// Hexadecimal, octal, binary, or exponential formatting in the formatting string
+/// from this crate imply debug formatting,
 /// and can be used to for example print an array of binary numbers.
 ///
 /// Note: Lowercase hexadecimal formatting requires calling the
-/// [`set_lower_hexadecimal`](#method.set_lower_hexadecimal) method.
+/// [`set_lower_hexadecimal`](#method.set_lower_hexadecimal) method,
+/// and the lowercase `e` of exponential formatting requires calling the
+/// [`set_lower_exponential`](#method.set_lower_exponential) method.
 ///
 /// # Alternate flag
 ///
@@ -94,18 +152,72 @@ impl NumberFormatting {
 /// - The binary formater (eg: `formatc!("{:#b}", FOO)`):
 /// prefixes numbers with `0b`.`
 ///
+/// - The octal formater (eg: `formatc!("{:#o}", FOO)`):
+/// prefixes numbers with `0o`.`
+///
+/// # Sign
+///
+/// The `+` flag (eg: `formatc!("{:+}", FOO)`), set with the `set_sign_plus` method,
+/// makes non-negative numbers be prefixed with a `+`.
+///
+/// As with `std::fmt`, hexadecimal, octal, and binary formatted numbers
+/// are always prefixed with a `+` when this flag is enabled,
+/// since negative numbers are formatted as their two's complement.
+///
+/// # Width, fill, and alignment
+///
+/// The minimum width (in `char`s) that primitive types are padded to,
+/// set with the `set_width` method (eg: `formatc!("{:8}", FOO)`).
+///
+/// The padding is made of the fill character (set with `set_fill`, defaults to `' '`),
+/// placed according to the [`Alignment`] (set with `set_alignment`).
+///
+/// The sign-aware zero padding flag (eg: `formatc!("{:08}", FOO)`),
+/// set with `set_sign_aware_zero_pad`,
+/// pads numbers with `'0'` characters after their sign and `0x`/`0o`/`0b` prefix,
+/// ignoring the fill character and alignment.
+///
+/// As with `std::fmt`, these flags only affect the formatting of
+/// primitive types (integers, floats, `bool`, `char`, and `&str`, the last two only when
+/// Display formatted),
+/// user-defined types can query them with the `width`, `fill`, and `alignment` methods.
+///
+/// # Precision
+///
+/// Set with the `set_precision` method (eg: `formatc!("{:.3}", FOO)`).
+///
+/// For floats, this is the exact amount of digits after the decimal point,
+/// rounding the value (with ties rounded to even).
+///
+/// For exponential formatted numbers, this is the exact amount of digits after
+/// the decimal point of the mantissa.
+///
+/// For Display formatted `&str`, `char`, and `bool`, this is the maximum amount of
+/// `char`s that are written, the rest of the string is truncated.
+///
+/// Non-exponential integers and Debug formatted strings ignore the precision,
+/// user-defined types can query it with the `precision` method.
+///
 /// [`Formatter`]: ./struct.Formatter.html
+/// [`Alignment`]: ./enum.Alignment.html
 ///
 #[must_use]
 #[derive(Debug, Copy, Clone)]
 pub struct FormattingFlags {
     num_fmt: NumberFormatting,
     // Whether the `NumberFormatting` prints hexadecimal digits in lowercase
-    // (e.g: 0xf00, 0xF00)
+    // (e.g: 0xf00, 0xF00),
+    // and whether the exponent is prefixed by an `e` or an `E`
     //
     // move this in 0.3.0 to `NumberFormatting`.
     hex_fmt: HexFormatting,
     is_alternate: bool,
+    fill: char,
+    alignment: Option<Alignment>,
+    width: Option<usize>,
+    precision: Option<usize>,
+    sign_plus: bool,
+    sign_aware_zero_pad: bool,
 }
 
 #[doc(hidden)]
@@ -114,19 +226,21 @@ impl FormattingFlags {
     pub const __HEX: Self = Self::NEW.set_alternate(false).set_hexadecimal();
     pub const __LOWHEX: Self = Self::NEW.set_alternate(false).set_lower_hexadecimal();
     pub const __BIN: Self = Self::NEW.set_alternate(false).set_binary();
+    pub const __OCT: Self = Self::NEW.set_alternate(false).set_octal();
+    pub const __EXP: Self = Self::NEW.set_alternate(false).set_exponential();
+    pub const __LOWEXP: Self = Self::NEW.set_alternate(false).set_lower_exponential();
 
     pub const __A_REG: Self = Self::NEW.set_alternate(true).set_decimal();
     pub const __A_HEX: Self = Self::NEW.set_alternate(true).set_hexadecimal();
     pub const __A_LOWHEX: Self = Self::NEW.set_alternate(true).set_lower_hexadecimal();
     pub const __A_BIN: Self = Self::NEW.set_alternate(true).set_binary();
+    pub const __A_OCT: Self = Self::NEW.set_alternate(true).set_octal();
+    pub const __A_EXP: Self = Self::NEW.set_alternate(true).set_exponential();
+    pub const __A_LOWEXP: Self = Self::NEW.set_alternate(true).set_lower_exponential();
 }
 impl FormattingFlags {
     #[doc(hidden)]
-    pub const DEFAULT: Self = Self {
-        num_fmt: NumberFormatting::Decimal,
-        hex_fmt: HexFormatting::Upper,
-        is_alternate: false,
-    };
+    pub const DEFAULT: Self = Self::NEW;
 
     /// Constructs a `FormattingFlags` with these values:
     ///
@@ -134,10 +248,28 @@ impl FormattingFlags {
     ///
     /// - is alternate: false
     ///
+    /// - fill: `' '`
+    ///
+    /// - alignment: None
+    ///
+    /// - width: None
+    ///
+    /// - precision: None
+    ///
+    /// - sign plus: false
+    ///
+    /// - sign-aware zero padding: false
+    ///
     pub const NEW: Self = Self {
         num_fmt: NumberFormatting::Decimal,
         hex_fmt: HexFormatting::Upper,
         is_alternate: false,
+        fill: ' ',
+        alignment: None,
+        width: None,
+        precision: None,
+        sign_plus: false,
+        sign_aware_zero_pad: false,
     };
 
     /// Constructs a `FormattingFlags` with these values:
@@ -146,6 +278,18 @@ impl FormattingFlags {
     ///
     /// - is alternate: false
     ///
+    /// - fill: `' '`
+    ///
+    /// - alignment: None
+    ///
+    /// - width: None
+    ///
+    /// - precision: None
+    ///
+    /// - sign plus: false
+    ///
+    /// - sign-aware zero padding: false
+    ///
     #[inline]
     pub const fn new() -> Self {
         Self::NEW
@@ -199,6 +343,37 @@ impl FormattingFlags {
         self
     }
 
+    /// Sets the number formatting to `NumberFormatting::Octal`.
+    ///
+    /// This means that numbers are written as octal.
+    #[inline]
+    pub const fn set_octal(mut self) -> Self {
+        self.num_fmt = NumberFormatting::Octal;
+        self
+    }
+
+    /// Sets the number formatting to `NumberFormatting::Exponential`.
+    ///
+    /// This means that numbers are written in scientific notation,
+    /// with an uppercase `E` before the exponent.
+    #[inline]
+    pub const fn set_exponential(mut self) -> Self {
+        self.num_fmt = NumberFormatting::Exponential;
+        self.hex_fmt = HexFormatting::Upper;
+        self
+    }
+
+    /// Sets the number formatting to `NumberFormatting::Exponential`,
+    /// and uses a lowercase `e` before the exponent.
+    ///
+    /// This means that numbers are written in scientific notation, eg: `1.5e3`.
+    #[inline]
+    pub const fn set_lower_exponential(mut self) -> Self {
+        self.num_fmt = NumberFormatting::Exponential;
+        self.hex_fmt = HexFormatting::Lower;
+        self
+    }
+
     /// Sets whether the formatting flag is enabled.
     #[inline]
     pub const fn set_alternate(mut self, is_alternate: bool) -> Self {
@@ -206,6 +381,50 @@ impl FormattingFlags {
         self
     }
 
+    /// Sets the character that values are padded with, when they're shorter than the width.
+    #[inline]
+    pub const fn set_fill(mut self, fill: char) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Sets how values are aligned inside the width.
+    #[inline]
+    pub const fn set_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Sets the minimum amount of `char`s that primitive types are padded to.
+    #[inline]
+    pub const fn set_width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the amount of digits after the decimal point in floats,
+    /// and the maximum amount of `char`s in Display formatted strings.
+    #[inline]
+    pub const fn set_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets whether non-negative numbers are prefixed with a `+`.
+    #[inline]
+    pub const fn set_sign_plus(mut self, sign_plus: bool) -> Self {
+        self.sign_plus = sign_plus;
+        self
+    }
+
+    /// Sets whether numbers are padded with `'0'`s after their sign/prefix,
+    /// instead of the fill character.
+    #[inline]
+    pub const fn set_sign_aware_zero_pad(mut self, sign_aware_zero_pad: bool) -> Self {
+        self.sign_aware_zero_pad = sign_aware_zero_pad;
+        self
+    }
+
     /// Gets the current `NumberFormatting`.
     #[inline]
     pub const fn num_fmt(self) -> NumberFormatting {
@@ -218,9 +437,85 @@ impl FormattingFlags {
         self.is_alternate
     }
 
+    /// Gets the character that values are padded with.
+    #[inline]
+    pub const fn fill(self) -> char {
+        self.fill
+    }
+
+    /// Gets the alignment of values inside the width, if it was set.
+    #[inline]
+    pub const fn alignment(self) -> Option<Alignment> {
+        self.alignment
+    }
+
+    /// Gets the minimum amount of `char`s that primitive types are padded to, if it was set.
+    #[inline]
+    pub const fn width(self) -> Option<usize> {
+        self.width
+    }
+
+    /// Gets the precision, if it was set.
+    #[inline]
+    pub const fn precision(self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Gets whether non-negative numbers are prefixed with a `+`.
+    #[inline]
+    pub const fn sign_plus(self) -> bool {
+        self.sign_plus
+    }
+
+    /// Gets whether numbers are padded with `'0'`s after their sign/prefix.
+    #[inline]
+    pub const fn sign_aware_zero_pad(self) -> bool {
+        self.sign_aware_zero_pad
+    }
+
     pub(crate) const fn hex_fmt(self) -> HexFormatting {
         self.hex_fmt
     }
+
+    /// Computes the padding for a value that is `len` `char`s long,
+    /// `default_alignment` is used when no alignment was set.
+    #[doc(hidden)]
+    pub const fn padding(self, len: usize, default_alignment: Alignment) -> Padding {
+        let total = match self.width {
+            Some(width) if width > len => width - len,
+            _ => return Padding::NONE,
+        };
+
+        let alignment = match self.alignment {
+            Some(x) => x,
+            None => default_alignment,
+        };
+
+        let before = match alignment {
+            Alignment::Left => 0,
+            Alignment::Center => total / 2,
+            Alignment::Right => total,
+        };
+
+        Padding {
+            before,
+            zeros: 0,
+            after: total - before,
+        }
+    }
+
+    /// Computes the padding for a number that is `len` `char`s long.
+    #[doc(hidden)]
+    pub const fn int_padding(self, len: usize) -> Padding {
+        match self.width {
+            Some(width) if self.sign_aware_zero_pad && width > len => Padding {
+                before: 0,
+                zeros: width - len,
+                after: 0,
+            },
+            _ => self.padding(len, Alignment::Right),
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -276,10 +571,10 @@ pub const FOR_ESCAPING: &ForEscaping = {
     let mut is_backslash_escaped = 0;
 
     let escaped = [
+        (b'\0', b'0'),
         (b'\t', b't'),
         (b'\n', b'n'),
         (b'\r', b'r'),
-        (b'\'', b'\''),
         (b'"', b'"'),
         (b'\\', b'\\'),
     ];
@@ -298,8 +593,8 @@ pub const FOR_ESCAPING: &ForEscaping = {
         escape_char[ei] = escape;
     }
 
-    // Setting all the control characters as being escaped.
-    let is_escaped = is_backslash_escaped | 0xFFFF_FFFF;
+    // Setting all the control characters (including DEL) as being escaped.
+    let is_escaped = is_backslash_escaped | 0xFFFF_FFFF | (1 << 0x7F);
 
     &ForEscaping {
         escape_char,
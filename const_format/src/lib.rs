@@ -12,12 +12,20 @@
 //! These macros are available in Rust 1.60.0:
 //!
 //! - [`concatcp`]:
-//! Concatenates `integers`, `bool`, `char`, and `&str` constants into a `&'static str` constant.
+//! Concatenates `integers`, `floats`, `bool`, `char`, and `&str` constants into a `&'static str` constant.
 //!
 //! - [`formatcp`]:
-//! [`format`]-like formatting which takes `integers`, `bool`, `char`, and `&str` constants,
+//! [`format`]-like formatting which takes `integers`, `floats`, `bool`, `char`, and `&str` constants,
 //! and emits a `&'static str` constant.
 //!
+//! - [`concatbytes`]/[`formatbytes`]:
+//! Equivalents of [`concatcp`]/[`formatcp`] which also take `u8` and `&[u8]` constants,
+//! and emit a `&'static [u8; N]` constant.
+//!
+//! - [`concatcp_cap`]/[`formatcp_cap`]:
+//! Equivalents of [`concatcp`]/[`formatcp`] which format into a buffer of a fixed capacity,
+//! so that they can take arguments that use generic parameters.
+//!
 //! - [`str_get`]:
 //! Indexes a `&'static str` constant, returning `None` when the index is out of bounds.
 //!
@@ -32,15 +40,56 @@
 //!
 //! - [`map_ascii_case`]:
 //! Converts a `&'static str` constant to a different casing style,
-//! determined by a [`Case`] argument.
+//! determined by a [`Case`] argument,
+//! and an optional [`CaseOptions`] argument.
+//!
+//! - [`map_case`]:
+//! Like [`map_ascii_case`], but converts the case of all Unicode characters.
+//!
+//! - [`is_case`]:
+//! Queries whether a `&'static str` constant is already in a casing style.
 //!
 //! - [`str_replace`]:
 //! Replaces all the instances of a pattern in a `&'static str` constant with
 //! another `&'static str` constant.
 //!
+//! - [`str_escape`]:
+//! Escapes a `&'static str` constant for use in JSON, C, HTML, shell, or URL text,
+//! determined by an [`Escape`] argument.
+//!
+//! - [`str_parse`]:
+//! Parses a `&'static str` constant into an integer or a `bool`.
+//!
+//! - [`str_scan`]:
+//! Extracts integers and `bool`s out of a `&'static str` constant,
+//! using a pattern with the syntax of format strings.
+//!
+//! - [`hex_encode`]/[`base64_encode`]:
+//! Encodes a `&'static [u8]` or `&'static str` constant as hexadecimal/base64.
+//!
+//! - [`hex_decode`]/[`base64_decode`]:
+//! Decodes a hexadecimal/base64 constant into a `&'static [u8]`,
+//! causing a compile-time error on invalid input.
+//!
+//! - [`str_join`]/[`str_concat_slice`]:
+//! Joins a `&'static [&'static str]` constant into a `&'static str`,
+//! with and without a separator respectively.
+//!
+//! - [`str_trim`]/[`str_trim_start`]/[`str_trim_end`]:
+//! Removes whitespace from the start and/or end of a `&'static str` constant.
+//! The [`str_trim_matches`]/[`str_trim_start_matches`]/[`str_trim_end_matches`]
+//! variants remove a pattern instead.
+//!
+//! - [`str_find`]/[`str_rfind`]:
+//! Finds the byte index of the first/last instance of a pattern in a `&'static str` constant.
+//!
+//! - [`str_contains`]/[`str_starts_with`]/[`str_ends_with`]:
+//! Queries whether a `&'static str` constant contains/starts with/ends with a pattern.
+//!
 //!
 //! The `"assertcp"` feature enables the [`assertcp`], [`assertcp_eq`],
-//! and [`assertcp_ne`] macros.
+//! [`assertcp_ne`], [`assertcp_lt`], [`assertcp_le`], [`assertcp_gt`], [`assertcp_ge`],
+//! and [`assertcp_case`] macros.
 //! These macros are like the standard library assert macros,
 //! but evaluated at compile-time,
 //! with the limitation that they can only have primitive types as arguments
@@ -52,6 +101,9 @@
 //!
 //! -  [`str_split`]: splits a string constant
 //!
+//! -  [`concatcp_cstr`]/[`formatcp_cstr`]:
+//! versions of [`concatcp`]/[`formatcp`] which emit a nul-terminated `&'static CStr` constant.
+//!
 //! ### Rust 1.83.0
 //!
 //! By enabling the "fmt" feature, you can use a [`std::fmt`]-like API.
@@ -71,14 +123,19 @@
 //! [`write`]-like macro that can format many standard library and user defined types
 //! into a type that implements [`WriteMarker`].
 //!
-//! The `"derive"` feature enables the [`ConstDebug`] macro,
+//! The `"derive"` feature enables the [`ConstDebug`] and [`ConstDisplay`] macros,
 //! and the `"fmt"` feature.<br>
 //! [`ConstDebug`] derives the [`FormatMarker`] trait,
-//! and implements an inherent `const_debug_fmt` method for compile-time debug formatting.
+//! and implements an inherent `const_debug_fmt` method for compile-time debug formatting.<br>
+//! [`ConstDisplay`] implements an inherent `const_display_fmt` method for compile-time display formatting,
+//! from the format strings in `#[cdisplay("...")]` attributes.
 //!
-//! The `"assertc"` feature enables the [`assertc`], [`assertc_eq`], [`assertc_ne`] macros,
+//! The `"assertc"` feature enables the [`assertc`], [`assertc_eq`], [`assertc_ne`],
+//! [`assertc_lt`], [`assertc_le`], [`assertc_gt`], and [`assertc_ge`] macros,
 //! and the `"fmt"` feature.<br>
-//! These macros are like the standard library assert macros, but evaluated at compile-time.
+//! These macros are like the standard library assert macros, but evaluated at compile-time.<br>
+//! With the `"derive"` feature, it also enables the [`ConstEq`] and [`ConstCmp`] macros,
+//! which implement inherent `const_eq` and `const_cmp` methods to compare user-defined types.
 //!
 //! # Examples
 //!
@@ -228,12 +285,17 @@
 //! assert_eq!(const_format::concatcp!(1 + 1, 2 + 1), "23");
 //! ```
 //!
+//! Unsuffixed float literals (optionally negated) are the exception,
+//! all macros other than `concatc` assume that they're `f64`s,
+//! so `concatcp!("ratio=", 0.5)` compiles and evaluates to `"ratio=0.5"`.
+//!
 //! # Renaming crate
 //!
 //! All function-like macros from `const_format` can be used when the crate is renamed.
 //!
-//! The [`ConstDebug`] derive macro has the `#[cdeb(crate = "foo::bar")]` attribute to
-//! tell it where to find the `const_format` crate.
+//! The [`ConstDebug`] and [`ConstDisplay`] derive macros have the
+//! `#[cdeb(crate = "foo::bar")]` and `#[cdisplay(crate = "foo::bar")]` attributes respectively
+//! to tell them where to find the `const_format` crate.
 //!
 //! Example of renaming the `const_format` crate in the Cargo.toml file:
 //! ```toml
@@ -248,18 +310,22 @@
 //! This feature includes the [`formatc`]/[`writec`] formatting macros.
 //!
 //! - `"derive"`: requires Rust 1.83.0, implies the `"fmt"` feature,
-//! provides the [`ConstDebug`] derive macro to format user-defined types at compile-time.<br>
+//! provides the [`ConstDebug`] and [`ConstDisplay`] derive macros
+//! to format user-defined types at compile-time.<br>
 //! This implicitly uses the `syn` crate, so clean compiles take a bit longer than without the feature.
 //!
 //! - `"assertc"`: requires Rust 1.83.0, implies the `"fmt"` feature,
-//! enables the [`assertc`], [`assertc_eq`], and [`assertc_ne`] assertion macros.<br>
+//! enables the [`assertc`], [`assertc_eq`], [`assertc_ne`], [`assertc_lt`], [`assertc_le`],
+//! [`assertc_gt`], and [`assertc_ge`] assertion macros,
+//! and the [`ConstEq`] and [`ConstCmp`] derive macros if the `"derive"` feature is also enabled.<br>
 //! This feature was previously named `"assert"`,
 //! but it was renamed to avoid confusion with the `"assertcp"` feature.
 //!
 //! - `"assertcp"`:
-//! Enables the [`assertcp`], [`assertcp_eq`], and [`assertcp_ne`] assertion macros.
+//! Enables the [`assertcp`], [`assertcp_eq`], [`assertcp_ne`], [`assertcp_lt`],
+//! [`assertcp_le`], [`assertcp_gt`], [`assertcp_ge`], and [`assertcp_case`] assertion macros.
 //!
-//! - `"rust_1_64"`: Enables the [`str_split`] macro.
+//! - `"rust_1_64"`: Enables the [`str_split`], [`concatcp_cstr`], and [`formatcp_cstr`] macros.
 //! Allows the `as_bytes_alt` methods and `slice_up_to_len_alt` methods to run
 //! in constant time, rather than linear time (proportional to the truncated part of the slice).
 //!
@@ -284,16 +350,46 @@
 //!
 //! [`assertc_ne`]: ./macro.assertc_ne.html
 //!
+//! [`assertc_lt`]: ./macro.assertc_lt.html
+//!
+//! [`assertc_le`]: ./macro.assertc_le.html
+//!
+//! [`assertc_gt`]: ./macro.assertc_gt.html
+//!
+//! [`assertc_ge`]: ./macro.assertc_ge.html
+//!
 //! [`assertcp`]: ./macro.assertcp.html
 //!
 //! [`assertcp_eq`]: ./macro.assertcp_eq.html
 //!
 //! [`assertcp_ne`]: ./macro.assertcp_ne.html
 //!
+//! [`assertcp_lt`]: ./macro.assertcp_lt.html
+//!
+//! [`assertcp_le`]: ./macro.assertcp_le.html
+//!
+//! [`assertcp_gt`]: ./macro.assertcp_gt.html
+//!
+//! [`assertcp_ge`]: ./macro.assertcp_ge.html
+//!
+//! [`assertcp_case`]: ./macro.assertcp_case.html
+//!
 //! [`concatcp`]: ./macro.concatcp.html
 //!
 //! [`formatcp`]: ./macro.formatcp.html
 //!
+//! [`concatbytes`]: ./macro.concatbytes.html
+//!
+//! [`formatbytes`]: ./macro.formatbytes.html
+//!
+//! [`concatcp_cap`]: ./macro.concatcp_cap.html
+//!
+//! [`formatcp_cap`]: ./macro.formatcp_cap.html
+//!
+//! [`concatcp_cstr`]: ./macro.concatcp_cstr.html
+//!
+//! [`formatcp_cstr`]: ./macro.formatcp_cstr.html
+//!
 //! [`format`]: https://doc.rust-lang.org/std/macro.format.html
 //!
 //! [`std::fmt`]: https://doc.rust-lang.org/std/fmt/index.html
@@ -314,14 +410,24 @@
 //!
 //! [`ConstDebug`]: ./derive.ConstDebug.html
 //!
+//! [`ConstDisplay`]: ./derive.ConstDisplay.html
+//! [`ConstEq`]: ./derive.ConstEq.html
+//! [`ConstCmp`]: ./derive.ConstCmp.html
+//!
 //! [`FormatMarker`]: ./marker_traits/trait.FormatMarker.html
 //!
 //! [`WriteMarker`]: ./marker_traits/trait.WriteMarker.html
 //!
 //! [`map_ascii_case`]: ./macro.map_ascii_case.html
 //!
+//! [`map_case`]: ./macro.map_case.html
+//!
+//! [`is_case`]: ./fn.is_case.html
+//!
 //! [`Case`]: ./enum.Case.html
 //!
+//! [`CaseOptions`]: ./struct.CaseOptions.html
+//!
 //!
 //! [`str_get`]: ./macro.str_get.html
 //!
@@ -333,8 +439,50 @@
 //!
 //! [`str_replace`]: ./macro.str_replace.html
 //!
+//! [`str_escape`]: ./macro.str_escape.html
+//!
+//! [`Escape`]: ./enum.Escape.html
+//!
+//! [`str_parse`]: ./macro.str_parse.html
+//!
+//! [`str_scan`]: ./macro.str_scan.html
+//!
+//! [`hex_encode`]: ./macro.hex_encode.html
+//!
+//! [`hex_decode`]: ./macro.hex_decode.html
+//!
+//! [`base64_encode`]: ./macro.base64_encode.html
+//!
+//! [`base64_decode`]: ./macro.base64_decode.html
+//!
 //! [`str_split`]: ./macro.str_split.html
 //!
+//! [`str_join`]: ./macro.str_join.html
+//!
+//! [`str_concat_slice`]: ./macro.str_concat_slice.html
+//!
+//! [`str_trim`]: ./macro.str_trim.html
+//!
+//! [`str_trim_start`]: ./macro.str_trim_start.html
+//!
+//! [`str_trim_end`]: ./macro.str_trim_end.html
+//!
+//! [`str_trim_matches`]: ./macro.str_trim_matches.html
+//!
+//! [`str_trim_start_matches`]: ./macro.str_trim_start_matches.html
+//!
+//! [`str_trim_end_matches`]: ./macro.str_trim_end_matches.html
+//!
+//! [`str_find`]: ./macro.str_find.html
+//!
+//! [`str_rfind`]: ./macro.str_rfind.html
+//!
+//! [`str_contains`]: ./macro.str_contains.html
+//!
+//! [`str_starts_with`]: ./macro.str_starts_with.html
+//!
+//! [`str_ends_with`]: ./macro.str_ends_with.html
+//!
 //! [`str::replace`]: https://doc.rust-lang.org/std/primitive.str.html#method.replace
 //!
 //! [inline const patterns]: https://doc.rust-lang.org/1.83.0/unstable-book/language-features/inline-const-pat.html
@@ -356,6 +504,12 @@
 
 include! {"const_debug_derive.rs"}
 
+include! {"const_display_derive.rs"}
+
+include! {"const_eq_derive.rs"}
+
+include! {"const_cmp_derive.rs"}
+
 #[macro_use]
 mod macros;
 
@@ -371,6 +525,8 @@ pub mod for_assert_macros;
 
 mod char_encoding;
 
+mod float_encoding;
+
 mod pargument;
 
 mod const_generic_concatcp;
@@ -419,9 +575,9 @@ pub mod __ascii_case_conv;
 #[doc(hidden)]
 pub mod __str_methods;
 
-pub use __str_methods::SplicedStr;
+pub use __str_methods::{Escape, SplicedStr};
 
-pub use __ascii_case_conv::Case;
+pub use __ascii_case_conv::{is_case, Acronyms, Case, CaseOptions, WordCase};
 
 #[cfg(feature = "fmt")]
 #[doc(no_inline)]
@@ -444,9 +600,12 @@ pub mod __cf_osRcTFl4A {
 
 #[doc(hidden)]
 pub mod pmr {
-    pub use {bool, str, u8, usize};
+    pub use {bool, i8, str, u8, usize};
 
-    pub use const_format_proc_macros::{__concatcp_impl, __formatcp_impl, respan_to};
+    pub use const_format_proc_macros::{
+        __concatbytes_impl, __concatcp_cap_impl, __concatcp_impl, __formatbytes_impl,
+        __formatcp_cap_impl, __formatcp_impl, __str_scan_impl, respan_to,
+    };
 
     #[cfg(feature = "fmt")]
     pub use const_format_proc_macros::{__formatc_if_impl, __formatc_impl, __writec_impl};
@@ -455,7 +614,7 @@ pub mod pmr {
     pub use const_format_proc_macros::__formatcp_if_impl;
 
     pub use core::{
-        cmp::Reverse,
+        cmp::{Ordering, Reverse},
         convert::identity,
         mem::transmute,
         num::Wrapping,
@@ -464,23 +623,35 @@ pub mod pmr {
         result::Result::{self, Err, Ok},
     };
 
-    pub use crate::const_generic_concatcp::__priv_concatenate;
+    pub use crate::const_generic_concatcp::{
+        __priv_cap_to_str, __priv_concatenate, __priv_concatenate_cap,
+    };
+
+    #[cfg(feature = "rust_1_64")]
+    pub use crate::const_generic_concatcp::__priv_to_cstr_bytes;
+
+    #[cfg(feature = "rust_1_64")]
+    pub use core::ffi::CStr;
 
     #[cfg(feature = "assertcp")]
     pub use crate::for_assert_macros::{assert_, ConcatArgsIf};
 
+    #[cfg(feature = "fmt")]
+    pub use core::fmt as core_fmt;
+
     #[cfg(feature = "fmt")]
     pub use crate::{
-        fmt::{ComputeStrLength, Error, Formatter, StrWriter, StrWriterMut, ToResult},
+        fmt::{ComputeStrLength, Error, Formatter, StdAdapter, StrWriter, StrWriterMut, ToResult},
         marker_traits::{
             FormatMarker, IsAFormatMarker, IsAWriteMarker, IsNotStdKind, IsStdKind, WriteMarker,
         },
     };
 
     pub use crate::{
+        char_encoding::{str_char_debug, StrCharDebug},
         formatting::{
-            hex_as_ascii, ForEscaping, Formatting, FormattingFlags, HexFormatting, LenAndArray,
-            NumberFormatting, StartAndArray, FOR_ESCAPING,
+            hex_as_ascii, Alignment, ForEscaping, Formatting, FormattingFlags, HexFormatting,
+            LenAndArray, NumberFormatting, Padding, StartAndArray, FOR_ESCAPING,
         },
         pargument::{PArgument, PConvWrapper, PVariant},
         wrapper_types::PWrapper,
@@ -56,3 +56,16 @@ macro_rules! __assertc_common {
         }
     );
 }
+
+// Turns the result of a `const_eq` or `const_cmp` method call into
+// the condition of an equality or ordering assertion.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assertc_compare {
+    (const_eq, $compared:expr, $($op:tt)*) => {
+        $compared $($op)* true
+    };
+    (const_cmp, $compared:expr, $($op:tt)*) => {
+        ($compared as $crate::pmr::i8) $($op)* 0
+    };
+}
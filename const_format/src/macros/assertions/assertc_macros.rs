@@ -259,6 +259,7 @@ assert_eq_docs! {
             $crate::__assertc_equality_inner!{
                 ($($parameters)*)
                 ($($parameters)*)
+                (const_eq)
                 ( == )
                 ("==")
             }
@@ -383,6 +384,7 @@ assert_eq_docs! {
             $crate::__assertc_equality_inner!{
                 ($($parameters)*)
                 ($($parameters)*)
+                (const_eq)
                 ( != )
                 ("!=")
             }
@@ -400,6 +402,7 @@ macro_rules! __assertc_equality_inner {
             $right:expr
             $(, $fmt_literal:expr $(,$fmt_arg:expr)*)? $(,)?
         )
+        ($method:ident)
         ($($op:tt)*)
         ($op_str:expr)
     )=>{
@@ -407,21 +410,23 @@ macro_rules! __assertc_equality_inner {
             use $crate::__cf_osRcTFl4A;
             use $crate::pmr::respan_to as __cf_respan_to;
 
-            const LEFT: $crate::pmr::bool = {
+            const COND: $crate::pmr::bool = {
                 // Have to use `respan_to` to make the `multiple coerce found` error
                 // point at the `$left` argument here.
                 use $crate::coerce_to_fmt as __cf_coerce_to_fmt;
                 match [&$left, &$right] {
-                    __cf_respan_to!(($left) [left, right]) =>
-                        __cf_respan_to!(($left) __cf_coerce_to_fmt!(left).const_eq(right)),
+                    __cf_respan_to!(($left) [left, right]) => $crate::__assertc_compare!(
+                        $method,
+                        __cf_respan_to!(($left) __cf_coerce_to_fmt!(left).$method(right)),
+                        $($op)*
+                    ),
                 }
             };
-            const RIGHT: $crate::pmr::bool = true;
 
             $crate::__assertc_common!{
                 __formatc_if_impl
                 ($($parameters)*)
-                (LEFT $($op)* RIGHT)
+                (COND)
                 (
                     concat!(
                         "\nassertion failed: `(left ",
@@ -439,3 +444,320 @@ macro_rules! __assertc_equality_inner {
         };
     }
 }
+
+macro_rules! assert_cmp_docs {
+    (
+        $(#[$documentation:meta])*
+        ;documentation
+        $item:item
+    ) => (
+        with_shared_docs! {
+            $(#[$documentation])*
+            ;clarification
+            /// # Arguments
+            ///
+            /// This macro accepts these types for comparison and debug printing:
+            ///
+            /// - Standard library types for which  [`PWrapper`] wrapping that type
+            /// has a `const_cmp` method.
+            /// This includes all integer types, `char`, `bool`, `&str`,
+            /// and slices/arrays of those.
+            ///
+            /// - non-standard-library types that implement [`FormatMarker`] with debug formatting<br>
+            /// and have a `const fn const_cmp(&self, other:&Self) -> Ordering` inherent method,
+            /// eg: types that derive both [`ConstDebug`] and [`ConstCmp`].
+            ///
+            /// [`ConstDebug`]: ./derive.ConstDebug.html
+            /// [`ConstCmp`]: ./derive.ConstCmp.html
+            ///
+            ;syntax
+            ;error_message
+            ;limitations
+            $item
+        }
+    )
+}
+
+assert_cmp_docs! {
+    /// Compile-time less-than assertion with formatting.
+    ///
+    ;documentation
+    ///
+    /// # Examples
+    ///
+    /// ### Passing assertion
+    ///
+    /// ```rust
+    ///
+    /// use const_format::assertc_lt;
+    ///
+    /// use std::mem::size_of;
+    ///
+    /// assertc_lt!(size_of::<u16>(), size_of::<u32>());
+    ///
+    /// assertc_lt!([3u8, 5, 8], [3u8, 8, 0], "arrays are compared lexicographically");
+    ///
+    /// # fn main(){}
+    /// ```
+    ///
+    /// ### Comparing user-defined types
+    ///
+    /// This example demonstrates a failing assertion comparing a user-defined type,
+    /// and how the compiler error looks like as of 2026-10-18.
+    ///
+    #[cfg_attr(feature = "derive", doc = "```compile_fail")]
+    #[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+    ///
+    /// use const_format::{assertc_lt, ConstCmp, ConstDebug};
+    ///
+    /// #[derive(ConstDebug, ConstCmp)]
+    /// struct Version {
+    ///     major: u16,
+    ///     minor: u16,
+    /// }
+    ///
+    /// const CURRENT: Version = Version { major: 1, minor: 10 };
+    ///
+    /// assertc_lt!(CURRENT, Version { major: 1, minor: 2 }, "must be older than 1.2");
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked:
+    ///               assertion failed: `(left < right)`
+    ///                left: `Version {
+    ///                   major: 1,
+    ///                   minor: 10,
+    ///               }`
+    ///               right: `Version {
+    ///                   major: 1,
+    ///                   minor: 2,
+    ///               }`
+    ///               must be older than 1.2
+    ///
+    ///   --> src/lib.rs:11:13
+    ///    |
+    /// 11 | assertc_lt!(CURRENT, Version { major: 1, minor: 2 }, "must be older than 1.2");
+    ///    |             ^^^^^^^ evaluation of `_` failed here
+    /// ```
+    ///
+    #[cfg_attr(feature = "__docsrs", doc(cfg(feature = "assertc")))]
+    #[macro_export]
+    macro_rules! assertc_lt {
+        ($($parameters:tt)*) => (
+            $crate::__assertc_equality_inner!{
+                ($($parameters)*)
+                ($($parameters)*)
+                (const_cmp)
+                ( < )
+                ("<")
+            }
+        );
+    }
+}
+
+assert_cmp_docs! {
+    /// Compile-time less-than-or-equal assertion with formatting.
+    ///
+    ;documentation
+    ///
+    /// # Examples
+    ///
+    /// ### Passing assertion
+    ///
+    /// ```rust
+    ///
+    /// use const_format::assertc_le;
+    ///
+    /// const MAX_SIZE: usize = 4096;
+    /// assertc_le!(MAX_SIZE, 4096usize, "MAX_SIZE must be <= 4096");
+    ///
+    /// assertc_le!("bar", "baz");
+    ///
+    /// # fn main(){}
+    /// ```
+    ///
+    /// ### Failing assertion
+    ///
+    /// This example demonstrates a failing assertion,
+    /// and how the compiler error looks like as of 2026-10-18.
+    ///
+    /// ```compile_fail
+    ///
+    /// use const_format::assertc_le;
+    ///
+    /// const NAMES: [&str; 2] = ["foo", "bar"];
+    ///
+    /// assertc_le!(NAMES, ["foo", "baa"], "NAMES is out of order");
+    ///
+    /// # fn main(){}
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked:
+    ///               assertion failed: `(left <= right)`
+    ///                left: `[
+    ///                   "foo",
+    ///                   "bar",
+    ///               ]`
+    ///               right: `[
+    ///                   "foo",
+    ///                   "baa",
+    ///               ]`
+    ///               NAMES is out of order
+    ///
+    ///  --> src/lib.rs:6:13
+    ///   |
+    /// 6 | assertc_le!(NAMES, ["foo", "baa"], "NAMES is out of order");
+    ///   |             ^^^^^ evaluation of `_` failed here
+    /// ```
+    ///
+    #[cfg_attr(feature = "__docsrs", doc(cfg(feature = "assertc")))]
+    #[macro_export]
+    macro_rules! assertc_le {
+        ($($parameters:tt)*) => (
+            $crate::__assertc_equality_inner!{
+                ($($parameters)*)
+                ($($parameters)*)
+                (const_cmp)
+                ( <= )
+                ("<=")
+            }
+        );
+    }
+}
+
+assert_cmp_docs! {
+    /// Compile-time greater-than assertion with formatting.
+    ///
+    ;documentation
+    ///
+    /// # Examples
+    ///
+    /// ### Passing assertion
+    ///
+    /// ```rust
+    ///
+    /// use const_format::assertc_gt;
+    ///
+    /// const THREADS: u32 = 4;
+    /// assertc_gt!(THREADS, 0u32, "there must be at least one thread");
+    ///
+    /// assertc_gt!('b', 'a');
+    ///
+    /// # fn main(){}
+    /// ```
+    ///
+    /// ### Failing assertion
+    ///
+    /// This example demonstrates a failing assertion,
+    /// and how the compiler error looks like as of 2026-10-18.
+    ///
+    /// ```compile_fail
+    ///
+    /// use const_format::assertc_gt;
+    ///
+    /// const THREADS: u32 = 0;
+    /// assertc_gt!(THREADS, 0u32, "there must be at least one thread");
+    ///
+    /// # fn main(){}
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked:
+    ///               assertion failed: `(left > right)`
+    ///                left: `0`
+    ///               right: `0`
+    ///               there must be at least one thread
+    ///
+    ///  --> src/lib.rs:5:13
+    ///   |
+    /// 5 | assertc_gt!(THREADS, 0u32, "there must be at least one thread");
+    ///   |             ^^^^^^^ evaluation of `_` failed here
+    /// ```
+    ///
+    #[cfg_attr(feature = "__docsrs", doc(cfg(feature = "assertc")))]
+    #[macro_export]
+    macro_rules! assertc_gt {
+        ($($parameters:tt)*) => (
+            $crate::__assertc_equality_inner!{
+                ($($parameters)*)
+                ($($parameters)*)
+                (const_cmp)
+                ( > )
+                (">")
+            }
+        );
+    }
+}
+
+assert_cmp_docs! {
+    /// Compile-time greater-than-or-equal assertion with formatting.
+    ///
+    ;documentation
+    ///
+    /// # Examples
+    ///
+    /// ### Passing assertion
+    ///
+    /// ```rust
+    ///
+    /// use const_format::assertc_ge;
+    ///
+    /// const BUFFER_LEN: usize = 64;
+    /// assertc_ge!(BUFFER_LEN, 64usize, "BUFFER_LEN must be at least 64");
+    ///
+    /// assertc_ge!(["foo", "bar"], ["foo", "bar"]);
+    ///
+    /// # fn main(){}
+    /// ```
+    ///
+    /// ### Failing assertion
+    ///
+    /// This example demonstrates a failing assertion,
+    /// and how the compiler error looks like as of 2026-10-18.
+    ///
+    /// ```compile_fail
+    ///
+    /// use const_format::assertc_ge;
+    ///
+    /// const OFFSET: i32 = -3;
+    /// assertc_ge!(OFFSET, 0i32, "OFFSET can't be negative");
+    ///
+    /// # fn main(){}
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked:
+    ///               assertion failed: `(left >= right)`
+    ///                left: `-3`
+    ///               right: `0`
+    ///               OFFSET can't be negative
+    ///
+    ///  --> src/lib.rs:5:13
+    ///   |
+    /// 5 | assertc_ge!(OFFSET, 0i32, "OFFSET can't be negative");
+    ///   |             ^^^^^^ evaluation of `_` failed here
+    /// ```
+    ///
+    #[cfg_attr(feature = "__docsrs", doc(cfg(feature = "assertc")))]
+    #[macro_export]
+    macro_rules! assertc_ge {
+        ($($parameters:tt)*) => (
+            $crate::__assertc_equality_inner!{
+                ($($parameters)*)
+                ($($parameters)*)
+                (const_cmp)
+                ( >= )
+                (">=")
+            }
+        );
+    }
+}
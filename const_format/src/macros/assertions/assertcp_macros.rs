@@ -125,6 +125,7 @@ macro_rules! __assertcp_equality_inner {
             $right:expr
             $(, $fmt_literal:expr $(,$fmt_arg:expr)*)? $(,)?
         )
+        ($method:ident)
         ($($op:tt)*)
         ($op_str:expr)
     )=>{
@@ -136,7 +137,7 @@ macro_rules! __assertcp_equality_inner {
             = {
                 let left = $crate::PWrapper($left);
                 let right = $crate::pmr::PConvWrapper($right);
-                let cond = left.const_eq(&right.0);
+                let cond = $crate::__assertc_compare!($method, left.$method(&right.0), $($op)*);
                 let fmt = $crate::pmr::FormattingFlags::NEW.set_alternate(true);
                 (
                     cond,
@@ -148,7 +149,7 @@ macro_rules! __assertcp_equality_inner {
             $crate::__assertc_common!{
                 __formatcp_if_impl
                 ($($parameters)*)
-                (ARGS_NHPMWYD3NJA.0 $($op)* true)
+                (ARGS_NHPMWYD3NJA.0)
                 (
                     concat!(
                         "\nassertion failed: `(left ",
@@ -229,6 +230,7 @@ with_shared_docs! {
             $crate::__assertcp_equality_inner!{
                 ($($parameters)*)
                 ($($parameters)*)
+                (const_eq)
                 ( == )
                 ("==")
             }
@@ -290,9 +292,382 @@ with_shared_docs! {
             $crate::__assertcp_equality_inner!{
                 ($($parameters)*)
                 ($($parameters)*)
+                (const_eq)
                 ( != )
                 ("!=")
             }
         );
     }
 }
+
+macro_rules! assertcp_ordering_docs {
+    (
+        $(#[$documentation:meta])*
+        ;documentation
+        $item:item
+    ) => (
+        with_shared_docs! {
+            $(#[$documentation])*
+            ;clarification
+            /// The operands are compared with the `const_cmp` method of [`PWrapper`],
+            /// which compares `&str`s lexicographically by their bytes,
+            /// and `false` as less than `true`.
+            ///
+            /// [`PWrapper`]: ./struct.PWrapper.html
+            ///
+            ;syntax
+            ;limitations
+            $item
+        }
+    )
+}
+
+assertcp_ordering_docs! {
+    /// Compile-time less-than assertion with formatting.
+    ///
+    ;documentation
+    ///
+    /// # Examples
+    ///
+    /// ### Passing assertion
+    ///
+    /// ```rust
+    /// use const_format::assertcp_lt;
+    ///
+    /// const LEN: usize = 3;
+    /// assertcp_lt!(LEN, 4usize, "LEN must be smaller than 4, it's {}", LEN);
+    ///
+    /// assertcp_lt!("apple", "banana");
+    /// ```
+    ///
+    /// ### Failing assertion
+    ///
+    /// This example demonstrates a failing assertion,
+    /// and how the compiler error looks like as of 2026-10-18.
+    ///
+    /// ```compile_fail
+    /// use const_format::assertcp_lt;
+    ///
+    /// const LEVEL: u8 = 9;
+    /// assertcp_lt!(LEVEL, 9u8, "LEVEL is out of range");
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked:
+    ///               assertion failed: `(left < right)`
+    ///                left: `9`
+    ///               right: `9`
+    ///               LEVEL is out of range
+    ///
+    ///  --> src/lib.rs:4:14
+    ///   |
+    /// 4 | assertcp_lt!(LEVEL, 9u8, "LEVEL is out of range");
+    ///   |              ^^^^^ evaluation of `_` failed here
+    /// ```
+    ///
+    #[cfg_attr(feature = "__docsrs", doc(cfg(feature = "assertcp")))]
+    #[macro_export]
+    macro_rules! assertcp_lt {
+        ($($parameters:tt)*) => (
+            $crate::__assertcp_equality_inner!{
+                ($($parameters)*)
+                ($($parameters)*)
+                (const_cmp)
+                ( < )
+                ("<")
+            }
+        );
+    }
+}
+
+assertcp_ordering_docs! {
+    /// Compile-time less-than-or-equal assertion with formatting.
+    ///
+    ;documentation
+    ///
+    /// # Examples
+    ///
+    /// ### Passing assertion
+    ///
+    /// ```rust
+    /// use const_format::assertcp_le;
+    ///
+    /// const MAX_SIZE: usize = 4096;
+    /// assertcp_le!(MAX_SIZE, 4096usize, "MAX_SIZE must be <= 4096");
+    ///
+    /// assertcp_le!('a', 'b');
+    /// ```
+    ///
+    /// ### Failing assertion
+    ///
+    /// This example demonstrates a failing assertion,
+    /// and how the compiler error looks like as of 2026-10-18.
+    ///
+    /// ```compile_fail
+    /// use const_format::assertcp_le;
+    ///
+    /// const MAX_SIZE: usize = 8192;
+    /// assertcp_le!(MAX_SIZE, 4096usize, "MAX_SIZE must be <= 4096");
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked:
+    ///               assertion failed: `(left <= right)`
+    ///                left: `8192`
+    ///               right: `4096`
+    ///               MAX_SIZE must be <= 4096
+    ///
+    ///  --> src/lib.rs:4:14
+    ///   |
+    /// 4 | assertcp_le!(MAX_SIZE, 4096usize, "MAX_SIZE must be <= 4096");
+    ///   |              ^^^^^^^^ evaluation of `_` failed here
+    /// ```
+    ///
+    #[cfg_attr(feature = "__docsrs", doc(cfg(feature = "assertcp")))]
+    #[macro_export]
+    macro_rules! assertcp_le {
+        ($($parameters:tt)*) => (
+            $crate::__assertcp_equality_inner!{
+                ($($parameters)*)
+                ($($parameters)*)
+                (const_cmp)
+                ( <= )
+                ("<=")
+            }
+        );
+    }
+}
+
+assertcp_ordering_docs! {
+    /// Compile-time greater-than assertion with formatting.
+    ///
+    ;documentation
+    ///
+    /// # Examples
+    ///
+    /// ### Passing assertion
+    ///
+    /// ```rust
+    /// use const_format::assertcp_gt;
+    ///
+    /// const THREADS: u32 = 4;
+    /// assertcp_gt!(THREADS, 0u32, "there must be at least one thread");
+    ///
+    /// assertcp_gt!(true, false);
+    /// ```
+    ///
+    /// ### Failing assertion
+    ///
+    /// This example demonstrates a failing assertion,
+    /// and how the compiler error looks like as of 2026-10-18.
+    ///
+    /// ```compile_fail
+    /// use const_format::assertcp_gt;
+    ///
+    /// // strings are compared lexicographically, so "1.10.0" is less than "1.2.0"
+    /// const VERSION: &str = "1.10.0";
+    /// assertcp_gt!(VERSION, "1.2.0");
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked:
+    ///               assertion failed: `(left > right)`
+    ///                left: `"1.10.0"`
+    ///               right: `"1.2.0"`
+    ///  --> src/lib.rs:5:14
+    ///   |
+    /// 5 | assertcp_gt!(VERSION, "1.2.0");
+    ///   |              ^^^^^^^ evaluation of `_` failed here
+    /// ```
+    ///
+    #[cfg_attr(feature = "__docsrs", doc(cfg(feature = "assertcp")))]
+    #[macro_export]
+    macro_rules! assertcp_gt {
+        ($($parameters:tt)*) => (
+            $crate::__assertcp_equality_inner!{
+                ($($parameters)*)
+                ($($parameters)*)
+                (const_cmp)
+                ( > )
+                (">")
+            }
+        );
+    }
+}
+
+assertcp_ordering_docs! {
+    /// Compile-time greater-than-or-equal assertion with formatting.
+    ///
+    ;documentation
+    ///
+    /// # Examples
+    ///
+    /// ### Passing assertion
+    ///
+    /// ```rust
+    /// use const_format::assertcp_ge;
+    ///
+    /// const BUFFER_LEN: usize = 64;
+    /// assertcp_ge!(BUFFER_LEN, 64usize, "BUFFER_LEN must be at least 64");
+    ///
+    /// assertcp_ge!("b", "abc");
+    /// ```
+    ///
+    /// ### Failing assertion
+    ///
+    /// This example demonstrates a failing assertion,
+    /// and how the compiler error looks like as of 2026-10-18.
+    ///
+    /// ```compile_fail
+    /// use const_format::assertcp_ge;
+    ///
+    /// const OFFSET: i32 = -3;
+    /// assertcp_ge!(OFFSET, 0i32, "OFFSET can't be negative");
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked:
+    ///               assertion failed: `(left >= right)`
+    ///                left: `-3`
+    ///               right: `0`
+    ///               OFFSET can't be negative
+    ///
+    ///  --> src/lib.rs:4:14
+    ///   |
+    /// 4 | assertcp_ge!(OFFSET, 0i32, "OFFSET can't be negative");
+    ///   |              ^^^^^^ evaluation of `_` failed here
+    /// ```
+    ///
+    #[cfg_attr(feature = "__docsrs", doc(cfg(feature = "assertcp")))]
+    #[macro_export]
+    macro_rules! assertcp_ge {
+        ($($parameters:tt)*) => (
+            $crate::__assertcp_equality_inner!{
+                ($($parameters)*)
+                ($($parameters)*)
+                (const_cmp)
+                ( >= )
+                (">=")
+            }
+        );
+    }
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assertcp_case_inner {
+    (
+        ($($parameters:tt)*)
+        (
+            $case:expr,
+            $string:expr
+            $(, $fmt_literal:expr $(,$fmt_arg:expr)*)? $(,)?
+        )
+    )=>{
+        #[allow(non_snake_case)]
+        const _: () = {
+            use $crate::__cf_osRcTFl4A;
+            const CASE_NHPMWYD3NJA: $crate::Case = $case;
+            const STR_NHPMWYD3NJA: &$crate::pmr::str = $string;
+            const EXPECTED_NHPMWYD3NJA: &$crate::pmr::str =
+                $crate::map_ascii_case!(CASE_NHPMWYD3NJA, STR_NHPMWYD3NJA);
+
+            $crate::__assertc_common!{
+                __formatcp_if_impl
+                ($($parameters)*)
+                ($crate::is_case(CASE_NHPMWYD3NJA, STR_NHPMWYD3NJA))
+                (
+                    concat!(
+                        "\nassertion failed: string is not in `{case_NHPMWYD3NJA}` casing style\n",
+                        "  string: {string_NHPMWYD3NJA:?}\n\
+                         expected: {expected_NHPMWYD3NJA:?}",
+                        $("\n", $fmt_literal, "\n")?
+                    ),
+                    $($($fmt_arg,)*)?
+                    case_NHPMWYD3NJA = stringify!($case),
+                    string_NHPMWYD3NJA = STR_NHPMWYD3NJA,
+                    expected_NHPMWYD3NJA = EXPECTED_NHPMWYD3NJA
+                )
+            }
+        };
+    }
+}
+
+with_shared_docs! {
+    /// Compile-time assertion that a `&'static str` constant is in a casing style,
+    /// with formatting.
+    ///
+    ;clarification
+    /// This macro is equivalent to
+    /// `assertcp!(is_case(case, string), ...)`,
+    /// but shows the result of converting the string to the casing style
+    /// (with [`map_ascii_case`]) in the error message.
+    ///
+    /// The case argument is a [`Case`], and the string argument is a `&'static str`.
+    ///
+    /// [`map_ascii_case`]: ./macro.map_ascii_case.html
+    /// [`Case`]: ./enum.Case.html
+    ///
+    ;syntax
+    ;limitations
+    ///
+    /// # Examples
+    ///
+    /// ### Passing assertion
+    ///
+    /// ```rust
+    /// use const_format::{Case, assertcp_case};
+    ///
+    /// const NAME: &str = "json_exporter";
+    /// assertcp_case!(Case::Snake, NAME);
+    ///
+    /// const HEADER: &str = "Content-Type";
+    /// assertcp_case!(Case::Train, HEADER, "`HEADER` must be in Train-Case");
+    /// ```
+    ///
+    /// ### Failing assertion
+    ///
+    /// This example demonstrates a failing assertion,
+    /// and how the compiler error looks like as of 2026-10-18.
+    ///
+    /// ```compile_fail
+    /// use const_format::{Case, assertcp_case};
+    ///
+    /// const NAME: &str = "json_Exporter";
+    /// assertcp_case!(Case::Kebab, NAME, "plugin names are kebab-case");
+    /// ```
+    ///
+    /// This is the compiler output:
+    ///
+    /// ```text
+    /// error[E0080]: evaluation panicked:
+    ///               assertion failed: string is not in `Case::Kebab` casing style
+    ///                 string: "json_Exporter"
+    ///               expected: "json-exporter"
+    ///               plugin names are kebab-case
+    ///
+    ///  --> src/lib.rs:4:16
+    ///   |
+    /// 4 | assertcp_case!(Case::Kebab, NAME, "plugin names are kebab-case");
+    ///   |                ^^^^ evaluation of `_` failed here
+    /// ```
+    ///
+    #[cfg_attr(feature = "__docsrs", doc(cfg(feature = "assertcp")))]
+    #[macro_export]
+    macro_rules! assertcp_case {
+        ($($parameters:tt)*) => (
+            $crate::__assertcp_case_inner!{
+                ($($parameters)*)
+                ($($parameters)*)
+            }
+        );
+    }
+}
@@ -14,6 +14,8 @@
 ///
 /// - `i*`/`u*` (all the primitive integer types).
 ///
+/// - `f32`/`f64`
+///
 /// - `char`
 ///
 /// - `bool`
@@ -109,9 +111,8 @@ macro_rules! __concatcp_inner {
 /// - Use constants from scope as arguments: `formatcp!("{FOO}")`<br>
 /// equivalent to the [`format_args_implicits` RFC]
 ///
-/// - Use Debug-like formatting (eg: `formatcp!("{:?}", "hello" )`:<br>
-/// Similar to how `Debug` formatting in the standard library works,
-/// except that it does not escape unicode characters.
+/// - Use Debug formatting (eg: `formatcp!("{:?}", "hello" )`:<br>
+/// Formats `char`s and `&str`s the same as `Debug` formatting in the standard library.
 ///
 /// - Use LowerHex formatting (eg: `formatcp!("{:x}", "hello" )`):<br>
 /// Formats numbers as lowercase hexadecimal.
@@ -124,6 +125,12 @@ macro_rules! __concatcp_inner {
 /// - Use Binary formatting (eg: `formatcp!("{:b}", "hello" )`)<br>
 /// The alternate version (written as `"{:#b}"`) prefixes the number with `0b`
 ///
+/// - Use Octal formatting (eg: `formatcp!("{:o}", 0o755)`)<br>
+/// The alternate version (written as `"{:#o}"`) prefixes the number with `0o`
+///
+/// - Use LowerExp/UpperExp formatting (eg: `formatcp!("{:e}", 1500)`, `formatcp!("{:E}", 1.5)`)<br>
+/// Formats integers and floats in scientific notation, eg: `1.5e3`.
+///
 /// - Use Display formatting: `formatcp!("{}", "hello" )`
 ///
 ///
@@ -135,6 +142,8 @@ macro_rules! __concatcp_inner {
 ///
 /// - `i*`/`u*` (all the primitive integer types).
 ///
+/// - `f32`/`f64`
+///
 /// - `char`
 ///
 /// - `bool`
@@ -145,26 +154,32 @@ macro_rules! __concatcp_inner {
 ///
 /// # Formating behavior
 ///
-/// ### Debug-like
+/// ### Debug
 ///
-/// The `{:?}` formatter formats things similarly to how Debug does it.
+/// The `{:?}` formatter formats `&'static str` and `char`
+/// byte-for-byte the same as `Debug` formatting in the standard library.
 ///
 /// For `&'static str` it does these things:
 /// - Prepend and append the double quote character (`"`).
-/// - Escape the `'\t'`,`'\n'`,`'\r'`,`'\\'`, `'\''`, and`'\"'` characters.
-/// - Escape control characters with `\xYY`,
-/// where `YY` is the hexadecimal value of the control character.
+/// - Escape the `'\0'`, `'\t'`,`'\n'`,`'\r'`,`'\\'`, and`'\"'` characters with a backslash.
+/// - Escape control characters, non-printable characters, and grapheme extenders
+/// (eg: combining accents) with `\u{YY}`,
+/// where `YY` is the lowercase hexadecimal value of the character.
 ///
 /// Example:
 /// ```
 /// use const_format::formatcp;
 ///
-/// assert_eq!(formatcp!("{:?}", r#" \ " ó "#), r#"" \\ \" ó ""#);
+/// assert_eq!(formatcp!("{:?}", " \\ \" ó \x1F \u{301} "), r#"" \\ \" ó \u{1f} \u{301} ""#);
 /// ```
 ///
 /// For `char` it does these things:
 /// - Prepend and append the single quote character (`'`).
-/// - Uses the same escapes as `&'static str`.
+/// - Uses the same escapes as `&'static str`,
+/// except that it escapes `'\''` instead of `'\"'`.
+///
+/// Which characters are printable is determined by
+/// the Unicode tables of the standard library, currently for Unicode 17.0.0.
 ///
 /// ### Display
 ///
@@ -211,14 +226,52 @@ macro_rules! __concatcp_inner {
 /// {
 ///     const CHARS: &str = formatcp!("{0:?} - {0} - {1} - {1:?}", '"', '👀');
 ///    
-///     assert_eq!(CHARS, r#"'\"' - " - 👀 - '👀'"#);
+///     assert_eq!(CHARS, r#"'"' - " - 👀 - '👀'"#);
 /// }
 /// ```
 ///
-/// ### Additional specifiers
+/// ### Width, fill, and alignment
+///
+/// ```rust
+/// use const_format::formatcp;
+///
+/// const WIDTH: usize = 6;
+///
+/// const MSG: &str = formatcp!("[{:>5}|{:-^7}|{:<WIDTH$}|{:#06x}]", 13u8, "foo", 'c', 255u8);
+///
+/// assert_eq!(MSG, "[   13|--foo--|c     |0x00ff]");
+///
+/// ```
+///
+/// ### Precision
+///
+/// ```rust
+/// use const_format::formatcp;
 ///
-/// `const_format` macros don't support width, fill, alignment, sign,
-/// or precision specifiers.
+/// const NAME: &str = "a_long_identifier";
+/// const MAX_LEN: usize = 6;
+///
+/// const MSG: &str = formatcp!("[{:.MAX_LEN$}|{:.2}|{:8.3}]", NAME, 2.0f64 / 3.0, 0.5);
+///
+/// assert_eq!(MSG, "[a_long|0.67|   0.500]");
+///
+/// ```
+///
+/// ### Sign, octal, and exponential formatting
+///
+/// ```rust
+/// use const_format::formatcp;
+///
+/// const MODE: u32 = 0o644;
+///
+/// const MSG: &str = formatcp!(
+///     "[{:+}|{:+.1}|{:#o}|{:e}|{:.2E}]",
+///     3u8, 0.25, MODE, 1500u32, 12345u32,
+/// );
+///
+/// assert_eq!(MSG, "[+3|+0.2|0o644|1.5e3|1.23E4]");
+///
+/// ```
 ///
 /// [`format`]: https://doc.rust-lang.org/std/macro.format.html
 ///
@@ -242,6 +295,402 @@ macro_rules! formatcp {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// Concatenates constants of primitive types into a `&'static str`,
+/// using a buffer with a fixed capacity.
+///
+/// This takes the same arguments as [`concatcp`](crate::concatcp),
+/// preceded by the capacity of the buffer that the arguments are concatenated into,
+/// eg: `concatcp_cap!(64; T::NAME, "_id")`.
+///
+/// Unlike [`concatcp`](crate::concatcp), the arguments of this macro
+/// can use generic parameters, eg: associated constants of a type parameter.
+/// The capacity can't use generic parameters.
+///
+/// This macro can only be used in the initializer of a `const` or `static`,
+/// because the concatenated string is promoted to a `'static` constant.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if the concatenated string is longer than the capacity.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::concatcp_cap;
+///
+/// trait Named {
+///     const NAME: &'static str;
+/// }
+///
+/// struct Registry<T>(T);
+///
+/// impl<T: Named> Registry<T> {
+///     const ID: &'static str = concatcp_cap!(64; T::NAME, "_id");
+/// }
+///
+/// struct User;
+///
+/// impl Named for User {
+///     const NAME: &'static str = "user";
+/// }
+///
+/// assert_eq!(Registry::<User>::ID, "user_id");
+///
+/// ```
+///
+/// ### Exceeded capacity
+///
+/// ```compile_fail
+/// const ID: &str = const_format::concatcp_cap!(4; "foo", "bar");
+/// # let _ = ID;
+/// ```
+///
+#[macro_export]
+macro_rules! concatcp_cap {
+    ($capacity:expr; $($arg: expr),* $(,)?) => ({
+        use $crate::__cf_osRcTFl4A;
+        $crate::pmr::__concatcp_cap_impl!{
+            ($capacity), $( ( $arg ), )*
+        }
+    });
+}
+
+/// Formats constants of primitive types into a `&'static str`,
+/// using a buffer with a fixed capacity.
+///
+/// This takes the same arguments as [`formatcp`](crate::formatcp),
+/// preceded by the capacity of the buffer that the arguments are formatted into,
+/// eg: `formatcp_cap!(64; "{}_{:x}", T::NAME, T::ID)`.
+///
+/// Unlike [`formatcp`](crate::formatcp), the arguments of this macro
+/// can use generic parameters, eg: associated constants of a type parameter.
+/// The capacity can't use generic parameters.
+///
+/// This macro can only be used in the initializer of a `const` or `static`,
+/// because the formatted string is promoted to a `'static` constant.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if the formatted string is longer than the capacity.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::formatcp_cap;
+///
+/// trait Component {
+///     const NAME: &'static str;
+///     const ID: u32;
+/// }
+///
+/// struct Registry<T>(T);
+///
+/// impl<T: Component> Registry<T> {
+///     const KEY: &'static str = formatcp_cap!(64; "{}#{:04X}", T::NAME, T::ID);
+/// }
+///
+/// struct Position;
+///
+/// impl Component for Position {
+///     const NAME: &'static str = "position";
+///     const ID: u32 = 0xA2;
+/// }
+///
+/// assert_eq!(Registry::<Position>::KEY, "position#00A2");
+///
+/// ```
+///
+/// ### Exceeded capacity
+///
+/// ```compile_fail
+/// const ID: &str = const_format::formatcp_cap!(4; "{0}{0}", "foo");
+/// # let _ = ID;
+/// ```
+///
+#[macro_export]
+macro_rules! formatcp_cap {
+    ($capacity:expr; $format_string:expr $( $(, $expr:expr )+ )? $(,)? ) => ({
+        use $crate::__cf_osRcTFl4A;
+
+        $crate::pmr::__formatcp_cap_impl!(
+            ($capacity)
+            ($format_string)
+            $(, $($expr,)+)?
+        )
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenates constants of primitive types into a `&'static [u8; N]`.
+///
+/// This is the byte string counterpart of [`concatcp`](crate::concatcp),
+/// the returned reference coerces to `&'static [u8]`,
+/// and can be dereferenced to get a `[u8; N]`.
+///
+/// [For **examples** look here](#examples)
+///
+/// # Limitations
+///
+/// This macro can only take constants of these types as inputs:
+///
+/// - `&[u8]`/`&[u8; N]` (eg: byte string literals): which are copied as is.
+///
+/// - `u8`: which is written as the byte itself,
+/// unsuffixed integer literals are `u8` by default.
+///
+/// - All the types that [`concatcp`](crate::concatcp) takes,
+/// which are written the same way as in that macro.
+///
+/// This macro also shares
+/// [the limitations described in here](./index.html#macro-limitations)
+/// as well.
+///
+/// # Examples
+///
+/// ### Literal arguments
+///
+/// ```rust
+/// use const_format::concatbytes;
+///
+/// const BYTES: &[u8] = concatbytes!(b"foo", 0, '\n', "bar", 0xFF);
+///
+/// assert_eq!(BYTES, b"foo\0\nbar\xFF");
+///
+/// ```
+///
+/// ### Sized array
+///
+/// ```rust
+/// use const_format::concatbytes;
+///
+/// const MAGIC: &[u8] = b"\x7FELF";
+///
+/// const HEADER: [u8; 8] = *concatbytes!(MAGIC, 2u8, b"\x01", 10u16);
+///
+/// assert_eq!(HEADER, *b"\x7FELF\x02\x0110");
+///
+/// ```
+///
+#[macro_export]
+macro_rules! concatbytes {
+    ()=>{ &[0u8; 0] };
+    ($($arg: expr),* $(,)?)=>({
+        use $crate::__cf_osRcTFl4A;
+        $crate::pmr::__concatbytes_impl!{
+            $( ( $arg ), )*
+        }
+    });
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concatbytes_inner {
+    ($variables:expr) => {{
+        #[doc(hidden)]
+        const ARR_LEN: usize = $crate::pmr::PArgument::calc_len($variables);
+
+        #[doc(hidden)]
+        const CONCAT_ARR: &$crate::pmr::LenAndArray<[u8; ARR_LEN]> =
+            &$crate::pmr::__priv_concatenate($variables);
+
+        #[doc(hidden)]
+        #[allow(clippy::transmute_ptr_to_ptr)]
+        const CONCAT_BYTES: &[u8; CONCAT_ARR.len] = unsafe {
+            // This transmute truncates the length of the array to the amound of written bytes.
+            $crate::pmr::transmute::<&[u8; ARR_LEN], &[u8; CONCAT_ARR.len]>(&CONCAT_ARR.array)
+        };
+        CONCAT_BYTES
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Formats constants of primitive types into a `&'static [u8; N]`.
+///
+/// This is the byte string counterpart of [`formatcp`](crate::formatcp),
+/// the returned reference coerces to `&'static [u8]`,
+/// and can be dereferenced to get a `[u8; N]`.
+///
+/// [For **examples** look here](#examples)
+///
+/// # Syntax
+///
+/// This macro uses the same syntax as [`formatcp`](crate::formatcp).
+///
+/// # Limitations
+///
+/// This macro can only take constants of these types as inputs:
+///
+/// - `&[u8]`/`&[u8; N]` (eg: byte string literals)
+///
+/// - `u8`: unsuffixed integer literals are `u8` by default.
+///
+/// - All the types that [`formatcp`](crate::formatcp) takes.
+///
+/// This macro also shares
+/// [the limitations described in here](./index.html#macro-limitations)
+/// as well.
+///
+/// # Formating behavior
+///
+/// The `{}`/`{:}` formatter copies `&[u8]` arguments as is,
+/// and writes `u8` arguments as the byte itself.
+/// The width, fill, and alignment of `{}` count bytes for these types,
+/// and the precision truncates `&[u8]` arguments to that many bytes.
+///
+/// All the other formatters, and all the other types,
+/// are formatted the same way as in [`formatcp`](crate::formatcp),
+/// eg: `formatbytes!("{:?}", 10u8)` writes the `10` text, not the byte.
+///
+/// Byte slices can only be formatted with the `{}` formatter.
+///
+/// # Examples
+///
+/// ### Bytes and text
+///
+/// ```rust
+/// use const_format::formatbytes;
+///
+/// const KEY: &[u8] = b"\xDE\xAD";
+///
+/// const MSG: &[u8] = formatbytes!("{KEY}|{0}|{0:?}|{0:#x}|{1:-<4}", 0xBEu8, b"a");
+///
+/// assert_eq!(MSG, b"\xDE\xAD|\xBE|190|0xbe|a---");
+///
+/// ```
+///
+/// ### Sized array
+///
+/// ```rust
+/// use const_format::formatbytes;
+///
+/// const PACKET: [u8; 6] = *formatbytes!("{:.3}{}{:>2}", b"abcdef", 0, b"z");
+///
+/// assert_eq!(PACKET, *b"abc\0 z");
+///
+/// ```
+///
+#[macro_export]
+macro_rules! formatbytes {
+    ($format_string:expr $( $(, $expr:expr )+ )? $(,)? ) => ({
+        use $crate::__cf_osRcTFl4A;
+
+        $crate::pmr::__formatbytes_impl!(
+            ($format_string)
+            $(, $($expr,)+)?
+        )
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenates constants of primitive types into a nul-terminated `&'static CStr`.
+///
+/// This takes the same arguments as [`concatcp`](crate::concatcp),
+/// appending a nul byte to the concatenated string.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if any argument contains a nul byte.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::concatcp_cstr;
+///
+/// use core::ffi::CStr;
+///
+/// const PREFIX: &str = "libfoo";
+///
+/// const NAME: &CStr = concatcp_cstr!(PREFIX, "_init_v", 3u8);
+///
+/// assert_eq!(NAME.to_bytes_with_nul(), b"libfoo_init_v3\0");
+///
+/// ```
+///
+/// ### Interior nul
+///
+/// ```compile_fail
+/// const NAME: &core::ffi::CStr = const_format::concatcp_cstr!("foo\0", "bar");
+/// ```
+///
+#[macro_export]
+#[cfg(feature = "rust_1_64")]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_64")))]
+macro_rules! concatcp_cstr {
+    ($($arg: expr),* $(,)?) => (
+        $crate::__str_to_cstr!($crate::concatcp!($($arg),*))
+    );
+}
+
+/// Formats constants of primitive types into a nul-terminated `&'static CStr`.
+///
+/// This takes the same arguments as [`formatcp`](crate::formatcp),
+/// appending a nul byte to the formatted string.
+///
+/// # Compile-time errors
+///
+/// This causes a compile-time error if any argument contains a nul byte.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::formatcp_cstr;
+///
+/// use core::ffi::CStr;
+///
+/// const MODULE: &str = "net";
+///
+/// const NAME: &CStr = formatcp_cstr!("{MODULE}_{:<5}_{:02}", "recv", 7u8);
+///
+/// assert_eq!(NAME.to_bytes_with_nul(), b"net_recv _07\0");
+///
+/// ```
+///
+/// ### Interior nul
+///
+/// ```compile_fail
+/// const NAME: &core::ffi::CStr = const_format::formatcp_cstr!("{}-{}", 'a', '\0');
+/// ```
+///
+#[macro_export]
+#[cfg(feature = "rust_1_64")]
+#[cfg_attr(feature = "__docsrs", doc(cfg(feature = "rust_1_64")))]
+macro_rules! formatcp_cstr {
+    ($format_string:expr $( $(, $expr:expr )+ )? $(,)? ) => (
+        $crate::__str_to_cstr!($crate::formatcp!($format_string $(, $($expr),+)?))
+    );
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(feature = "rust_1_64")]
+macro_rules! __str_to_cstr {
+    ($string:expr) => {{
+        // The suffix is to avoid name collisions with identifiers in the passed-in expression.
+        #[doc(hidden)]
+        const STR_OSRCTFL4A: &$crate::pmr::str = $string;
+
+        {
+            #[doc(hidden)]
+            const ARR_LEN: usize = STR_OSRCTFL4A.len() + 1;
+
+            #[doc(hidden)]
+            const ARR: &[u8; ARR_LEN] = &$crate::pmr::__priv_to_cstr_bytes(STR_OSRCTFL4A);
+
+            #[doc(hidden)]
+            const CSTR: &$crate::pmr::CStr = unsafe {
+                // `__priv_to_cstr_bytes` ensures that the only nul byte is the last one.
+                $crate::pmr::CStr::from_bytes_with_nul_unchecked(ARR)
+            };
+            CSTR
+        }
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 /// Concatenates constants of standard library and/or user-defined types into a `&'static str`.
 ///
 /// User defined types must implement the [`FormatMarker`] trait and
@@ -66,6 +66,21 @@ macro_rules! iter_copy_slice{
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __write_pvariant {
+    (fill, $fill:ident, $repeated:expr => $out:ident) => {{
+        let encoded = $fill.encoded();
+        let len = $fill.len();
+
+        let mut repeated = $repeated;
+        while repeated != 0 {
+            let mut start = 0;
+            while start < len {
+                $out.array[$out.len] = encoded[start];
+                $out.len += 1;
+                start += 1;
+            }
+            repeated -= 1;
+        }
+    }};
     (char, $parg:expr, $elem:ident => $out:ident) => {{
         let encoded = $elem.encoded();
         let len = $elem.len();
@@ -77,41 +92,116 @@ macro_rules! __write_pvariant {
             start += 1;
         }
     }};
+    (bytes, $parg:expr, $elem:ident => $out:ident) => {{
+        let mut i = 0;
+        while i < $elem.len() {
+            $out.array[$out.len] = $elem[i];
+            $out.len += 1;
+            i += 1;
+        }
+    }};
+    (byte, $parg:expr, $elem:ident => $out:ident) => {{
+        $out.array[$out.len] = $elem;
+        $out.len += 1;
+    }};
     (int, $parg:expr, $elem:ident => $out:ident) => {{
         let wrapper = $crate::pmr::PWrapper($elem);
 
         let debug_display;
         let bin;
         let hex;
+        let oct;
+
+        // the length of the sign or `0x`/`0o`/`0b` prefix,
+        // which goes before the zeros from sign-aware zero padding.
+        let prefix_len;
+
+        // whether a `+` is written before the number
+        let plus_sign;
 
         let sa: &$crate::pmr::StartAndArray<[_]> = match $parg.fmt {
             $crate::pmr::Formatting::Display => {
+                prefix_len = $elem.is_negative as usize;
+                plus_sign = $parg.fmt_flags.sign_plus() && !$elem.is_negative;
                 debug_display = wrapper.to_start_array_display();
                 &debug_display
             }
             $crate::pmr::Formatting::Debug => match $parg.fmt_flags.num_fmt() {
-                $crate::pmr::NumberFormatting::Decimal => {
+                // integers formatted as exponentials are converted to floats
+                // before getting here.
+                $crate::pmr::NumberFormatting::Decimal
+                | $crate::pmr::NumberFormatting::Exponential => {
+                    prefix_len = $elem.is_negative as usize;
+                    plus_sign = $parg.fmt_flags.sign_plus() && !$elem.is_negative;
                     debug_display = wrapper.to_start_array_debug();
                     &debug_display
                 }
                 $crate::pmr::NumberFormatting::Binary => {
+                    prefix_len = ($parg.fmt_flags.is_alternate() as usize) * 2;
+                    plus_sign = $parg.fmt_flags.sign_plus();
                     bin = wrapper.to_start_array_binary($parg.fmt_flags);
                     &bin
                 }
                 $crate::pmr::NumberFormatting::Hexadecimal => {
+                    prefix_len = ($parg.fmt_flags.is_alternate() as usize) * 2;
+                    plus_sign = $parg.fmt_flags.sign_plus();
                     hex = wrapper.to_start_array_hexadecimal($parg.fmt_flags);
                     &hex
                 }
+                $crate::pmr::NumberFormatting::Octal => {
+                    prefix_len = ($parg.fmt_flags.is_alternate() as usize) * 2;
+                    plus_sign = $parg.fmt_flags.sign_plus();
+                    oct = wrapper.to_start_array_octal($parg.fmt_flags);
+                    &oct
+                }
             },
         };
 
+        if plus_sign {
+            $out.array[$out.len] = b'+';
+            $out.len += 1;
+        }
+
         let mut start = sa.start;
+        while start < sa.start + prefix_len {
+            $out.array[$out.len] = sa.array[start];
+            $out.len += 1;
+            start += 1;
+        }
+
+        let mut zeros = $parg.padding.zeros;
+        while zeros != 0 {
+            $out.array[$out.len] = b'0';
+            $out.len += 1;
+            zeros -= 1;
+        }
+
         while start < sa.array.len() {
             $out.array[$out.len] = sa.array[start];
             $out.len += 1;
             start += 1;
         }
     }};
+    (float, $parg:expr, $elem:ident => $out:ident) => {{
+        let len = $elem.len();
+        let sign_len = $elem.sign_len();
+
+        let mut i = 0;
+        while i < len {
+            if i == sign_len {
+                let mut zeros = $parg.padding.zeros;
+                while zeros != 0 {
+                    $out.array[$out.len] = b'0';
+                    $out.len += 1;
+                    zeros -= 1;
+                }
+            }
+
+            $out.array[$out.len] = $elem.byte_at(i);
+            $out.len += 1;
+            i += 1;
+        }
+    }};
     (str, $parg:expr, $elem:ident => $out:ident) => {{
         let str = $elem.as_bytes();
         let is_display = $parg.fmt.is_display();
@@ -126,30 +216,21 @@ macro_rules! __write_pvariant {
             $out.array[$out.len] = b'"';
             $out.len += 1;
             while i < str.len() {
-                use $crate::pmr::{hex_as_ascii, ForEscaping, FOR_ESCAPING};
-
                 let c = str[i];
-                let mut written_c = c;
-                if c < 128 {
-                    let shifted = 1 << c;
-
-                    if (FOR_ESCAPING.is_escaped & shifted) != 0 {
-                        $out.array[$out.len] = b'\\';
+                if c < 128 && ($crate::pmr::FOR_ESCAPING.is_escaped & (1 << c)) == 0 {
+                    $out.array[$out.len] = c;
+                    $out.len += 1;
+                    i += 1;
+                } else {
+                    let escaped = $crate::pmr::str_char_debug(str, i);
+                    let mut j = 0;
+                    while j < escaped.len {
+                        $out.array[$out.len] = escaped.encoded[j];
                         $out.len += 1;
-                        if (FOR_ESCAPING.is_backslash_escaped & shifted) == 0 {
-                            $out.array[$out.len] = b'x';
-                            $out.array[$out.len + 1] =
-                                hex_as_ascii(c >> 4, $crate::pmr::HexFormatting::Upper);
-                            $out.len += 2;
-                            written_c = hex_as_ascii(c & 0b1111, $crate::pmr::HexFormatting::Upper);
-                        } else {
-                            written_c = ForEscaping::get_backslash_escape(c);
-                        };
+                        j += 1;
                     }
+                    i += escaped.char_len;
                 }
-                $out.array[$out.len] = written_c;
-                $out.len += 1;
-                i += 1;
             }
             $out.array[$out.len] = b'"';
             $out.len += 1;
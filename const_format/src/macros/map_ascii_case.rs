@@ -10,24 +10,23 @@
 ///
 /// The [`Case`](enum.Case.html) parameter determines the casing style of the returned string.
 ///
+/// The optional [`CaseOptions`](struct.CaseOptions.html) parameter
+/// (eg: `map_ascii_case!(case, input, options)`)
+/// determines how the string is split into words, defaulting to `CaseOptions::NEW`.
+///
 /// # Ascii
 ///
-/// This only transforms ascii characters because broader unicode case conversion,
-/// while possible, is much harder to implement purely with `const fn`s.
+/// This only transforms ascii characters,
+/// the [`map_case`] macro does unicode case conversion.
 ///
 /// Non-ascii characters are treated as though they're alphabetic ascii characters.
 ///
 /// # Ignored characters
 ///
-/// These casing styles treat non-alphanumeric ascii characters as spaces,
-/// removing them from the returned string:
-///
-/// - `Case::Pascal`
-/// - `Case::Camel`
-/// - `Case::Snake`
-/// - `Case::UpperSnake`
-/// - `Case::Kebab`
-/// - `Case::UpperKebab`
+/// All casing styles other than `Case::Lower` and `Case::Upper`
+/// treat non-alphanumeric ascii characters as spaces,
+/// removing them from the returned string,
+/// unless they're kept with [`CaseOptions::set_kept_punctuation`].
 ///
 /// # Example
 ///
@@ -70,21 +69,172 @@
 ///     const OUT: &str = map_ascii_case!(Case::UpperKebab, IN2);
 ///     assert_eq!(OUT, "HELLO-FOOкаждому-100-BAR-QUX");
 /// }
+/// {
+///     const OUT: &str = map_ascii_case!(Case::Title, IN2);
+///     assert_eq!(OUT, "Hello Fooкаждому 100 Bar Qux");
+/// }
+/// {
+///     const OUT: &str = map_ascii_case!(Case::Train, IN2);
+///     assert_eq!(OUT, "Hello-Fooкаждому-100-Bar-Qux");
+/// }
+/// {
+///     const OUT: &str = map_ascii_case!(Case::Dot, IN2);
+///     assert_eq!(OUT, "hello.fooкаждому.100.bar.qux");
+/// }
+/// {
+///     const OUT: &str = map_ascii_case!(Case::Flat, IN2);
+///     assert_eq!(OUT, "hellofooкаждому100barqux");
+/// }
+/// {
+///     const OUT: &str = map_ascii_case!(Case::Path, IN2);
+///     assert_eq!(OUT, "hello/fooкаждому/100/bar/qux");
+/// }
 ///
 ///
 /// ```
+///
+/// [`map_case`]: ./macro.map_case.html
+/// [`CaseOptions::set_kept_punctuation`]:
+/// ./struct.CaseOptions.html#method.set_kept_punctuation
 #[macro_export]
 macro_rules! map_ascii_case {
     ($case:expr, $str:expr) => {
+        $crate::map_ascii_case!($case, $str, $crate::CaseOptions::NEW)
+    };
+    ($case:expr, $str:expr, $options:expr) => {
+        $crate::__str_const! {{
+            const S_OSRCTFL4A: &$crate::pmr::str = $str;
+            const CASE_OSRCTFL4A: $crate::Case = $case;
+            const OPT_OSRCTFL4A: $crate::CaseOptions = $options;
+            {
+                const L: $crate::pmr::usize = $crate::__ascii_case_conv::size_after_conversion(
+                    CASE_OSRCTFL4A,
+                    OPT_OSRCTFL4A,
+                    S_OSRCTFL4A,
+                );
+
+                const OB: &[$crate::pmr::u8; L] = &$crate::__ascii_case_conv::convert_str::<L>(
+                    CASE_OSRCTFL4A,
+                    OPT_OSRCTFL4A,
+                    S_OSRCTFL4A,
+                );
+
+                const OS: &$crate::pmr::str = unsafe { $crate::__priv_transmute_bytes_to_str!(OB) };
+
+                OS
+            }
+        }}
+    };
+}
+
+/// Converts the casing style of a `&'static str` constant,
+/// with unicode case conversion.
+///
+/// This macro is equivalent to a function with this signature:
+///
+/// ```rust
+/// const fn map_case(case: const_format::Case, input: &'static str) -> &'static str
+/// # {""}
+/// ```
+///
+/// The [`Case`](enum.Case.html) parameter determines the casing style of the returned string.
+///
+/// The optional [`CaseOptions`](struct.CaseOptions.html) parameter
+/// (eg: `map_case!(case, input, options)`)
+/// determines how the string is split into words, defaulting to `CaseOptions::NEW`.
+///
+/// # Unicode
+///
+/// This uses the same case conversion as the [`str::to_uppercase`] and
+/// [`str::to_lowercase`] methods (with Unicode 17.0.0),
+/// including conversions to multiple chars, eg: `'ß'` is uppercased to `"SS"`,
+/// and the rule for lowercasing `'Σ'` to `'ς'` at the end of words.
+///
+/// In the casing styles that capitalize words (eg: `Case::Pascal` and `Case::Title`),
+/// the first char of each word is uppercased, not titlecased.
+///
+/// # Words
+///
+/// The casing styles that split the string into words
+/// treat unicode letters and numbers as word characters,
+/// removing every other character from the returned string
+/// (except for the ascii punctuation kept with `CaseOptions::set_kept_punctuation`).
+/// Letters that don't have case (eg: CJK characters) continue the current word.
+///
+/// This is unlike [`map_ascii_case`],
+/// which treats all non-ascii characters as alphabetic.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{Case, map_case};
+///
+/// {
+///     const OUT: &str = map_case!(Case::Upper, "Straße γειά σου Привет");
+///     assert_eq!(OUT, "STRASSE ΓΕΙΆ ΣΟΥ ПРИВЕТ");
+/// }
+/// {
+///     const OUT: &str = map_case!(Case::Lower, "ΟΔΟΣ ΣΚΙΑΣ");
+///     assert_eq!(OUT, "οδος σκιας");
+/// }
+///
+/// const IN: &str = "größe—ÜBER_маленький100Wert";
+/// {
+///     const OUT: &str = map_case!(Case::Pascal, IN);
+///     assert_eq!(OUT, "GrößeÜberМаленький100Wert");
+/// }
+/// {
+///     const OUT: &str = map_case!(Case::Camel, IN);
+///     assert_eq!(OUT, "größeÜberМаленький100Wert");
+/// }
+/// {
+///     const OUT: &str = map_case!(Case::Snake, IN);
+///     assert_eq!(OUT, "größe_über_маленький_100_wert");
+/// }
+/// {
+///     const OUT: &str = map_case!(Case::UpperSnake, IN);
+///     assert_eq!(OUT, "GRÖSSE_ÜBER_МАЛЕНЬКИЙ_100_WERT");
+/// }
+/// {
+///     const OUT: &str = map_case!(Case::Kebab, IN);
+///     assert_eq!(OUT, "größe-über-маленький-100-wert");
+/// }
+/// {
+///     const OUT: &str = map_case!(Case::UpperKebab, IN);
+///     assert_eq!(OUT, "GRÖSSE-ÜBER-МАЛЕНЬКИЙ-100-WERT");
+/// }
+/// {
+///     const OUT: &str = map_case!(Case::Title, IN);
+///     assert_eq!(OUT, "Größe Über Маленький 100 Wert");
+/// }
+///
+/// ```
+///
+/// [`map_ascii_case`]: ./macro.map_ascii_case.html
+/// [`str::to_uppercase`]: https://doc.rust-lang.org/std/primitive.str.html#method.to_uppercase
+/// [`str::to_lowercase`]: https://doc.rust-lang.org/std/primitive.str.html#method.to_lowercase
+#[macro_export]
+macro_rules! map_case {
+    ($case:expr, $str:expr) => {
+        $crate::map_case!($case, $str, $crate::CaseOptions::NEW)
+    };
+    ($case:expr, $str:expr, $options:expr) => {
         $crate::__str_const! {{
             const S_OSRCTFL4A: &$crate::pmr::str = $str;
             const CASE_OSRCTFL4A: $crate::Case = $case;
+            const OPT_OSRCTFL4A: $crate::CaseOptions = $options;
             {
-                const L: $crate::pmr::usize =
-                    $crate::__ascii_case_conv::size_after_conversion(CASE_OSRCTFL4A, S_OSRCTFL4A);
+                const L: $crate::pmr::usize = $crate::__ascii_case_conv::size_after_unicode_conversion(
+                    CASE_OSRCTFL4A,
+                    OPT_OSRCTFL4A,
+                    S_OSRCTFL4A,
+                );
 
-                const OB: &[$crate::pmr::u8; L] =
-                    &$crate::__ascii_case_conv::convert_str::<L>(CASE_OSRCTFL4A, S_OSRCTFL4A);
+                const OB: &[$crate::pmr::u8; L] = &$crate::__ascii_case_conv::convert_str_unicode::<L>(
+                    CASE_OSRCTFL4A,
+                    OPT_OSRCTFL4A,
+                    S_OSRCTFL4A,
+                );
 
                 const OS: &$crate::pmr::str = unsafe { $crate::__priv_transmute_bytes_to_str!(OB) };
 
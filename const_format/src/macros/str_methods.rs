@@ -82,6 +82,678 @@ macro_rules! str_replace {
     };
 }
 
+/// Escapes `$input` (a `&'static str` constant)
+/// for use in JSON, C, HTML, shell, or URL text.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # use const_format::Escape;
+/// fn str_escape(escape: Escape, input: &'static str) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// The [`Escape`] argument determines how the string is escaped,
+/// the returned string doesn't have quotes around it.
+///
+/// For escaping strings at runtime, there are the `write_str_escaped` methods of
+/// [`Formatter`] and [`StrWriterMut`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{Escape, concatcp, str_escape};
+///
+/// const NAME: &str = "say \"hi\"\n";
+///
+/// const JSON: &str = concatcp!(r#"{"name":""#, str_escape!(Escape::Json, NAME), r#""}"#);
+/// assert_eq!(JSON, r#"{"name":"say \"hi\"\n"}"#);
+///
+/// const C_HEADER: &str = concatcp!("#define NAME \"", str_escape!(Escape::C, NAME), "\"");
+/// assert_eq!(C_HEADER, r#"#define NAME "say \"hi\"\n""#);
+///
+/// assert_eq!(str_escape!(Escape::Html, "a<b && c"), "a&lt;b &amp;&amp; c");
+///
+/// assert_eq!(str_escape!(Escape::Shell, "it's"), r"it'\''s");
+///
+/// const QUERY: &str = concatcp!("/search?q=", str_escape!(Escape::Percent, "ñ & b/c"));
+/// assert_eq!(QUERY, "/search?q=%C3%B1%20%26%20b%2Fc");
+///
+/// ```
+///
+/// [`Escape`]: ./enum.Escape.html
+/// [`Formatter`]: ./fmt/struct.Formatter.html#method.write_str_escaped
+/// [`StrWriterMut`]: ./fmt/struct.StrWriterMut.html#method.write_str_escaped
+#[macro_export]
+macro_rules! str_escape {
+    ($escape:expr, $input:expr $(,)*) => {
+        $crate::__str_const! {{
+            const ARGS_OSRCTFL4A: $crate::__str_methods::EscapeInput =
+                $crate::__str_methods::EscapeInput {
+                    escape: $escape,
+                    str: $input,
+                };
+
+            {
+                const OB: &[$crate::pmr::u8; ARGS_OSRCTFL4A.escaped_length()] =
+                    &ARGS_OSRCTFL4A.escape();
+
+                const OS: &$crate::pmr::str = unsafe { $crate::__priv_transmute_bytes_to_str!(OB) };
+
+                OS
+            }
+        }}
+    };
+}
+
+/// Encodes `$input` (a `&'static [u8]`, `&'static [u8; N]`, or `&'static str` constant)
+/// as lowercase hexadecimal.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Bytes {}
+/// fn hex_encode(input: impl Bytes) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::hex_encode;
+///
+/// const FINGERPRINT: &[u8; 4] = &[0xDE, 0xAD, 0xBE, 0xEF];
+///
+/// assert_eq!(hex_encode!(FINGERPRINT), "deadbeef");
+/// assert_eq!(hex_encode!("hi!"), "686921");
+/// assert_eq!(hex_encode!(b""), "");
+///
+/// ```
+///
+/// This can also encode the bytes of a file, by passing
+/// `include_bytes!("path/to/file")` as the argument.
+#[macro_export]
+macro_rules! hex_encode {
+    ($input:expr $(,)*) => {
+        $crate::__encode_bytes!(Hex, $input)
+    };
+}
+
+/// Encodes `$input` (a `&'static [u8]`, `&'static [u8; N]`, or `&'static str` constant)
+/// as base64, with the standard alphabet and padding.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Bytes {}
+/// fn base64_encode(input: impl Bytes) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::base64_encode;
+///
+/// const KEY: &[u8] = &[0xFB, 0xFF, 0x00, 0x10];
+///
+/// assert_eq!(base64_encode!(KEY), "+/8AEA==");
+/// assert_eq!(base64_encode!("hello"), "aGVsbG8=");
+/// assert_eq!(base64_encode!(b"hello!"), "aGVsbG8h");
+///
+/// ```
+#[macro_export]
+macro_rules! base64_encode {
+    ($input:expr $(,)*) => {
+        $crate::__encode_bytes!(Base64, $input)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __encode_bytes {
+    ($encoding:ident, $input:expr) => {
+        $crate::__str_const! {{
+            const ARGS_OSRCTFL4A: $crate::__str_methods::EncodeInput =
+                $crate::__str_methods::EncodeInput {
+                    encoding: $crate::__str_methods::Encoding::$encoding,
+                    bytes: $crate::__str_methods::EncodingInputConv($input).conv(),
+                };
+
+            {
+                const OB: &[$crate::pmr::u8; ARGS_OSRCTFL4A.encoded_length()] =
+                    &ARGS_OSRCTFL4A.encode();
+
+                const OS: &$crate::pmr::str = unsafe { $crate::__priv_transmute_bytes_to_str!(OB) };
+
+                OS
+            }
+        }}
+    };
+}
+
+/// Decodes `$input` (a `&'static str`, `&'static [u8]`, or `&'static [u8; N]` constant)
+/// from hexadecimal into a `&'static [u8]`.
+///
+/// Both uppercase and lowercase hex digits are accepted.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Bytes {}
+/// fn hex_decode(input: impl Bytes) -> &'static [u8]
+/// # {&[]}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::hex_decode;
+///
+/// const FIRMWARE_ID: &str = "00C0FFee";
+///
+/// assert_eq!(hex_decode!(FIRMWARE_ID), [0x00, 0xC0, 0xFF, 0xEE]);
+/// assert_eq!(hex_decode!(b"686921"), b"hi!");
+///
+/// ```
+///
+/// ### Invalid input
+///
+/// Input that isn't valid hexadecimal causes a compile-time error,
+/// which includes the byte offset of the invalid digit
+/// (or the length of the input, if it has an odd length).
+///
+/// ```compile_fail
+/// const_format::hex_decode!("12 34");
+/// ```
+///
+/// ```compile_fail
+/// const_format::hex_decode!("123");
+/// ```
+///
+/// The error for the `"12 34"` input looks like this:
+/// ```text
+/// error[E0080]: index out of bounds: the length is 0 but the index is 2
+///  --> src/lib.rs:1:27
+///   |
+/// 1 | const_format::hex_decode!("12 34");
+///   |                           ^^^^^^^ evaluation of `_ASSERT_VALID_INPUT` failed inside this call
+///   |
+/// note: inside `const_format::__str_methods::DecodeValidity::assert_valid`
+///   |
+///   |             Self::InvalidHexDigit(offset) => [/* invalid hex digit at this byte offset */][offset],
+///   |                                              ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the failure occurred here
+/// ```
+///
+#[macro_export]
+macro_rules! hex_decode {
+    ($input:expr $(,)*) => {
+        $crate::__decode_bytes!(Hex, $input)
+    };
+}
+
+/// Decodes `$input` (a `&'static str`, `&'static [u8]`, or `&'static [u8; N]` constant)
+/// from base64 into a `&'static [u8]`.
+///
+/// This uses the standard alphabet, and accepts input both with and without padding.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Bytes {}
+/// fn base64_decode(input: impl Bytes) -> &'static [u8]
+/// # {&[]}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::base64_decode;
+///
+/// const KEY: &str = "+/8AEA==";
+///
+/// assert_eq!(base64_decode!(KEY), [0xFB, 0xFF, 0x00, 0x10]);
+/// assert_eq!(base64_decode!("aGVsbG8"), b"hello");
+/// assert_eq!(base64_decode!(b"aGVsbG8h"), b"hello!");
+///
+/// ```
+///
+/// ### Invalid input
+///
+/// Input that isn't valid base64 causes a compile-time error,
+/// which includes the byte offset of the invalid character
+/// (or the length of the input, if it has an invalid length).
+///
+/// ```compile_fail
+/// const_format::base64_decode!("aGV-bG8=");
+/// ```
+///
+/// ```compile_fail
+/// const_format::base64_decode!("aGVsbG8=aGVsbG8=");
+/// ```
+///
+/// ```compile_fail
+/// const_format::base64_decode!("aGVsb");
+/// ```
+///
+#[macro_export]
+macro_rules! base64_decode {
+    ($input:expr $(,)*) => {
+        $crate::__decode_bytes!(Base64, $input)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __decode_bytes {
+    ($encoding:ident, $input:expr) => {
+        $crate::__const! {&'static [$crate::pmr::u8] => {
+            const ARGS_OSRCTFL4A: $crate::__str_methods::DecodeArgs =
+                $crate::__str_methods::DecodeArgs(
+                    $crate::__str_methods::Encoding::$encoding,
+                    $crate::__str_methods::EncodingInputConv($input).conv(),
+                );
+
+            {
+                $crate::pmr::respan_to! {
+                    ($input)
+                    const _ASSERT_VALID_INPUT: () = ARGS_OSRCTFL4A.assert_valid();
+                }
+
+                const OB: &[$crate::pmr::u8; ARGS_OSRCTFL4A.decoded_len] =
+                    &ARGS_OSRCTFL4A.decode();
+
+                OB
+            }
+        }}
+    };
+}
+
+/// Parses a `&'static str` constant into an integer or a `bool`.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Parse {}
+/// fn str_parse<T: Parse>(input: &'static str, radix: Option<u32>) -> T
+/// # {loop{}}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `T` can be any of the primitive integer types, or `bool`.
+///
+/// The type is passed as the first argument (eg: `str_parse!(u16, "100")`),
+/// and the optional radix is passed as `radix = <expression>`
+/// (eg: `str_parse!(u16, "ff", radix = 16)`).
+/// The radix can't be passed when parsing a `bool`.
+///
+/// # Integer syntax
+///
+/// Integers are parsed with this syntax:
+///
+/// - An optional `-` or `+` sign, `-` is only allowed for signed integers.
+///
+/// - An optional `0x`/`0o`/`0b` prefix, which sets the radix to 16/8/2 respectively.
+///   If a radix argument is passed, only the prefix for that radix is accepted.
+///
+/// - One or more digits of the radix, which is 10 if there's no prefix or radix argument.
+///   Letters of both cases are accepted as digits.
+///
+/// - `_` separators can go anywhere after the first digit,
+///   so they can't go right after the sign or prefix.
+///
+/// Whitespace is not allowed, you can remove it with [`str_trim`] beforehand.
+///
+/// `bool`s are parsed from exactly `"true"` or `"false"`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_parse;
+///
+/// const MAX_CONN: &str = "1_024"; // eg: `env!("MAX_CONN")`
+///
+/// const MAX_CONN_N: u32 = str_parse!(u32, MAX_CONN);
+/// assert_eq!(MAX_CONN_N, 1024);
+///
+/// assert_eq!(str_parse!(i64, "-0x7FFF_FFFF"), -0x7FFF_FFFF);
+/// assert_eq!(str_parse!(i64, "ff", radix = 16), 255);
+/// assert_eq!(str_parse!(u8, "0b1010_1010"), 0b1010_1010);
+/// assert_eq!(str_parse!(u8, "0o17"), 0o17);
+/// assert_eq!(str_parse!(i8, "-128"), i8::MIN);
+/// assert_eq!(str_parse!(u128, "z", radix = 36), 35);
+///
+/// assert_eq!(str_parse!(bool, "true"), true);
+/// assert_eq!(str_parse!(bool, "false"), false);
+///
+/// ```
+///
+/// ### Invalid input
+///
+/// Input that can't be parsed, or doesn't fit in the type,
+/// causes a compile-time error that includes the byte offset of the problem.
+///
+/// ```compile_fail
+/// const_format::str_parse!(u32, "12a4");
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_parse!(u8, "256");
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_parse!(u8, "-1");
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_parse!(u8, "0x_f");
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_parse!(bool, "True");
+/// ```
+///
+/// The error for the `"12a4"` input looks like this:
+/// ```text
+/// error[E0080]: index out of bounds: the length is 0 but the index is 2
+///  --> src/lib.rs:1:31
+///   |
+/// 1 | const_format::str_parse!(u32, "12a4");
+///   |                               ^^^^^^ evaluation of `_ASSERT_VALID_INPUT` failed inside this call
+///   |
+/// note: inside `const_format::__str_methods::ParseValidity::assert_valid`
+///   |
+///   |             Self::InvalidDigit(offset) => [/* invalid digit at this byte offset */][offset],
+///   |                                           ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ the failure occurred here
+/// ```
+///
+/// [`str_trim`]: ./macro.str_trim.html
+#[macro_export]
+macro_rules! str_parse {
+    (bool, $input:expr $(,)*) => {
+        $crate::__const! {$crate::pmr::bool => {
+            const ARGS_OSRCTFL4A: $crate::__str_methods::ParseBoolArgs =
+                $crate::__str_methods::ParseBoolArgs($input);
+
+            {
+                $crate::pmr::respan_to! {
+                    ($input)
+                    const _ASSERT_VALID_INPUT: () = ARGS_OSRCTFL4A.validity.assert_valid();
+                }
+
+                const OUT: $crate::pmr::bool = ARGS_OSRCTFL4A.value;
+                OUT
+            }
+        }}
+    };
+    ($ty:ty, $input:expr $(,)*) => {
+        $crate::__str_parse_int!($ty, $input, $crate::pmr::None)
+    };
+    ($ty:ty, $input:expr, radix = $radix:expr $(,)*) => {
+        $crate::__str_parse_int!($ty, $input, $crate::pmr::Some($radix))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __str_parse_int {
+    ($ty:ty, $input:expr, $radix:expr) => {
+        $crate::__const! {$ty => {
+            const ARGS_OSRCTFL4A: $crate::__str_methods::ParseIntArgs =
+                $crate::__str_methods::ParseIntArgs(
+                    $input,
+                    $radix,
+                    <$ty as $crate::__str_methods::StrParseInt>::MAX_POSITIVE,
+                    <$ty as $crate::__str_methods::StrParseInt>::MAX_NEGATIVE,
+                );
+
+            {
+                $crate::pmr::respan_to! {
+                    ($input)
+                    const _ASSERT_VALID_INPUT: () = ARGS_OSRCTFL4A.validity.assert_valid();
+                }
+
+                const OUT: $ty = if ARGS_OSRCTFL4A.negative {
+                    (ARGS_OSRCTFL4A.magnitude as i128).wrapping_neg() as $ty
+                } else {
+                    ARGS_OSRCTFL4A.magnitude as $ty
+                };
+                OUT
+            }
+        }}
+    };
+}
+
+/// Extracts integers and `bool`s out of a `&'static str` constant,
+/// using a pattern with the syntax of format strings.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Parse {}
+/// fn str_scan<T: Parse, U: Parse>(pattern: &'static str, input: &'static str) -> (T, U)
+/// # {loop{}}
+/// ```
+/// and is evaluated at compile-time,
+/// with one type parameter for each argument of the pattern.
+///
+/// The pattern is passed first, then the input followed by `=>` and the type of each argument
+/// (eg: `str_scan!("{}x{}", SIZE => u16, u16)`).
+/// This returns a tuple with the value of each argument of the pattern, in order,
+/// which is a single-element tuple when the pattern has one argument.
+///
+/// The pattern must be a string literal, or a `concat!(...)` of literals.
+///
+/// # Pattern syntax
+///
+/// The text of the pattern must match the input exactly,
+/// and `{{`/`}}` match the `{`/`}` characters.
+///
+/// These are the supported arguments:
+///
+/// - `{}`: an integer or `bool`, integers are parsed like [`str_parse`] does.
+///
+/// - `{:x}`/`{:X}`, `{:o}`, `{:b}`: an integer in hexadecimal, octal, or binary,
+///   respectively, with an optional `0x`/`0o`/`0b` prefix.
+///
+/// Each argument matches the input up to the first occurrence of the text that follows it
+/// in the pattern, or up to the end of the input for the last argument.
+/// Arguments must be separated by text, `"{}{}"` is a compile-time error.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_scan;
+///
+/// const VERSION: &str = "1.22.333"; // eg: `env!("CARGO_PKG_VERSION")`
+///
+/// const PARTS: (u32, u32, u32) = str_scan!("{}.{}.{}", VERSION => u32, u32, u32);
+/// assert_eq!(PARTS, (1, 22, 333));
+///
+/// assert_eq!(
+///     str_scan!("port={};debug={}", "port=8080;debug=true" => u16, bool),
+///     (8080, true),
+/// );
+///
+/// assert_eq!(str_scan!("#{:x}", "#ff8000" => u32), (0xff8000,));
+///
+/// assert_eq!(str_scan!("{{{}}}", "{-3}" => i8), (-3,));
+///
+/// ```
+///
+/// ### Invalid input
+///
+/// Input that doesn't match the pattern, or whose arguments can't be parsed,
+/// causes a compile-time error that shows where it happened.
+///
+/// ```compile_fail
+/// const_format::str_scan!("{}.{}.{}", "1.2" => u32, u32, u32);
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_scan!("{}.{}", "1.2b" => u32, u32);
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_scan!("{}.{}", "1.256" => u8, u8);
+/// ```
+///
+/// ```compile_fail
+/// const_format::str_scan!("v{}", "w1" => u8);
+/// ```
+///
+/// The error for the `"1.2b"` input looks like this:
+/// ```text
+/// error[E0080]: evaluation panicked: the input doesn't match the `str_scan` pattern at byte offset 3: invalid digit in argument 2, of type `u32`
+///                   before: "1.2"
+///                   after:  "b"
+///  --> src/lib.rs:1:34
+///   |
+/// 1 | const_format::str_scan!("{}.{}", "1.2b" => u32, u32);
+///   |                                  ^^^^^^ evaluation of `_` failed inside this call
+///   |
+/// note: inside `const_format::__str_methods::assert_scan`
+/// ```
+///
+/// [`str_parse`]: ./macro.str_parse.html
+#[macro_export]
+macro_rules! str_scan {
+    ($pattern:expr, $input:expr => $($ty:ty),* $(,)?) => {{
+        use $crate::__cf_osRcTFl4A;
+
+        $crate::pmr::__str_scan_impl!(($pattern) ($input) $(($ty))*)
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __str_scan_inner {
+    (
+        ($input:expr)
+        [$($piece:expr),*]
+        ($arg_count:expr)
+        $( ($kind:ident $index:tt $ty:ty $(, $radix:expr)?) )*
+    ) => {
+        $crate::__const! {($($ty,)*) => {
+            const INPUT_OSRCTFL4A: &$crate::pmr::str = $input;
+
+            const MATCH_OSRCTFL4A: $crate::__str_methods::ScanMatch<$arg_count> =
+                $crate::__str_methods::scan_match(INPUT_OSRCTFL4A, &[$($piece),*]);
+
+            $crate::__str_scan_assert! {
+                ($input) INPUT_OSRCTFL4A,
+                MATCH_OSRCTFL4A.is_match,
+                MATCH_OSRCTFL4A.offset,
+                if MATCH_OSRCTFL4A.expected.is_empty() {
+                    "expected the end of the input"
+                } else {
+                    $crate::formatcp!("expected {:?}", MATCH_OSRCTFL4A.expected)
+                },
+            }
+
+            const OUT: ($($ty,)*) = ($(
+                $crate::__str_scan_value!(
+                    ($input) INPUT_OSRCTFL4A, MATCH_OSRCTFL4A,
+                    $kind $index $ty $(, $radix)?
+                ),
+            )*);
+            OUT
+        }}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __str_scan_value {
+    (($input:expr) $input_const:ident, $match_const:ident, int $index:tt $ty:ty, $radix:expr) => {{
+        const ARGS_OSRCTFL4A: $crate::__str_methods::ParseIntArgs =
+            $crate::__str_methods::parse_int_range(
+                $input_const.as_bytes(),
+                $match_const.starts[$index],
+                $match_const.ends[$index],
+                $radix,
+                <$ty as $crate::__str_methods::StrParseInt>::MAX_POSITIVE,
+                <$ty as $crate::__str_methods::StrParseInt>::MAX_NEGATIVE,
+            );
+
+        $crate::__str_scan_value!(@assert ($input) $input_const, $match_const, $index $ty);
+
+        const OUT: $ty = if ARGS_OSRCTFL4A.negative {
+            (ARGS_OSRCTFL4A.magnitude as i128).wrapping_neg() as $ty
+        } else {
+            ARGS_OSRCTFL4A.magnitude as $ty
+        };
+        OUT
+    }};
+    (($input:expr) $input_const:ident, $match_const:ident, bool $index:tt $ty:ty) => {{
+        const ARGS_OSRCTFL4A: $crate::__str_methods::ParseBoolArgs =
+            $crate::__str_methods::parse_bool_range(
+                $input_const.as_bytes(),
+                $match_const.starts[$index],
+                $match_const.ends[$index],
+            );
+
+        $crate::__str_scan_value!(@assert ($input) $input_const, $match_const, $index $ty);
+
+        const OUT: $crate::pmr::bool = ARGS_OSRCTFL4A.value;
+        OUT
+    }};
+    (@assert ($input:expr) $input_const:ident, $match_const:ident, $index:tt $ty:ty) => {
+        // mismatches with the pattern were already reported by `__str_scan_inner`
+        $crate::__str_scan_assert! {
+            ($input) $input_const,
+            !$match_const.is_match || ARGS_OSRCTFL4A.validity.is_valid(),
+            ARGS_OSRCTFL4A.validity.offset(),
+            $crate::formatcp!(
+                "{} in argument {}, of type `{TYPE}`",
+                ARGS_OSRCTFL4A.validity.description(),
+                $index + 1usize,
+                TYPE = stringify!($ty),
+            ),
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __str_scan_assert {
+    (($input:expr) $input_const:ident, $is_ok:expr, $offset:expr, $reason:expr $(,)?) => {
+        const _: () = {
+            const OK_OSRCTFL4A: $crate::pmr::bool = $is_ok;
+            const OFFSET_OSRCTFL4A: $crate::pmr::usize = if OK_OSRCTFL4A { 0 } else { $offset };
+            const MSG_OSRCTFL4A: &$crate::pmr::str = if OK_OSRCTFL4A {
+                ""
+            } else {
+                $crate::formatcp!(
+                    "the input doesn't match the `str_scan` pattern \
+                     at byte offset {}: {}\n    before: {:?}\n    after:  {:?}",
+                    OFFSET_OSRCTFL4A,
+                    $reason,
+                    $crate::str_index!($input_const, ..OFFSET_OSRCTFL4A),
+                    $crate::str_index!($input_const, OFFSET_OSRCTFL4A..),
+                )
+            };
+
+            __cf_osRcTFl4A::pmr::respan_to! {
+                ($input)
+                __cf_osRcTFl4A::__str_methods::assert_scan(OK_OSRCTFL4A, MSG_OSRCTFL4A)
+            }
+        };
+    };
+}
+
 /// Creates a `&'static str` by repeating a `&'static str` constant `times` times
 ///
 /// This is evaluated at compile-time.
@@ -152,6 +824,133 @@ macro_rules! str_repeat {
     };
 }
 
+/// Joins a `&'static [&'static str]` constant into a `&'static str`,
+/// placing `$separator` between each string.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Separator {}
+/// fn str_join(strings: &'static [&'static str], separator: impl Separator) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// `strings` can also be a `&'static [&'static str; N]`, or a `[&'static str; N]`
+/// (eg: the array returned by [`str_split`]).
+///
+/// `impl Separator` is any of these types:
+///
+/// - `&'static str`
+///
+/// - `char`
+///
+/// - `u8`: only ascii values (0 up to 127 inclusive) are allowed
+///
+/// # Alternatives
+///
+/// For an alternative which doesn't take a separator,
+/// you can use [`str_concat_slice`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_join;
+///
+/// assert_eq!(str_join!(&["foo", "bar", "baz"], ", "), "foo, bar, baz");
+///
+/// assert_eq!(str_join!(&["dash", "separated"], '-'), "dash-separated");
+///
+/// assert_eq!(str_join!(&["", "", ""], b'/'), "//");
+///
+/// {
+///     const WORDS: &[&str] = &["hello", "world"];
+///     const SEPARATOR: char = '🦀';
+///
+///     // both arguments can be non-literal constants
+///     const OUT: &str = str_join!(WORDS, SEPARATOR);
+///
+///     assert_eq!(OUT, "hello🦀world");
+/// }
+/// ```
+///
+/// ### Splitting and joining
+///
+/// ```rust
+/// # #[cfg(feature = "rust_1_64")]
+/// # {
+/// use const_format::{str_join, str_split};
+///
+/// const PATH: &str = "usr/local/bin";
+///
+/// const SEGMENTS: [&str; 3] = str_split!(PATH, '/');
+///
+/// assert_eq!(str_join!(SEGMENTS, "::"), "usr::local::bin");
+/// # }
+/// ```
+///
+/// [`str_split`]: crate::str_split
+#[macro_export]
+macro_rules! str_join {
+    ($strings:expr, $separator:expr $(,)*) => {
+        $crate::__str_const! {{
+            const STRS_OSRCTFL4A: &[&$crate::pmr::str] =
+                &$crate::__str_methods::StrSliceConv($strings).conv();
+
+            {
+                const ARGS: $crate::__str_methods::JoinInput =
+                    $crate::__str_methods::JoinInputConv(STRS_OSRCTFL4A, $separator).conv();
+
+                const OB: &[$crate::pmr::u8; ARGS.join_length()] = &ARGS.join();
+
+                const OS: &$crate::pmr::str = unsafe { $crate::__priv_transmute_bytes_to_str!(OB) };
+
+                OS
+            }
+        }}
+    };
+}
+
+/// Concatenates a `&'static [&'static str]` constant into a `&'static str`.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// fn str_concat_slice(strings: &'static [&'static str]) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// `strings` can also be a `&'static [&'static str; N]`, or a `[&'static str; N]`.
+///
+/// This is equivalent to [`str_join`] with an empty separator.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_concat_slice;
+///
+/// assert_eq!(str_concat_slice!(&["foo", "bar", "baz"]), "foobarbaz");
+///
+/// {
+///     const PARTS: [&str; 3] = ["ñ", "", "👀"];
+///
+///     const OUT: &str = str_concat_slice!(PARTS);
+///
+///     assert_eq!(OUT, "ñ👀");
+/// }
+/// ```
+///
+/// [`str_join`]: crate::str_join
+#[macro_export]
+macro_rules! str_concat_slice {
+    ($strings:expr $(,)*) => {
+        $crate::str_join!($strings, "")
+    };
+}
+
 /// Replaces a substring in a `&'static str` constant.
 /// Returns both the new resulting `&'static str`, and the replaced substring.
 ///
@@ -678,3 +1477,442 @@ macro_rules! str_split_pat {
         }}
     };
 }
+
+/// Removes the leading and trailing whitespace from `$string` (a `&'static str` constant).
+///
+/// Whitespace is defined the same way as in [`str::trim`],
+/// as the chars for which [`char::is_whitespace`] returns true.
+///
+/// This evaluates to a subslice of `$string`, without copying it.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// fn str_trim(string: &'static str) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_trim;
+///
+/// assert_eq!(str_trim!("  foo bar\n"), "foo bar");
+/// assert_eq!(str_trim!("\u{3000}\tfoo\r\n"), "foo");
+///
+/// {
+///     // eg: the contents of a file read with `include_str!`
+///     const FILE: &str = "  0.1.0\n\n";
+///
+///     const VERSION: &str = str_trim!(FILE);
+///
+///     assert_eq!(VERSION, "0.1.0");
+/// }
+/// ```
+///
+/// [`str::trim`]: https://doc.rust-lang.org/std/primitive.str.html#method.trim
+/// [`char::is_whitespace`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_whitespace
+#[macro_export]
+macro_rules! str_trim {
+    ($string:expr $(,)*) => {
+        $crate::__str_const! {{
+            const OUT_OSRCTFL4A: &$crate::pmr::str = $crate::__str_methods::str_trim($string);
+            OUT_OSRCTFL4A
+        }}
+    };
+}
+
+/// Removes the leading whitespace from `$string` (a `&'static str` constant).
+///
+/// This is the leading-only version of [`str_trim`],
+/// which evaluates to a subslice of `$string`, without copying it.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_trim_start;
+///
+/// assert_eq!(str_trim_start!("  foo bar\n"), "foo bar\n");
+/// ```
+///
+/// [`str_trim`]: crate::str_trim
+#[macro_export]
+macro_rules! str_trim_start {
+    ($string:expr $(,)*) => {
+        $crate::__str_const! {{
+            const OUT_OSRCTFL4A: &$crate::pmr::str =
+                $crate::__str_methods::str_trim_start($string);
+            OUT_OSRCTFL4A
+        }}
+    };
+}
+
+/// Removes the trailing whitespace from `$string` (a `&'static str` constant).
+///
+/// This is the trailing-only version of [`str_trim`],
+/// which evaluates to a subslice of `$string`, without copying it.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_trim_end;
+///
+/// assert_eq!(str_trim_end!("  foo bar\n"), "  foo bar");
+/// ```
+///
+/// [`str_trim`]: crate::str_trim
+#[macro_export]
+macro_rules! str_trim_end {
+    ($string:expr $(,)*) => {
+        $crate::__str_const! {{
+            const OUT_OSRCTFL4A: &$crate::pmr::str = $crate::__str_methods::str_trim_end($string);
+            OUT_OSRCTFL4A
+        }}
+    };
+}
+
+/// Repeatedly removes `$pattern` from the start and end of
+/// `$string` (a `&'static str` constant).
+///
+/// This evaluates to a subslice of `$string`, without copying it.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Pattern {}
+/// fn str_trim_matches(string: &'static str, pattern: impl Pattern) -> &'static str
+/// # {""}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `pattern` can be any of these types:
+///
+/// - `&'static str`: an empty string removes nothing.
+///
+/// - `char`
+///
+/// - `u8`: required to be ascii (`0` up to `127` inclusive).
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_trim_matches;
+///
+/// assert_eq!(str_trim_matches!("--foo-bar--", '-'), "foo-bar");
+/// assert_eq!(str_trim_matches!("ñññfooñ", 'ñ'), "foo");
+/// assert_eq!(str_trim_matches!("/*/*foo*/", "/*"), "foo*/");
+/// assert_eq!(str_trim_matches!("0001000", b'0'), "1");
+///
+/// {
+///     const QUOTED: &str = r#""hello""#;
+///     const QUOTE: char = '"';
+///
+///     const OUT: &str = str_trim_matches!(QUOTED, QUOTE);
+///
+///     assert_eq!(OUT, "hello");
+/// }
+/// ```
+#[macro_export]
+macro_rules! str_trim_matches {
+    ($string:expr, $pattern:expr $(,)*) => {
+        $crate::__str_const! {{
+            const OUT_OSRCTFL4A: &$crate::pmr::str =
+                $crate::__str_methods::TrimInputConv($string, $pattern)
+                    .conv()
+                    .trim_matches();
+            OUT_OSRCTFL4A
+        }}
+    };
+}
+
+/// Repeatedly removes `$pattern` from the start of `$string` (a `&'static str` constant).
+///
+/// This is the leading-only version of [`str_trim_matches`],
+/// which takes the same kinds of patterns,
+/// and evaluates to a subslice of `$string`, without copying it.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_trim_start_matches;
+///
+/// assert_eq!(str_trim_start_matches!("--foo-bar--", '-'), "foo-bar--");
+/// assert_eq!(str_trim_start_matches!("../../foo", "../"), "foo");
+/// ```
+///
+/// [`str_trim_matches`]: crate::str_trim_matches
+#[macro_export]
+macro_rules! str_trim_start_matches {
+    ($string:expr, $pattern:expr $(,)*) => {
+        $crate::__str_const! {{
+            const OUT_OSRCTFL4A: &$crate::pmr::str =
+                $crate::__str_methods::TrimInputConv($string, $pattern)
+                    .conv()
+                    .trim_start_matches();
+            OUT_OSRCTFL4A
+        }}
+    };
+}
+
+/// Repeatedly removes `$pattern` from the end of `$string` (a `&'static str` constant).
+///
+/// This is the trailing-only version of [`str_trim_matches`],
+/// which takes the same kinds of patterns,
+/// and evaluates to a subslice of `$string`, without copying it.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_trim_end_matches;
+///
+/// assert_eq!(str_trim_end_matches!("--foo-bar--", '-'), "--foo-bar");
+/// assert_eq!(str_trim_end_matches!("foo.rs.rs", ".rs"), "foo");
+/// ```
+///
+/// [`str_trim_matches`]: crate::str_trim_matches
+#[macro_export]
+macro_rules! str_trim_end_matches {
+    ($string:expr, $pattern:expr $(,)*) => {
+        $crate::__str_const! {{
+            const OUT_OSRCTFL4A: &$crate::pmr::str =
+                $crate::__str_methods::TrimInputConv($string, $pattern)
+                    .conv()
+                    .trim_end_matches();
+            OUT_OSRCTFL4A
+        }}
+    };
+}
+
+/// Finds the byte index of the first instance of `$pattern` in
+/// `$string` (a `&'static str` constant).
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Pattern {}
+/// fn str_find(string: &'static str, pattern: impl Pattern) -> Option<usize>
+/// # {None}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// Where `pattern` can be any of these types:
+///
+/// - `&'static str`: an empty string is found at index `0`.
+///
+/// - `char`
+///
+/// - `u8`: required to be ascii (`0` up to `127` inclusive).
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{str_find, str_index};
+///
+/// assert_eq!(str_find!("foo.bar.baz", '.'), Some(3));
+/// assert_eq!(str_find!("foo.bar.baz", "ba"), Some(4));
+/// assert_eq!(str_find!("foo.bar.baz", b'z'), Some(10));
+/// assert_eq!(str_find!("foo.bar.baz", "qux"), None);
+///
+/// // Splitting a version string at the first `.`
+/// {
+///     const VERSION: &str = "1.57.0";
+///
+///     const DOT: usize = match str_find!(VERSION, '.') {
+///         Some(x) => x,
+///         None => VERSION.len(),
+///     };
+///
+///     assert_eq!(str_index!(VERSION, ..DOT), "1");
+///     assert_eq!(str_index!(VERSION, DOT + 1..), "57.0");
+/// }
+/// ```
+#[macro_export]
+macro_rules! str_find {
+    ($string:expr, $pattern:expr $(,)*) => {
+        $crate::__const! {
+            $crate::pmr::Option<$crate::pmr::usize> => {
+            const OUT_OSRCTFL4A: $crate::pmr::Option<$crate::pmr::usize> =
+                $crate::__str_methods::FindInputConv($string, $pattern).conv().find();
+
+            OUT_OSRCTFL4A
+        }}
+    };
+}
+
+/// Finds the byte index of the last instance of `$pattern` in
+/// `$string` (a `&'static str` constant).
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Pattern {}
+/// fn str_rfind(string: &'static str, pattern: impl Pattern) -> Option<usize>
+/// # {None}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// This takes the same kinds of patterns as [`str_find`],
+/// an empty `&str` pattern is found at the end of `$string`.
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::{str_index, str_rfind};
+///
+/// assert_eq!(str_rfind!("foo.bar.baz", '.'), Some(7));
+/// assert_eq!(str_rfind!("foo.bar.baz", "ba"), Some(8));
+/// assert_eq!(str_rfind!("foo.bar.baz", "qux"), None);
+///
+/// {
+///     const PATH: &str = "src/macros/str_methods.rs";
+///
+///     const FILE_START: usize = match str_rfind!(PATH, '/') {
+///         Some(x) => x + 1,
+///         None => 0,
+///     };
+///
+///     assert_eq!(str_index!(PATH, FILE_START..), "str_methods.rs");
+/// }
+/// ```
+///
+/// [`str_find`]: crate::str_find
+#[macro_export]
+macro_rules! str_rfind {
+    ($string:expr, $pattern:expr $(,)*) => {
+        $crate::__const! {
+            $crate::pmr::Option<$crate::pmr::usize> => {
+            const OUT_OSRCTFL4A: $crate::pmr::Option<$crate::pmr::usize> =
+                $crate::__str_methods::FindInputConv($string, $pattern).conv().rfind();
+
+            OUT_OSRCTFL4A
+        }}
+    };
+}
+
+/// Whether `$pattern` is in `$string` (a `&'static str` constant).
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Pattern {}
+/// fn str_contains(string: &'static str, pattern: impl Pattern) -> bool
+/// # {false}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// This takes the same kinds of patterns as [`str_find`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_contains;
+///
+/// assert!(str_contains!("foo bar", "o b"));
+/// assert!(str_contains!("foo bar", 'r'));
+/// assert!(!str_contains!("foo bar", b'x'));
+/// ```
+///
+#[cfg_attr(feature = "assertcp", doc = "```rust")]
+#[cfg_attr(not(feature = "assertcp"), doc = "```ignore")]
+/// use const_format::{assertcp, str_contains};
+///
+/// const NAME: &str = "my_crate";
+///
+/// assertcp!(!str_contains!(NAME, '-'), "crate names can't contain dashes: {}", NAME);
+/// ```
+///
+/// [`str_find`]: crate::str_find
+#[macro_export]
+macro_rules! str_contains {
+    ($string:expr, $pattern:expr $(,)*) => {
+        $crate::__const! {
+            $crate::pmr::bool => {
+            const OUT_OSRCTFL4A: $crate::pmr::bool =
+                $crate::__str_methods::FindInputConv($string, $pattern).conv().contains();
+
+            OUT_OSRCTFL4A
+        }}
+    };
+}
+
+/// Whether `$string` (a `&'static str` constant) starts with `$pattern`.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Pattern {}
+/// fn str_starts_with(string: &'static str, pattern: impl Pattern) -> bool
+/// # {false}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// This takes the same kinds of patterns as [`str_find`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_starts_with;
+///
+/// assert!(str_starts_with!("foo bar", "foo"));
+/// assert!(str_starts_with!("ñoo bar", 'ñ'));
+/// assert!(!str_starts_with!("foo bar", "bar"));
+/// ```
+///
+/// [`str_find`]: crate::str_find
+#[macro_export]
+macro_rules! str_starts_with {
+    ($string:expr, $pattern:expr $(,)*) => {
+        $crate::__const! {
+            $crate::pmr::bool => {
+            const OUT_OSRCTFL4A: $crate::pmr::bool =
+                $crate::__str_methods::FindInputConv($string, $pattern).conv().starts_with();
+
+            OUT_OSRCTFL4A
+        }}
+    };
+}
+
+/// Whether `$string` (a `&'static str` constant) ends with `$pattern`.
+///
+/// # Signature
+///
+/// This macro acts like a function of this signature:
+/// ```rust
+/// # trait Pattern {}
+/// fn str_ends_with(string: &'static str, pattern: impl Pattern) -> bool
+/// # {false}
+/// ```
+/// and is evaluated at compile-time.
+///
+/// This takes the same kinds of patterns as [`str_find`].
+///
+/// # Example
+///
+/// ```rust
+/// use const_format::str_ends_with;
+///
+/// assert!(str_ends_with!("foo bar", "bar"));
+/// assert!(str_ends_with!("foo bar", b'r'));
+/// assert!(!str_ends_with!("foo bar", "foo"));
+/// ```
+///
+/// [`str_find`]: crate::str_find
+#[macro_export]
+macro_rules! str_ends_with {
+    ($string:expr, $pattern:expr $(,)*) => {
+        $crate::__const! {
+            $crate::pmr::bool => {
+            const OUT_OSRCTFL4A: $crate::pmr::bool =
+                $crate::__str_methods::FindInputConv($string, $pattern).conv().ends_with();
+
+            OUT_OSRCTFL4A
+        }}
+    };
+}
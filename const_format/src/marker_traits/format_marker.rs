@@ -254,6 +254,7 @@ std_kind_impls! {
     i64, u64,
     i128, u128,
     isize, usize,
+    f32, f64,
     bool, char,
 }
 
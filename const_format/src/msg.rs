@@ -88,4 +88,6 @@ type_level_error! {
     NotAscii => input_text_was_not_ascii<>,
 
     NotOnCharBoundary => NotOnCharBoundary<>,
+
+    InvalidMapEntry => map_key_without_value_or_value_without_key<>,
 }
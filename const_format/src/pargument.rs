@@ -1,8 +1,9 @@
 #![allow(clippy::wrong_self_convention)]
 
 use crate::{
-    char_encoding::FmtChar,
-    formatting::{Formatting, FormattingFlags},
+    char_encoding::{truncate_str, utf8_char_count, FmtChar},
+    float_encoding::FmtFloat,
+    formatting::{Alignment, Formatting, FormattingFlags, NumberFormatting, Padding},
     wrapper_types::PWrapper,
 };
 
@@ -10,12 +11,70 @@ use crate::{
 /// The uniform representation for every argument of the concatcp macro.
 pub struct PArgument {
     pub elem: PVariant,
+    /// The length of the formatted argument, including the padding.
     pub fmt_len: usize,
     pub fmt: Formatting,
     pub fmt_flags: FormattingFlags,
+    pub padding: Padding,
 }
 
 impl PArgument {
+    /// Constructs a `PArgument` that is padded according to the width in `fmt_flags`.
+    ///
+    /// `len` is the length of the formatted argument in bytes,
+    /// and `char_count` is its length in `char`s.
+    const fn padded(
+        elem: PVariant,
+        len: usize,
+        char_count: usize,
+        fmt: Formatting,
+        fmt_flags: FormattingFlags,
+        default_alignment: Alignment,
+    ) -> Self {
+        let padding = match elem {
+            PVariant::Int(_) | PVariant::Float(_) => fmt_flags.int_padding(char_count),
+            _ => fmt_flags.padding(char_count, default_alignment),
+        };
+        PArgument {
+            elem,
+            fmt_len: len + padding.byte_len(fmt_flags.fill()),
+            fmt,
+            fmt_flags,
+            padding,
+        }
+    }
+
+    /// Constructs a `PArgument` that ignores the width in `fmt_flags`,
+    /// like the `Debug` impls for `str` and `char` in std.
+    const fn unpadded(
+        elem: PVariant,
+        len: usize,
+        fmt: Formatting,
+        fmt_flags: FormattingFlags,
+    ) -> Self {
+        PArgument {
+            elem,
+            fmt_len: len,
+            fmt,
+            fmt_flags,
+            padding: Padding::NONE,
+        }
+    }
+
+    /// Constructs a `PArgument` for an integer formatted in scientific notation,
+    /// which is written the same way as a float.
+    const fn integer_exponential(integer: Integer, fmt_flags: FormattingFlags) -> Self {
+        let elem = PWrapper(integer).to_fmt_float(fmt_flags);
+        PArgument::padded(
+            PVariant::Float(elem),
+            elem.len(),
+            elem.len(),
+            Formatting::Debug,
+            fmt_flags,
+            Alignment::Right,
+        )
+    }
+
     /// Calculates the length of the string after adding up all the PArguments
     pub const fn calc_len(mut args: &[PArgument]) -> usize {
         let mut sum = 0;
@@ -30,10 +89,15 @@ impl PArgument {
 }
 
 #[doc(hidden)]
+// `Float` can't be boxed, because this is constructed in const fns.
+#[allow(clippy::large_enum_variant)]
 pub enum PVariant {
     Str(&'static str),
     Int(Integer),
     Char(FmtChar),
+    Float(FmtFloat),
+    Bytes(&'static [u8]),
+    Byte(u8),
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -91,24 +155,42 @@ macro_rules! pconvwrapper_impls {
             #[doc(hidden)]
             impl PConvWrapper<$Signed> {
                 pub const fn $method(self, fmt_flags: FormattingFlags)->PArgument{
-                    PArgument {
-                        fmt_len: $crate::pmr::PWrapper(self.0).$called(fmt_flags),
-                        fmt: $formatting,
-                        fmt_flags,
-                        elem: PVariant::Int(self.to_integer()),
+                    if let (Formatting::Debug, NumberFormatting::Exponential) =
+                        ($formatting, fmt_flags.num_fmt())
+                    {
+                        return PArgument::integer_exponential(self.to_integer(), fmt_flags);
                     }
+
+                    let len = $crate::pmr::PWrapper(self.0).$called(fmt_flags);
+                    PArgument::padded(
+                        PVariant::Int(self.to_integer()),
+                        len,
+                        len,
+                        $formatting,
+                        fmt_flags,
+                        Alignment::Right,
+                    )
                 }
             }
 
             #[doc(hidden)]
             impl PConvWrapper<$Unsigned> {
                 pub const fn $method(self, fmt_flags: FormattingFlags)->PArgument{
-                    PArgument {
-                        fmt_len: $crate::pmr::PWrapper(self.0).$called(fmt_flags),
-                        fmt: $formatting,
-                        fmt_flags,
-                        elem: PVariant::Int(self.to_integer()),
+                    if let (Formatting::Debug, NumberFormatting::Exponential) =
+                        ($formatting, fmt_flags.num_fmt())
+                    {
+                        return PArgument::integer_exponential(self.to_integer(), fmt_flags);
                     }
+
+                    let len = $crate::pmr::PWrapper(self.0).$called(fmt_flags);
+                    PArgument::padded(
+                        PVariant::Int(self.to_integer()),
+                        len,
+                        len,
+                        $formatting,
+                        fmt_flags,
+                        Alignment::Right,
+                    )
                 }
             }
         )*
@@ -124,6 +206,124 @@ pconvwrapper_impls! {
     (isize, usize)
 }
 
+macro_rules! pconvwrapper_float_impls {
+    ($( ($ty:ty, $to_display:ident, $to_debug:ident) )*) => (
+        $(
+            #[doc(hidden)]
+            impl PConvWrapper<$ty> {
+                #[inline]
+                pub const fn to_pargument_display(self, fmt_flags: FormattingFlags) -> PArgument {
+                    let elem = crate::float_encoding::$to_display(self.0, fmt_flags);
+                    PArgument::padded(
+                        PVariant::Float(elem),
+                        elem.len(),
+                        elem.len(),
+                        Formatting::Display,
+                        fmt_flags,
+                        Alignment::Right,
+                    )
+                }
+                #[inline]
+                pub const fn to_pargument_debug(self, fmt_flags: FormattingFlags) -> PArgument {
+                    let elem = crate::float_encoding::$to_debug(self.0, fmt_flags);
+                    PArgument::padded(
+                        PVariant::Float(elem),
+                        elem.len(),
+                        elem.len(),
+                        Formatting::Debug,
+                        fmt_flags,
+                        Alignment::Right,
+                    )
+                }
+            }
+        )*
+    );
+}
+
+pconvwrapper_float_impls! {
+    (f32, f32_to_display, f32_to_debug)
+    (f64, f64_to_display, f64_to_debug)
+}
+
+// The types that are written the same way by the `concatbytes` macro as by `concatcp`.
+macro_rules! pconvwrapper_bytes_impls {
+    ($($ty:ty)*) => (
+        $(
+            #[doc(hidden)]
+            impl PConvWrapper<$ty> {
+                #[inline]
+                pub const fn to_pargument_bytes(self, fmt_flags: FormattingFlags) -> PArgument {
+                    self.to_pargument_display(fmt_flags)
+                }
+            }
+        )*
+    );
+}
+
+pconvwrapper_bytes_impls! {
+    i8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize
+    f32 f64
+    bool char &'static str PArgument
+}
+
+#[doc(hidden)]
+impl PConvWrapper<u8> {
+    /// Converts the `u8` into a `PArgument` for the byte itself,
+    /// instead of its decimal representation.
+    #[inline]
+    pub const fn to_pargument_bytes(self, fmt_flags: FormattingFlags) -> PArgument {
+        if let Some(0) = fmt_flags.precision() {
+            return PConvWrapper(&[] as &'static [u8]).to_pargument_bytes(fmt_flags);
+        }
+
+        PArgument::padded(
+            PVariant::Byte(self.0),
+            1,
+            1,
+            Formatting::Display,
+            fmt_flags,
+            Alignment::Left,
+        )
+    }
+}
+
+#[doc(hidden)]
+impl PConvWrapper<&'static [u8]> {
+    #[inline]
+    pub const fn to_pargument_bytes(self, fmt_flags: FormattingFlags) -> PArgument {
+        let bytes = truncate_bytes(self.0, fmt_flags.precision());
+        PArgument::padded(
+            PVariant::Bytes(bytes),
+            bytes.len(),
+            bytes.len(),
+            Formatting::Display,
+            fmt_flags,
+            Alignment::Left,
+        )
+    }
+}
+
+#[doc(hidden)]
+impl<const N: usize> PConvWrapper<&'static [u8; N]> {
+    #[inline]
+    pub const fn to_pargument_bytes(self, fmt_flags: FormattingFlags) -> PArgument {
+        PConvWrapper(self.0 as &'static [u8]).to_pargument_bytes(fmt_flags)
+    }
+}
+
+/// Truncates `bytes` to its first `max_len` bytes,
+/// returning `bytes` unmodified if `max_len` is `None`.
+const fn truncate_bytes(mut bytes: &[u8], max_len: Option<usize>) -> &[u8] {
+    if let Some(max_len) = max_len {
+        while bytes.len() > max_len {
+            if let [rem @ .., _] = bytes {
+                bytes = rem;
+            }
+        }
+    }
+    bytes
+}
+
 #[doc(hidden)]
 impl PConvWrapper<PArgument> {
     #[inline]
@@ -139,9 +339,8 @@ impl PConvWrapper<PArgument> {
 #[doc(hidden)]
 impl PConvWrapper<bool> {
     #[inline]
-    pub const fn to_pargument_display(self, _: FormattingFlags) -> PArgument {
-        PConvWrapper(if self.0 { "true" } else { "false" })
-            .to_pargument_display(FormattingFlags::DEFAULT)
+    pub const fn to_pargument_display(self, fmt_flags: FormattingFlags) -> PArgument {
+        PConvWrapper(if self.0 { "true" } else { "false" }).to_pargument_display(fmt_flags)
     }
     #[inline]
     pub const fn to_pargument_debug(self, fmt_flags: FormattingFlags) -> PArgument {
@@ -153,23 +352,29 @@ impl PConvWrapper<bool> {
 impl PConvWrapper<char> {
     #[inline]
     pub const fn to_pargument_display(self, fmt_flags: FormattingFlags) -> PArgument {
+        if let Some(0) = fmt_flags.precision() {
+            return PConvWrapper("").to_pargument_display(fmt_flags);
+        }
+
         let elem = crate::char_encoding::char_to_display(self.0);
-        PArgument {
-            fmt_len: elem.len(),
+        PArgument::padded(
+            PVariant::Char(elem),
+            elem.len(),
+            1,
+            Formatting::Display,
             fmt_flags,
-            fmt: Formatting::Display,
-            elem: PVariant::Char(elem),
-        }
+            Alignment::Left,
+        )
     }
     #[inline]
     pub const fn to_pargument_debug(self, fmt_flags: FormattingFlags) -> PArgument {
         let elem = crate::char_encoding::char_to_debug(self.0);
-        PArgument {
-            fmt_len: elem.len(),
+        PArgument::unpadded(
+            PVariant::Char(elem),
+            elem.len(),
+            Formatting::Debug,
             fmt_flags,
-            fmt: Formatting::Debug,
-            elem: PVariant::Char(elem),
-        }
+        )
     }
 }
 
@@ -177,20 +382,23 @@ impl PConvWrapper<char> {
 impl PConvWrapper<&'static str> {
     #[inline]
     pub const fn to_pargument_display(self, fmt_flags: FormattingFlags) -> PArgument {
-        PArgument {
-            fmt_len: PWrapper(self.0).compute_display_len(fmt_flags),
+        let str = truncate_str(self.0, fmt_flags.precision());
+        PArgument::padded(
+            PVariant::Str(str),
+            str.len(),
+            utf8_char_count(str.as_bytes()),
+            Formatting::Display,
             fmt_flags,
-            fmt: Formatting::Display,
-            elem: PVariant::Str(self.0),
-        }
+            Alignment::Left,
+        )
     }
     #[inline]
     pub const fn to_pargument_debug(self, fmt_flags: FormattingFlags) -> PArgument {
-        PArgument {
-            fmt_len: PWrapper(self.0).compute_debug_len(fmt_flags),
+        PArgument::unpadded(
+            PVariant::Str(self.0),
+            PWrapper(self.0).compute_debug_len(fmt_flags),
+            Formatting::Debug,
             fmt_flags,
-            fmt: Formatting::Debug,
-            elem: PVariant::Str(self.0),
-        }
+        )
     }
 }
@@ -1,3 +1,5 @@
+use core::cmp::Ordering;
+
 /// A const equivalent of `&str` equality comparison.
 ///
 /// # Example
@@ -88,6 +90,86 @@ pub const fn u8_slice_eq(left: &[u8], right: &[u8]) -> bool {
     true
 }
 
+/// A const equivalent of `&str` ordering comparison,
+/// comparing the strings lexicographically by their bytes.
+///
+/// # Example
+///
+#[cfg_attr(feature = "fmt", doc = "```rust")]
+#[cfg_attr(not(feature = "fmt"), doc = "```ignore")]
+/// use const_format::utils::str_cmp;
+///
+/// use std::cmp::Ordering;
+///
+/// const CMPS: [Ordering; 4] = [
+///     str_cmp("foo", "foo"),
+///     str_cmp("foo", "fooooo"),
+///     str_cmp("bar", "baz"),
+///     str_cmp("baz", "ba"),
+/// ];
+///
+/// assert_eq!(
+///     CMPS,
+///     [Ordering::Equal, Ordering::Less, Ordering::Less, Ordering::Greater],
+/// );
+///
+/// ```
+///
+pub const fn str_cmp(left: &str, right: &str) -> Ordering {
+    u8_slice_cmp(left.as_bytes(), right.as_bytes())
+}
+
+/// A const equivalent of `&[u8]` ordering comparison,
+/// comparing the slices lexicographically.
+///
+/// # Example
+///
+#[cfg_attr(feature = "fmt", doc = "```rust")]
+#[cfg_attr(not(feature = "fmt"), doc = "```ignore")]
+/// use const_format::utils::u8_slice_cmp;
+///
+/// use std::cmp::Ordering;
+///
+/// const CMPS: [Ordering; 4] = [
+///     u8_slice_cmp(&[3, 5], &[3, 5]),
+///     u8_slice_cmp(&[3, 5], &[3, 5, 8]),
+///     u8_slice_cmp(&[3, 5], &[3, 4, 8]),
+///     u8_slice_cmp(&[], &[0]),
+/// ];
+///
+/// assert_eq!(
+///     CMPS,
+///     [Ordering::Equal, Ordering::Less, Ordering::Greater, Ordering::Less],
+/// );
+///
+/// ```
+///
+pub const fn u8_slice_cmp(left: &[u8], right: &[u8]) -> Ordering {
+    let min_len = if left.len() < right.len() {
+        left.len()
+    } else {
+        right.len()
+    };
+
+    let mut i = 0;
+    while i != min_len {
+        if left[i] < right[i] {
+            return Ordering::Less;
+        } else if left[i] > right[i] {
+            return Ordering::Greater;
+        }
+        i += 1;
+    }
+
+    if left.len() < right.len() {
+        Ordering::Less
+    } else if left.len() > right.len() {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,4 +202,38 @@ mod tests {
         assert!(str_eq("0, 1", "0, 1"));
         assert!(!str_eq("0, 1", "0, 2"));
     }
+
+    #[test]
+    fn slice_cmp_test() {
+        let slices: &[&[u8]] = &[&[], &[0], &[1], &[0, 1], &[0, 2], &[1, 0], &[255]];
+
+        for left in slices {
+            for right in slices {
+                assert_eq!(
+                    u8_slice_cmp(left, right),
+                    left.cmp(right),
+                    "{:?} {:?}",
+                    left,
+                    right
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn str_cmp_test() {
+        let strs = ["", "0", "1", "0, 1", "0, 2", "1, 0", "ñ", "ñ0", "z"];
+
+        for left in strs {
+            for right in strs {
+                assert_eq!(
+                    str_cmp(left, right),
+                    left.cmp(right),
+                    "{:?} {:?}",
+                    left,
+                    right
+                );
+            }
+        }
+    }
 }
@@ -1,8 +1,9 @@
 #![allow(unexpected_cfgs)]
 
 use crate::{
+    float_encoding::FmtFloat,
     formatting::{FormattingFlags, NumberFormatting, StartAndArray, FOR_ESCAPING},
-    pargument::Integer,
+    pargument::{Integer, PConvWrapper},
 };
 
 use core::ops::Range;
@@ -88,22 +89,32 @@ impl<'a, T> PWrapper<&'a [T]> {
     }
 }
 
+// The length of the `+` sign and the `0x`/`0o`/`0b` prefix
+macro_rules! compute_prefix_len {
+    ($fmt:expr) => {
+        ($fmt.sign_plus() as usize) + (($fmt.is_alternate() as usize) << 1)
+    };
+}
 macro_rules! compute_hex_count {
-    ($bits:expr, $int:expr, $with_0x:expr) => {{
-        let with_0x = ($with_0x as usize) << 1;
+    ($bits:expr, $int:expr, $fmt:expr) => {{
         let i = ($bits - $int.leading_zeros()) as usize;
         (if i == 0 {
             1
         } else {
             (i >> 2) + ((i & 3) != 0) as usize
-        }) + with_0x
+        }) + compute_prefix_len!($fmt)
+    }};
+}
+macro_rules! compute_octal_count {
+    ($bits:expr, $int:expr, $fmt:expr) => {{
+        let i = ($bits - $int.leading_zeros()) as usize;
+        (if i == 0 { 1 } else { (i + 2) / 3 }) + compute_prefix_len!($fmt)
     }};
 }
 macro_rules! compute_binary_count {
-    ($bits:expr, $int:expr, $with_0b:expr) => {{
-        let with_0b = ($with_0b as usize) << 1;
+    ($bits:expr, $int:expr, $fmt:expr) => {{
         let i = ($bits - $int.leading_zeros()) as usize;
-        (if i == 0 { 1 } else { i }) + with_0b
+        (if i == 0 { 1 } else { i }) + compute_prefix_len!($fmt)
     }};
 }
 
@@ -142,22 +153,38 @@ macro_rules! impl_number_of_digits {
                     NumberFormatting::Decimal=>
                         self.compute_display_len(fmt),
                     NumberFormatting::Hexadecimal=>
-                        compute_hex_count!($bits, self.0, fmt.is_alternate()),
+                        compute_hex_count!($bits, self.0, fmt),
                     NumberFormatting::Binary=>
-                        compute_binary_count!($bits, self.0, fmt.is_alternate()),
+                        compute_binary_count!($bits, self.0, fmt),
+                    NumberFormatting::Octal=>
+                        compute_octal_count!($bits, self.0, fmt),
+                    NumberFormatting::Exponential=>
+                        self.exponential_len(fmt),
                 }
             }
 
             /// Computes how long much space is necessary to
             /// write this integer as a hexadecimal literal.
             pub const fn hexadecimal_len(self, fmt: FormattingFlags)-> usize {
-                compute_hex_count!($bits, self.0, fmt.is_alternate())
+                compute_hex_count!($bits, self.0, fmt)
+            }
+
+            /// Computes how long much space is necessary to
+            /// write this integer as an octal literal.
+            pub const fn octal_len(self, fmt: FormattingFlags)-> usize {
+                compute_octal_count!($bits, self.0, fmt)
             }
 
             /// Computes how long much space is necessary to
             /// write this integer as a binary literal.
             pub const fn binary_len(self, fmt: FormattingFlags)-> usize {
-                compute_binary_count!($bits, self.0, fmt.is_alternate())
+                compute_binary_count!($bits, self.0, fmt)
+            }
+
+            /// Computes how long much space is necessary to
+            /// write this integer in scientific notation.
+            pub const fn exponential_len(self, fmt: FormattingFlags)-> usize {
+                PWrapper(PConvWrapper(self.0).to_integer()).to_fmt_float(fmt).len()
             }
         }
     };
@@ -176,9 +203,9 @@ macro_rules! impl_number_of_digits {
 
             #[allow(unused_mut,unused_variables)]
             #[doc(hidden)]
-            pub const fn compute_display_len(self, _: FormattingFlags)-> usize {
+            pub const fn compute_display_len(self, fmt: FormattingFlags)-> usize {
                 let mut n = self.0.wrapping_abs() as $Unsigned;
-                let mut len = 1 + (self.0 < 0) as usize;
+                let mut len = 1 + (self.0 < 0 || fmt.sign_plus()) as usize;
                 impl_number_of_digits!(num number_of_digits;$bits n len)
             }
 
@@ -199,9 +226,9 @@ macro_rules! impl_number_of_digits {
             }
 
             #[doc(hidden)]
-            pub const fn compute_display_len(self, _: FormattingFlags)-> usize {
+            pub const fn compute_display_len(self, fmt: FormattingFlags)-> usize {
                 let mut n = self.0;
-                let mut len = 1usize;
+                let mut len = 1 + fmt.sign_plus() as usize;
                 impl_number_of_digits!(num number_of_digits;$bits n len)
             }
         }
@@ -259,12 +286,26 @@ macro_rules! impl_for_xsize {
                 PWrapper(self.0 as $XWord).hexadecimal_len(fmt)
             }
 
+            /// Computes how long much space is necessary to
+            /// write this integer as an octal literal.
+            #[inline(always)]
+            pub const fn octal_len(self, fmt: FormattingFlags) -> usize {
+                PWrapper(self.0 as $XWord).octal_len(fmt)
+            }
+
             /// Computes how long much space is necessary to
             /// write this integer as a binary literal.
             #[inline(always)]
             pub const fn binary_len(self, fmt: FormattingFlags) -> usize {
                 PWrapper(self.0 as $XWord).binary_len(fmt)
             }
+
+            /// Computes how long much space is necessary to
+            /// write this integer in scientific notation.
+            #[inline(always)]
+            pub const fn exponential_len(self, fmt: FormattingFlags) -> usize {
+                PWrapper(self.0 as $XWord).exponential_len(fmt)
+            }
         }
     };
 }
@@ -369,6 +410,45 @@ impl PWrapper<Integer> {
         out
     }
 
+    pub const fn to_start_array_octal(self, flags: FormattingFlags) -> StartAndArray<[u8; 45]> {
+        let mut n = if self.0.is_negative {
+            self.0.as_negative() as u128
+        } else {
+            self.0.unsigned
+        };
+
+        n &= *self.0.mask;
+
+        let mut out = StartAndArray {
+            start: 45,
+            array: [0u8; 45],
+        };
+
+        loop {
+            out.start -= 1;
+            let digit = (n & 0b111) as u8;
+            out.array[out.start] = b'0' + digit;
+            n >>= 3;
+            if n == 0 {
+                break;
+            }
+        }
+
+        if flags.is_alternate() {
+            out.start -= 1;
+            out.array[out.start] = b'o';
+            out.start -= 1;
+            out.array[out.start] = b'0';
+        }
+
+        out
+    }
+
+    /// Formats the integer in scientific notation, eg: `1.5e3`.
+    pub const fn to_fmt_float(self, flags: FormattingFlags) -> FmtFloat {
+        FmtFloat::integer_exponential(self.0.is_negative, self.0.unsigned, flags)
+    }
+
     pub const fn to_start_array_display(self) -> StartAndArray<[u8; 40]> {
         let mut out = StartAndArray {
             start: 40,
@@ -414,17 +494,13 @@ impl PWrapper<&[u8]> {
         let mut sum = range.end - range.start;
         while range.start < range.end {
             let c = self.0[range.start];
-            if c < 128 {
-                let shifted = 1 << c;
-                if (FOR_ESCAPING.is_escaped & shifted) != 0 {
-                    sum += if (FOR_ESCAPING.is_backslash_escaped & shifted) == 0 {
-                        3 // `\x01` only add 3 characters
-                    } else {
-                        1 // Escaped with a backslash
-                    };
-                }
+            if c < 128 && (FOR_ESCAPING.is_escaped & (1 << c)) == 0 {
+                range.start += 1;
+            } else {
+                let escaped = crate::char_encoding::str_char_debug(self.0, range.start);
+                sum += escaped.len - escaped.char_len;
+                range.start += escaped.char_len;
             }
-            range.start += 1;
         }
         sum + 2 // The quote characters
     }
@@ -441,11 +517,42 @@ impl PWrapper<&str> {
     /// Computes how much space is necessary to write a `&str` with display formatting
     #[inline(always)]
     #[doc(hidden)]
-    pub const fn compute_display_len(self, _: FormattingFlags) -> usize {
-        self.0.len()
+    pub const fn compute_display_len(self, fmt: FormattingFlags) -> usize {
+        crate::char_encoding::truncate_str(self.0, fmt.precision()).len()
     }
 }
 
+macro_rules! impl_float_len {
+    ($( ($ty:ty, $to_display:ident, $to_debug:ident) )*) => (
+        $(
+            impl PWrapper<$ty> {
+                /// Computes how much space is necessary to write a
+                #[doc = concat!("`", stringify!($ty), "`")]
+                /// with debug formatting
+                #[inline]
+                #[doc(hidden)]
+                pub const fn compute_debug_len(self, fmt: FormattingFlags) -> usize {
+                    crate::float_encoding::$to_debug(self.0, fmt).len()
+                }
+
+                /// Computes how much space is necessary to write a
+                #[doc = concat!("`", stringify!($ty), "`")]
+                /// with display formatting
+                #[inline]
+                #[doc(hidden)]
+                pub const fn compute_display_len(self, fmt: FormattingFlags) -> usize {
+                    crate::float_encoding::$to_display(self.0, fmt).len()
+                }
+            }
+        )*
+    );
+}
+
+impl_float_len! {
+    (f32, f32_to_display, f32_to_debug)
+    (f64, f64_to_display, f64_to_debug)
+}
+
 #[cfg(feature = "fmt")]
 const _: () = {
     use crate::marker_traits::{FormatMarker, IsNotStdKind};
@@ -500,3 +607,40 @@ impl_eq_for_primitives! {
     impl[] char = l == *r;
     impl[] &str = crate::slice_cmp::str_eq(l, r);
 }
+
+#[cfg(feature = "assertcp")]
+macro_rules! impl_cmp_for_primitives {
+    ($($type:ty),* $(,)?) => (
+        $(
+            impl PWrapper<$type> {
+                /// Compares `self` and `other` for ordering.
+                ///
+                /// This method is only available with the "assertcp" feature.
+                pub const fn const_cmp(&self, other: &$type) -> core::cmp::Ordering {
+                    if self.0 < *other {
+                        core::cmp::Ordering::Less
+                    } else if self.0 > *other {
+                        core::cmp::Ordering::Greater
+                    } else {
+                        core::cmp::Ordering::Equal
+                    }
+                }
+            }
+        )*
+    )
+}
+
+#[cfg(feature = "assertcp")]
+impl_cmp_for_primitives! {
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, bool, char,
+}
+
+#[cfg(feature = "assertcp")]
+impl PWrapper<&str> {
+    /// Compares `self` and `other` lexicographically.
+    ///
+    /// This method is only available with the "assertcp" feature.
+    pub const fn const_cmp(&self, other: &&str) -> core::cmp::Ordering {
+        crate::slice_cmp::str_cmp(self.0, other)
+    }
+}
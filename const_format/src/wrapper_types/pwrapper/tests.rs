@@ -23,6 +23,16 @@ fn get_lower_hex_digits(n: impl fmt::LowerHex) -> ArrayString<64> {
     write!(buff, "{:x}", n).unwrap();
     buff
 }
+fn get_octal_digits(n: impl fmt::Octal) -> ArrayString<64> {
+    let mut buff = ArrayString::<64>::new();
+    write!(buff, "{:#o}", n).unwrap();
+    buff
+}
+fn get_exponential_digits(n: impl fmt::LowerExp) -> ArrayString<64> {
+    let mut buff = ArrayString::<64>::new();
+    write!(buff, "{:+e}", n).unwrap();
+    buff
+}
 fn get_binary_digits(n: impl fmt::Binary) -> ArrayString<192> {
     let mut buff = ArrayString::<192>::new();
     write!(buff, "{:b}", n).unwrap();
@@ -40,6 +50,8 @@ macro_rules! check_number_of_digits_ {
             let hex_digits = get_hex_digits(val);
             let lower_hex_digits = get_lower_hex_digits(val);
             let binary_digits = get_binary_digits(val);
+            let octal_digits = get_octal_digits(val);
+            let exponential_digits = get_exponential_digits(val);
             let wrapper = PWrapper(val);
 
             {
@@ -68,6 +80,17 @@ macro_rules! check_number_of_digits_ {
                     binary_digits.len(),
                     "const_debug_len binary"
                 );
+                assert_eq!(
+                    wrapper.compute_debug_len(DEF_FLAGS.set_octal().set_alternate(true)),
+                    octal_digits.len(),
+                    "const_debug_len octal"
+                );
+                let exp_flags = DEF_FLAGS.set_lower_exponential().set_sign_plus(true);
+                assert_eq!(
+                    wrapper.compute_debug_len(exp_flags),
+                    exponential_digits.len(),
+                    "const_debug_len exponential"
+                );
             }
 
             {
@@ -108,6 +131,13 @@ macro_rules! check_number_of_digits_ {
                     binary_digits.as_bytes(),
                     "const_debug_len binary"
                 );
+
+                let sa = integer.to_start_array_octal(FormattingFlags::NEW.set_alternate(true));
+                assert_eq!(
+                    &sa.array[sa.start..],
+                    octal_digits.as_bytes(),
+                    "const_debug_len octal"
+                );
             }
         }
 
@@ -1,7 +1,7 @@
 use cfmt_a::{
     ascii_str,
     fmt::{ComputeStrLength, Formatter, FormattingFlags},
-    AsciiStr,
+    str_escape, AsciiStr, Escape,
 };
 
 mod debug_methods;
@@ -137,12 +137,43 @@ fn write_str_methods() {
 
     let expected = "\
         ello\n;;\nABCD\n;;\x1F bar;;what\0the;;----;;\
-        \"ello\\n\";;\"\\nABCD\\n\";;\"\\x1F bar\";;\"what\\x00the\";;\
+        \"ello\\n\";;\"\\nABCD\\n\";;\"\\u{1f} bar\";;\"what\\0the\";;\
     ";
 
     write_with_flag(FormattingFlags::NEW, expected, &inner);
 }
 
+#[test]
+fn write_str_escaped_methods() {
+    const FOO: &str = "<'ñ'>\"a\tb\"\\ &/";
+
+    fn inner(mut fmt: Formatter<'_>) {
+        append_str!(fmt,";;";
+            fmt.write_str_escaped(FOO, Escape::Json).unwrap();
+            fmt.write_str_escaped(FOO, Escape::C).unwrap();
+            fmt.write_str_escaped(FOO, Escape::Html).unwrap();
+            fmt.write_str_escaped(FOO, Escape::Shell).unwrap();
+            fmt.write_str_escaped(FOO, Escape::Percent).unwrap();
+            fmt.write_str_range_escaped(FOO, 2..4, Escape::Percent).unwrap();
+            fmt.write_str_range_escaped(FOO, 9..100, Escape::Json).unwrap();
+        );
+    }
+
+    let expected = [
+        str_escape!(Escape::Json, FOO),
+        str_escape!(Escape::C, FOO),
+        str_escape!(Escape::Html, FOO),
+        str_escape!(Escape::Shell, FOO),
+        str_escape!(Escape::Percent, FOO),
+        "%C3%B1",
+        "b\\\"\\\\ &/",
+        "",
+    ]
+    .join(";;");
+
+    write_with_flag(FormattingFlags::NEW, &expected, &inner);
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 fn remove_margin(s: &str) -> String {
@@ -1,7 +1,7 @@
 use super::{remove_margin, write_with_flag};
 
 use cfmt_a::{
-    fmt::{Error, Formatter, FormattingFlags},
+    fmt::{ComputeStrLength, DebugMap, Error, Formatter, FormattingFlags},
     impl_fmt, try_, PWrapper,
 };
 
@@ -327,3 +327,219 @@ fn formatting_set() {
         set.const_debug_fmt(&mut fmt).unwrap();
     })
 }
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct Map {
+    a: u32,
+    b: &'static [u32],
+    rec: Option<&'static Map>,
+}
+
+impl_fmt! {
+    impl Map;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut fmt = fmt.debug_map();
+        {
+            let mut fmt = fmt.str_entry("margin");
+            let margin = fmt.margin();
+            try_!(fmt.write_usize_display(margin));
+        }
+        try_!(fmt.key().write_u32_debug(self.a));
+        try_!(format_b_field(self.b, fmt.value()));
+        if let Some(x) = self.rec {
+            let mut entry = fmt.entry();
+            try_!(format_b_field(self.b, entry.key()));
+            try_!(x.const_debug_fmt(entry.value()));
+        }
+        fmt.finish()
+    }
+}
+
+#[test]
+fn formatting_map() {
+    let expected = remove_margin(
+        "
+        {
+            \"margin\": 4,
+            3: [
+                0x9,
+                0xC,
+                0xF,
+            ],
+            [
+                0x9,
+                0xC,
+                0xF,
+            ]: {
+                \"margin\": 8,
+                8: [A, E, 12],
+                [A, E, 12]: {
+                    \"margin\": 12,
+                    21: [
+                        0xF,
+                        0x14,
+                        0x19,
+                    ],
+                },
+            },
+        }\
+    ",
+    );
+
+    let map = Map {
+        a: 3,
+        b: &[9, 12, 15],
+        rec: Some(&Map {
+            a: 8,
+            b: &[10, 14, 18],
+            rec: Some(&Map {
+                a: 21,
+                b: &[15, 20, 25],
+                rec: None,
+            }),
+        }),
+    };
+
+    let flags = FormattingFlags::NEW.set_alternate(true);
+
+    write_with_flag(flags, &expected, &|mut fmt| {
+        map.const_debug_fmt(&mut fmt).unwrap();
+    });
+
+    write_with_flag(
+        FormattingFlags::NEW,
+        "{\"foo\": 3, 5: [8, 13], \"bar\": \"baz\"}",
+        &|mut fmt| {
+            let mut fmt = fmt.debug_map();
+            fmt.str_entry("foo").write_u32_debug(3).unwrap();
+            fmt.key().write_u32_debug(5).unwrap();
+            PWrapper(&[8u32, 13][..])
+                .const_debug_fmt(fmt.value())
+                .unwrap();
+            fmt.str_entry("bar").write_str_debug("baz").unwrap();
+            fmt.finish().unwrap();
+        },
+    );
+
+    write_with_flag(FormattingFlags::NEW, "{}", &|mut fmt| {
+        fmt.debug_map().finish().unwrap();
+    });
+}
+
+#[test]
+fn map_entry_order_errors() {
+    fn assert_invalid(write_entries: &dyn Fn(&mut DebugMap<'_, '_>)) {
+        let mut len = 0;
+        let mut buffer = [0; 64];
+        let mut fmt = Formatter::from_custom(&mut buffer, &mut len, FormattingFlags::NEW);
+        let mut map = fmt.debug_map();
+        write_entries(&mut map);
+        assert_eq!(map.finish(), Err(Error::InvalidMapEntry));
+
+        let mut compute = ComputeStrLength::new();
+        let mut fmt = compute.make_formatter(FormattingFlags::NEW);
+        let mut map = fmt.debug_map();
+        write_entries(&mut map);
+        assert_eq!(map.finish(), Err(Error::InvalidMapEntry));
+    }
+
+    // value without a key
+    assert_invalid(&|map| {
+        map.value().write_u32_debug(3).unwrap();
+    });
+    assert_invalid(&|map| {
+        map.entry().value().write_u32_debug(3).unwrap();
+    });
+
+    // key without a value
+    assert_invalid(&|map| {
+        map.key().write_u32_debug(3).unwrap();
+    });
+    assert_invalid(&|map| {
+        map.str_entry("foo").write_u32_debug(3).unwrap();
+        map.entry().key().write_u32_debug(5).unwrap();
+    });
+
+    // two keys in a row
+    assert_invalid(&|map| {
+        map.key().write_u32_debug(3).unwrap();
+        map.key().write_u32_debug(5).unwrap();
+        map.value().write_u32_debug(8).unwrap();
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct NonExhaustive {
+    a: u32,
+    rec: Option<&'static NonExhaustive>,
+}
+
+impl_fmt! {
+    impl NonExhaustive;
+
+    pub const fn const_debug_fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        {
+            let mut fmt = fmt.debug_struct("Struct");
+            try_!(fmt.field("a").write_u32_debug(self.a));
+            if let Some(x) = self.rec {
+                try_!(x.const_debug_fmt(fmt.field("rec")));
+            }
+            try_!(fmt.finish_non_exhaustive());
+        }
+        try_!(fmt.write_str(" "));
+        {
+            let mut fmt = fmt.debug_tuple("Tuple");
+            try_!(fmt.field().write_u32_debug(self.a));
+            try_!(fmt.finish_non_exhaustive());
+        }
+        try_!(fmt.write_str(" "));
+        try_!(fmt.debug_struct("EmptyStruct").finish_non_exhaustive());
+        try_!(fmt.write_str(" "));
+        fmt.debug_tuple("EmptyTuple").finish_non_exhaustive()
+    }
+}
+
+#[test]
+fn formatting_non_exhaustive() {
+    let value = NonExhaustive {
+        a: 3,
+        rec: Some(&NonExhaustive { a: 5, rec: None }),
+    };
+
+    let expected = remove_margin(
+        "
+        Struct {
+            a: 3,
+            rec: Struct {
+                a: 5,
+                ..
+            } Tuple(
+                5,
+                ..
+            ) EmptyStruct { .. } EmptyTuple(..),
+            ..
+        } Tuple(
+            3,
+            ..
+        ) EmptyStruct { .. } EmptyTuple(..)\
+    ",
+    );
+
+    let flags = FormattingFlags::NEW.set_alternate(true);
+
+    write_with_flag(flags, &expected, &|mut fmt| {
+        value.const_debug_fmt(&mut fmt).unwrap();
+    });
+
+    write_with_flag(
+        FormattingFlags::NEW,
+        "Struct { a: 3, rec: Struct { a: 5, .. } Tuple(5, ..) EmptyStruct { .. } \
+         EmptyTuple(..), .. } Tuple(3, ..) EmptyStruct { .. } EmptyTuple(..)",
+        &|mut fmt| {
+            value.const_debug_fmt(&mut fmt).unwrap();
+        },
+    );
+}
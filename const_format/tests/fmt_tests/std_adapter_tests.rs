@@ -0,0 +1,255 @@
+use cfmt_a::{
+    fmt::{Error, Formatter, FormattingFlags, StdAdapter, StrWriter, StrWriterMut},
+    impl_fmt, try_, Escape, PWrapper,
+};
+
+use core::fmt::Write;
+
+////////////////////////////////////////////////////////////////////////////////
+
+struct Many<'a> {
+    strs: &'a [&'a str],
+    floats: &'a [f64],
+    ints: &'a [u128],
+}
+
+impl_fmt! {
+    impl['a] Many<'a>;
+
+    const fn const_debug_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut f = f.debug_struct("Many");
+        try_!(PWrapper(self.strs).const_debug_fmt(f.field("strs")));
+        try_!(PWrapper(self.floats).const_debug_fmt(f.field("floats")));
+        try_!(PWrapper(self.ints).const_debug_fmt(f.field("ints")));
+        f.finish()
+    }
+
+    const fn const_display_fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        let mut i = 0;
+        while i < self.strs.len() {
+            try_!(PWrapper(self.strs[i]).const_display_fmt(f));
+            i += 1;
+        }
+        let mut i = 0;
+        while i < self.floats.len() {
+            try_!(PWrapper(self.floats[i]).const_display_fmt(f));
+            i += 1;
+        }
+        Ok(())
+    }
+}
+
+/// Formats `value` with `fmt` into a buffer that's large enough to not need chunking.
+fn const_fmt<T: ?Sized>(
+    value: &T,
+    fmt: fn(&T, &mut Formatter<'_>) -> Result<(), Error>,
+    flags: FormattingFlags,
+) -> String {
+    let mut len = 0;
+    let mut buffer = vec![0u8; 1 << 16];
+    fmt(
+        value,
+        &mut Formatter::from_custom(&mut buffer, &mut len, flags),
+    )
+    .unwrap();
+    String::from_utf8(buffer[..len].to_vec()).unwrap()
+}
+
+const LONG_STRS: &[&str] = &[
+    "hello",
+    "\"quoted\"\t\n\r\\\0 and \u{7F}\u{80}\u{200B}",
+    "ñ日本語🧡",
+    "000000000000000000000000000000000000000000000000000000000000000",
+];
+
+fn many_strs() -> Vec<&'static str> {
+    let mut strs = Vec::new();
+    for i in 0..300 {
+        strs.push(LONG_STRS[i % LONG_STRS.len()]);
+    }
+    strs
+}
+
+#[test]
+fn chunked_matches_unchunked() {
+    let strs = many_strs();
+    let floats = [
+        1.5,
+        -0.0,
+        f64::MAX,
+        f64::MIN_POSITIVE,
+        5e-324,
+        f64::NAN,
+        1e21,
+    ];
+    let ints = [0, 255, u128::MAX];
+    let many = Many {
+        strs: &strs,
+        floats: &floats,
+        ints: &ints,
+    };
+
+    let debug = StdAdapter::new(&many, Many::const_debug_fmt);
+    let display = StdAdapter::new(&many, Many::const_display_fmt);
+
+    let reg = FormattingFlags::NEW;
+    let alt = FormattingFlags::NEW.set_alternate(true);
+
+    let reg_debug = format!("{:?}", debug);
+    let alt_debug = format!("{:#?}", debug);
+    assert!(reg_debug.len() > 8192, "{}", reg_debug.len());
+    assert_eq!(reg_debug, const_fmt(&many, Many::const_debug_fmt, reg));
+    assert_eq!(alt_debug, const_fmt(&many, Many::const_debug_fmt, alt));
+
+    assert_eq!(
+        format!("{}", display),
+        const_fmt(&many, Many::const_display_fmt, reg)
+    );
+
+    assert_eq!(
+        format!("{:?}", strs),
+        reg_debug[13..].split(", floats").next().unwrap()
+    );
+}
+
+#[test]
+fn long_pieces() {
+    let s = "ñ".repeat(3000);
+    let adapter = StdAdapter::new(&*s, |s, f| PWrapper(s).const_display_fmt(f));
+    assert_eq!(format!("{}", adapter), s);
+    assert_eq!(
+        format!("{:?}", StdAdapter::new(&*s, |s, f| f.write_str_debug(s))),
+        format!("{:?}", s)
+    );
+
+    for fill in ['-', 'ñ', '🧡'] {
+        let flags = FormattingFlags::NEW.set_fill(fill).set_width(3000);
+        let ours = const_fmt(&"foo", |s, f| PWrapper(*s).const_display_fmt(f), flags);
+        let adapter = StdAdapter::new(&"foo", |s, f| PWrapper(*s).const_display_fmt(f));
+        assert_eq!(format!("{:1$}", adapter, 3000).len(), 3000);
+        match fill {
+            '-' => assert_eq!(format!("{:-<3000}", adapter), ours),
+            'ñ' => assert_eq!(format!("{:ñ<3000}", adapter), ours),
+            _ => assert_eq!(format!("{:🧡<3000}", adapter), ours),
+        }
+    }
+
+    let float = StdAdapter::new(&1.5f64, |n, f| PWrapper(*n).const_display_fmt(f));
+    assert_eq!(format!("{:.2000}", float), format!("{:.2000}", 1.5f64));
+    assert_eq!(format!("{:+05000}", float), format!("{:+05000}", 1.5f64));
+
+    let margin = StdAdapter::new(&(), |_, f| f.write_ascii_repeated(b' ', 5000));
+    assert_eq!(format!("{}", margin), " ".repeat(5000));
+}
+
+#[test]
+fn escaped_output_longer_than_buffer() {
+    type EscapeFn = fn(&str, &mut Formatter<'_>) -> Result<(), Error>;
+
+    const ESCAPES: [EscapeFn; 5] = [
+        |s, f| f.write_str_escaped(s, Escape::Json),
+        |s, f| f.write_str_escaped(s, Escape::C),
+        |s, f| f.write_str_escaped(s, Escape::Html),
+        |s, f| f.write_str_escaped(s, Escape::Shell),
+        |s, f| f.write_str_escaped(s, Escape::Percent),
+    ];
+
+    let s = "ñ<a b='c'>🧡\n".repeat(300);
+    for escape in ESCAPES {
+        let expected = const_fmt(&*s, escape, FormattingFlags::NEW);
+        assert!(expected.len() > 4000);
+        assert_eq!(format!("{}", StdAdapter::new(&*s, escape)), expected);
+    }
+}
+
+#[test]
+fn std_flags() {
+    let int = StdAdapter::new(&-5i32, |n, f| PWrapper(*n).const_debug_fmt(f));
+    assert_eq!(format!("{:?}", int), "-5");
+    assert_eq!(format!("{:+?}", int), "-5");
+    assert_eq!(format!("{:>5?}", int), "   -5");
+    assert_eq!(format!("{:<5?}", int), "-5   ");
+    assert_eq!(format!("{:^5?}", int), " -5  ");
+    assert_eq!(format!("{:*^6?}", int), "**-5**");
+    assert_eq!(format!("{:05?}", int), "-0005");
+
+    let float = StdAdapter::new(&2.5f64, |n, f| PWrapper(*n).const_display_fmt(f));
+    assert_eq!(format!("{:+.3}", float), "+2.500");
+    assert_eq!(format!("{:8.1}", float), "     2.5");
+
+    let list = StdAdapter::new(&[3u8, 5][..], |s, f| PWrapper(s).const_debug_fmt(f));
+    assert_eq!(format!("{:?}", list), "[3, 5]");
+    assert_eq!(format!("{:#?}", list), "[\n    3,\n    5,\n]");
+}
+
+#[test]
+fn errors_are_propagated() {
+    let err = StdAdapter::new(&(), |_, _| Err(Error::NotEnoughSpace));
+    assert!(write!(String::new(), "{}", err).is_err());
+
+    let not_on_boundary = StdAdapter::new(&"ñ", |s, f| f.write_str_range(s, 1..2));
+    assert!(write!(String::new(), "{}", not_on_boundary).is_err());
+}
+
+#[test]
+fn output_changing_between_calls_is_an_error() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static SHORT_CALLS: AtomicUsize = AtomicUsize::new(0);
+    static GROWING_CALLS: AtomicUsize = AtomicUsize::new(0);
+    static SHRINKING_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    // fits in the buffer, writes 10 bytes when computing the length and 11 after that
+    let short = StdAdapter::new(&SHORT_CALLS, |calls, f| {
+        let n = 10 + calls.fetch_add(1, Ordering::Relaxed).min(1);
+        f.write_ascii_repeated(b'a', n)
+    });
+    assert!(write!(String::new(), "{}", short).is_err());
+
+    // needs chunking, with output that grows on every call
+    let growing = StdAdapter::new(&GROWING_CALLS, |calls, f| {
+        let n = 1500 + 1000 * calls.fetch_add(1, Ordering::Relaxed);
+        f.write_ascii_repeated(b'a', n)
+    });
+    assert!(write!(String::new(), "{}", growing).is_err());
+
+    // needs chunking, with output that's shorter after the length is computed
+    let shrinking = StdAdapter::new(&SHRINKING_CALLS, |calls, f| {
+        let n = if calls.fetch_add(1, Ordering::Relaxed) == 0 {
+            3000
+        } else {
+            1500
+        };
+        f.write_ascii_repeated(b'a', n)
+    });
+    assert!(write!(String::new(), "{}", shrinking).is_err());
+}
+
+#[test]
+fn core_fmt_write_for_str_writer_mut() {
+    let mut len = 0;
+    let mut buffer = [0; 15];
+    let mut writer = StrWriterMut::from_custom_cleared(&mut buffer, &mut len);
+
+    let (c, s) = ('ñ', "a\n");
+    write!(writer, "{}{:?}", c, s).unwrap();
+    assert_eq!(writer.as_str(), "ñ\"a\\n\"");
+
+    writer.write_char('🧡').unwrap();
+    assert_eq!(writer.as_str(), "ñ\"a\\n\"🧡");
+
+    assert!(writer.write_str("0123456").is_err());
+    writer.write_char('🧡').unwrap();
+    assert!(writer.write_char('a').is_err());
+    assert_eq!(writer.as_str(), "ñ\"a\\n\"🧡🧡");
+}
+
+#[test]
+fn display_for_str_writer() {
+    let mut writer = StrWriter::new([0; 16]);
+    writer.as_mut().write_str("hello").unwrap();
+
+    assert_eq!(format!("{}", writer), "hello");
+    assert_eq!(format!("{:>7}", writer.r()), "  hello");
+    assert_eq!(writer.to_string(), "hello");
+}
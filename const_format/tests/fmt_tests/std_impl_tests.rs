@@ -74,10 +74,10 @@ fn array_impls() {
     test_fmt! {&[char];
         (
             ['f', 'o', '\n', '\t', 'ñ', '个', '\u{100000}'],
-            "['f', 'o', '\\n', '\\t', 'ñ', '个', '\u{100000}']",
+            "['f', 'o', '\\n', '\\t', 'ñ', '个', '\\u{100000}']",
             "[\n    'f',\n    'o',\n    '\\n',\
               \n    '\\t',\n    'ñ',\n    '个',\
-              \n    '\u{100000}',\n\
+              \n    '\\u{100000}',\n\
             ]"
         )
     }
@@ -111,7 +111,7 @@ fn options() {
     test_fmt! {Option<char>;
         (None::<char>, "None", "None")
         (Some('4'), "Some('4')", "Some(\n    '4',\n)")
-        (Some('\x00'), "Some('\\x00')", "Some(\n    '\\x00',\n)")
+        (Some('\x00'), "Some('\\0')", "Some(\n    '\\0',\n)")
     }
     test_fmt! {Option<NonZeroU8>;
         (None::<NonZeroU8>, "None", "None")
@@ -158,14 +158,19 @@ fn chars() {
         char;
         ('\\', r#"'\\'"#, r#"'\\'"#)
         ('\'', r#"'\''"#, r#"'\''"#)
-        ('\"', r#"'\"'"#, r#"'\"'"#)
+        ('\"', r#"'"'"#, r#"'"'"#)
         ('\n', r#"'\n'"#, r#"'\n'"#)
         ('\r', r#"'\r'"#, r#"'\r'"#)
         ('\t', r#"'\t'"#, r#"'\t'"#)
         ('o', r#"'o'"#, r#"'o'"#)
         ('ñ', r#"'ñ'"#, r#"'ñ'"#)
         ('个', r#"'个'"#, r#"'个'"#)
-        // non-ascii characters are always written unescaped
-        ('\u{100000}', "'\u{100000}'", "'\u{100000}'")
+        ('\x00', r#"'\0'"#, r#"'\0'"#)
+        ('\x1F', r#"'\u{1f}'"#, r#"'\u{1f}'"#)
+        ('\x7F', r#"'\u{7f}'"#, r#"'\u{7f}'"#)
+        // non-printable characters and grapheme extenders are escaped like std does
+        ('\u{301}', r#"'\u{301}'"#, r#"'\u{301}'"#)
+        ('\u{200B}', r#"'\u{200b}'"#, r#"'\u{200b}'"#)
+        ('\u{100000}', r#"'\u{100000}'"#, r#"'\u{100000}'"#)
     }
 }
@@ -6,6 +6,7 @@ use cfmt_a::{
     test_utils::{ALL_ASCII, ALL_ASCII_ESCAPED},
     utils::saturate_range,
     wrapper_types::{AsciiStr, PWrapper},
+    Escape,
 };
 
 use arrayvec::ArrayString;
@@ -132,10 +133,30 @@ fn test_write_ints() {
 fn basic() {
     assert_eq!(
         formatcp!("{:?}", r#" !Aq¡\"🧡🧠₀₁ "#),
-        r#"" !Aq¡\\\"🧡🧠₀₁ ""#
+        r#"" !Aq¡\\\"\u{7f}\u{80}🧡🧠₀₁ ""#
     );
 }
 
+#[test]
+fn write_str_debug_matches_std() {
+    let rng = Rng::with_seed(5148612375319427154);
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 256]);
+
+    for _ in 0..4096 {
+        let mut input = ArrayString::<32>::new();
+        while input.try_push(rng.unicode_char()).is_ok() {}
+
+        writer.clear();
+        writer.as_mut().write_str_debug(&input).unwrap();
+        assert_eq!(writer.as_str(), format!("{:?}", input.as_str()));
+
+        let c = rng.unicode_char();
+        writer.clear();
+        writer.as_mut().write_char_debug(c).unwrap();
+        assert_eq!(writer.as_str(), format!("{:?}", c));
+    }
+}
+
 #[test]
 fn saturate_range_tests() {
     let all_ascii = ALL_ASCII.as_bytes();
@@ -275,7 +296,7 @@ fn write_ascii() {
 }
 
 fn is_it_escaped(c: char) -> bool {
-    matches!(c, '\0'..='\u{1F}' | '\\' | '"' | '\'')
+    c != '\'' && c.escape_debug().nth(1).is_some()
 }
 
 #[test]
@@ -316,7 +337,7 @@ fn write_str_debug() {
 
         writer.truncate(snapshot).unwrap();
         writer.write_ascii_debug(all_ascii).unwrap();
-        let end = ALL_ASCII_ESCAPED.find('\u{80}').unwrap();
+        let end = ALL_ASCII_ESCAPED.find(r"\u{80}").unwrap();
 
         let bytes = writer.as_bytes();
         assert_eq!(bytes[0], b'"');
@@ -412,11 +433,14 @@ fn returns_error() {
 
     test_case!("foo\nb", 1);
     test_case!("foo\"ba", 1);
-    test_case!("foo\'bar", 1);
+    test_case!("foo\'bar", 0);
     test_case!("foo\rbarb", 1);
     test_case!("foo\\barba", 1);
-    test_case!("foo\u{5}bar", 3);
-    test_case!("foo\u{11}bar", 3);
+    test_case!("foo\u{5}bar", 4);
+    test_case!("foo\u{11}bar", 5);
+    test_case!("foo\u{7F}bar", 5);
+    test_case!("foo\u{200B}bar", 5);
+    test_case!("foo\u{E0100}bar", 5);
 }
 
 #[test]
@@ -570,3 +594,52 @@ fn write_ascii_debug() {
         p.writer.write_ascii_debug(ascii)
     });
 }
+
+#[test]
+fn write_str_escaped() {
+    const S: &str = "a\"ñ\n'<b>/";
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 64]);
+
+    for escape in [
+        Escape::Json,
+        Escape::C,
+        Escape::Html,
+        Escape::Shell,
+        Escape::Percent,
+    ] {
+        writer.clear();
+        writer.as_mut().write_str_escaped(S, escape).unwrap();
+        writer
+            .as_mut()
+            .write_str_range_escaped(S, 2..4, escape)
+            .unwrap();
+
+        let expected = match escape {
+            Escape::Json => r#"a\"ñ\n'<b>/ñ"#,
+            Escape::C => r#"a\"\303\261\n'<b>/\303\261"#,
+            Escape::Html => "a&quot;ñ\n&#39;&lt;b&gt;/ñ",
+            Escape::Shell => "a\"ñ\n'\\''<b>/ñ",
+            Escape::Percent => "a%22%C3%B1%0A%27%3Cb%3E%2F%C3%B1",
+            _ => unimplemented!("{:?}", escape),
+        };
+        assert_eq!(writer.as_str(), expected);
+    }
+
+    writer.clear();
+    let res = writer.as_mut().write_str_range_escaped(S, 3..4, Escape::C);
+    assert_eq!(res, Err(Error::NotOnCharBoundary));
+    assert_eq!(writer.as_str(), "");
+
+    // "a%22" fits, but "%C3%B1" doesn't
+    let small: &mut StrWriter = &mut StrWriter::new([0; 7]);
+    let res = small.as_mut().write_str_escaped("a\"ñ", Escape::Percent);
+    assert_eq!(res, Err(Error::NotEnoughSpace));
+    assert_eq!(small.as_str(), "");
+
+    small
+        .as_mut()
+        .write_str_escaped("a'", Escape::Shell)
+        .unwrap();
+    assert_eq!(small.as_str(), "a'\\''");
+}
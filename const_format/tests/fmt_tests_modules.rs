@@ -20,6 +20,9 @@ mod fmt_tests {
     #[cfg(not(feature = "__only_new_tests"))]
     mod formatter_methods;
 
+    #[cfg(not(feature = "__only_new_tests"))]
+    mod std_adapter_tests;
+
     #[cfg(not(feature = "__only_new_tests"))]
     mod std_impl_tests;
 
@@ -2,7 +2,9 @@
 #![allow(non_local_definitions)]
 
 use cfmt_b::for_examples::{Point3, Unit};
-use cfmt_b::{assertc, assertc_eq, assertc_ne, call_debug_fmt};
+use cfmt_b::{
+    assertc, assertc_eq, assertc_ge, assertc_gt, assertc_le, assertc_lt, assertc_ne, call_debug_fmt,
+};
 
 struct Foo;
 
@@ -101,4 +103,26 @@ const _: () = {
         loop {}
         f.write_str("hello")
     });
+
+    ////////////////////////////////////////////////////////////////////////////////
+    ////        assertc_lt, assertc_le, assertc_gt, assertc_ge
+
+    assertc_lt!(0u8, 3u8);
+    assertc_lt!("bar", "foo", "hello");
+    assertc_lt!(&[3u8, 5] as &[u8], &[3u8, 5, 8] as &[u8], "hello {}", {
+        let x: u32 = loop {};
+        x
+    });
+
+    assertc_le!(0u8, 0u8);
+    assertc_le!(["foo"], ["foo"], "{}", |f| {
+        loop {}
+        f.write_str("hello")
+    });
+
+    assertc_gt!('b', 'a');
+    assertc_gt!([true, false], [false, true], "hello");
+
+    assertc_ge!(-3i32, -3i32);
+    assertc_ge!("foo", "bar", "hello");
 };
@@ -1,7 +1,10 @@
 #![allow(unreachable_code)]
 #![allow(non_local_definitions)]
 
-use cfmt_b::{assertcp, assertcp_eq, assertcp_ne};
+use cfmt_b::{
+    assertcp, assertcp_case, assertcp_eq, assertcp_ge, assertcp_gt, assertcp_le, assertcp_lt,
+    assertcp_ne, Case, WordCase,
+};
 
 struct Foo;
 
@@ -110,4 +113,43 @@ const _: () = {
             foo
         },
     );
+
+    ////////////////////////////////////////////////////////////////////////////////
+    ////        assertcp_lt, assertcp_le, assertcp_gt, assertcp_ge
+
+    assertcp_lt!(0u8, 1u8);
+    assertcp_lt!(-1i8, 0i8, "world");
+    assertcp_lt!("hello", "help", "world{}", {
+        let x: u32 = loop {};
+        x
+    });
+    assertcp_lt!(false, true);
+
+    assertcp_le!(0u8, 0u8);
+    assertcp_le!('a', 'b', "world{foo}", foo = 1u8);
+    assertcp_le!("", "", "world{X}");
+
+    assertcp_gt!(X, 0u8);
+    assertcp_gt!("helo", "hello", "world");
+    assertcp_gt!('b', 'a');
+
+    assertcp_ge!(u128::MAX, u128::MAX);
+    assertcp_ge!(true, false, "world{}", 1u8);
+    assertcp_ge!("b", "abc");
+
+    ////////////////////////////////////////////////////////////////////////////////
+    ////        assertcp_case
+
+    assertcp_case!(Case::Snake, "foo_bar_100");
+    assertcp_case!(Case::UpperSnake, "FOO_BAR", "world");
+    assertcp_case!(Case::Kebab, "foo-bar", "world{}", {
+        let x: u32 = loop {};
+        x
+    });
+    assertcp_case!(Case::Pascal, "FooBar", "world{X}");
+    assertcp_case!(Case::Camel, "fooBar", "world{foo}", foo = 1u8);
+    assertcp_case!(Case::Lower, "foo bar!");
+    assertcp_case!(Case::Title, "Foo Bar");
+    assertcp_case!(Case::custom("::", WordCase::Capitalized), "Foo::Bar");
+    assertcp_case!(Case::Flat, "");
 };
@@ -0,0 +1,80 @@
+use cfmt_b::{concatbytes, concatcp, formatbytes, formatcp};
+
+#[test]
+fn concatbytes_test() {
+    const EMPTY: &[u8; 0] = concatbytes!();
+    assert_eq!(EMPTY, b"");
+
+    const SLICE: &[u8] = b"\xFFbar";
+    const ARR: [u8; 3] = *b"baz";
+
+    assert_eq!(concatbytes!(b"foo"), b"foo");
+    assert_eq!(concatbytes!(b"foo", SLICE, &ARR,), b"foo\xFFbarbaz");
+    assert_eq!(concatbytes!(0, 255, 0x7F, 1u8 + 1), b"\0\xFF\x7F\x02");
+
+    // the types that concatcp takes are written the same way.
+    assert_eq!(
+        concatbytes!("foo", 'ñ', true, 100u16, -3i8, 0.5),
+        concatcp!("foo", 'ñ', true, 100u16, -3i8, 0.5).as_bytes(),
+    );
+
+    const SIZED: [u8; 6] = *concatbytes!(b"ab", 3u8, "c", 10u32);
+    assert_eq!(SIZED, *b"ab\x03c10");
+}
+
+#[test]
+fn formatbytes_test() {
+    const EMPTY: &[u8] = formatbytes!("");
+    assert_eq!(EMPTY, b"");
+
+    const SLICE: &[u8] = b"\xDE\xAD";
+
+    assert_eq!(formatbytes!("{SLICE}{0}{0}", b"\n"), b"\xDE\xAD\n\n");
+    assert_eq!(formatbytes!("{}|{}", 0, 0xFF), b"\0|\xFF");
+    assert_eq!(
+        formatbytes!("{0:?}|{0:x}|{0:#b}|{0:>4}", 190u8),
+        b"190|be|0b10111110|   \xBE",
+    );
+
+    // width, fill, and alignment count bytes
+    assert_eq!(
+        formatbytes!("[{:<4}|{:-^5}|{:>3}]", b"\xF0\x9F", b"a", 0xF0),
+        b"[\xF0\x9F  |--a--|  \xF0]",
+    );
+
+    // precision truncates to that many bytes
+    assert_eq!(
+        formatbytes!(
+            "[{:.2}|{:.0}|{:.10}|{:.0}]",
+            b"\xF0\x9F\x91\x80",
+            b"abc",
+            b"ab",
+            7
+        ),
+        b"[\xF0\x9F||ab|]",
+    );
+
+    // the types that formatcp takes are written the same way.
+    assert_eq!(
+        formatbytes!(
+            "{:?}{:5}{:+}{:#x}{:.2}",
+            "ñ\"",
+            'c',
+            3i8,
+            255u16,
+            2.0f64 / 3.0
+        ),
+        formatcp!(
+            "{:?}{:5}{:+}{:#x}{:.2}",
+            "ñ\"",
+            'c',
+            3i8,
+            255u16,
+            2.0f64 / 3.0
+        )
+        .as_bytes(),
+    );
+
+    const SIZED: [u8; 5] = *formatbytes!("{}-{:>2}", b"ab", 1u8 + 2);
+    assert_eq!(SIZED, *b"ab- \x03");
+}
@@ -0,0 +1,60 @@
+use cfmt_b::{concatcp, concatcp_cap, formatcp, formatcp_cap};
+
+trait Named {
+    const NAME: &'static str;
+    const WIDTH: usize;
+    const NUMBER: u32;
+}
+
+struct Foo;
+
+impl Named for Foo {
+    const NAME: &'static str = "foo";
+    const WIDTH: usize = 5;
+    const NUMBER: u32 = 255;
+}
+
+struct Wrapper<T>(T);
+
+impl<T: Named> Wrapper<T> {
+    const CONCAT: &'static str = concatcp_cap!(64; T::NAME, '_', T::NUMBER, 0.5, true,);
+    const CONCAT_EXACT: &'static str = concatcp_cap!(6; T::NAME, "_id");
+    const CONCAT_EMPTY: &'static str = concatcp_cap!(0;);
+
+    const FORMAT: &'static str =
+        formatcp_cap!(64; "{0}-{0:?}-{1:#x}-{:>w$}", T::NAME, T::NUMBER, w = T::WIDTH);
+    const FORMAT_EXACT: &'static str = formatcp_cap!(5; "{}{:2}", T::NAME, 1u8);
+}
+
+#[test]
+fn concatcp_cap_test() {
+    assert_eq!(Wrapper::<Foo>::CONCAT, "foo_2550.5true");
+    assert_eq!(
+        Wrapper::<Foo>::CONCAT,
+        concatcp!(Foo::NAME, '_', Foo::NUMBER, 0.5, true)
+    );
+    assert_eq!(Wrapper::<Foo>::CONCAT_EXACT, "foo_id");
+    assert_eq!(Wrapper::<Foo>::CONCAT_EMPTY, "");
+
+    const NON_GENERIC: &str = concatcp_cap!(16; "ñ", 'ó', 3u8);
+    assert_eq!(NON_GENERIC, "ñó3");
+}
+
+#[test]
+fn formatcp_cap_test() {
+    assert_eq!(Wrapper::<Foo>::FORMAT, r#"foo-"foo"-0xff-  foo"#);
+    assert_eq!(
+        Wrapper::<Foo>::FORMAT,
+        formatcp!(
+            "{0}-{0:?}-{1:#x}-{:>w$}",
+            Foo::NAME,
+            Foo::NUMBER,
+            w = Foo::WIDTH
+        )
+    );
+    assert_eq!(Wrapper::<Foo>::FORMAT_EXACT, "foo 1");
+
+    const NAME: &str = "bar";
+    const IMPLICIT: &str = formatcp_cap!(16; "{NAME}{NAME:?}");
+    assert_eq!(IMPLICIT, r#"bar"bar""#);
+}
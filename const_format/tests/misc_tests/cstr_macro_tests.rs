@@ -0,0 +1,38 @@
+use cfmt_b::{concatcp, concatcp_cstr, formatcp, formatcp_cstr};
+
+use core::ffi::CStr;
+
+#[test]
+fn concatcp_cstr_test() {
+    const EMPTY: &CStr = concatcp_cstr!();
+    assert_eq!(EMPTY.to_bytes_with_nul(), b"\0");
+
+    const NAME: &str = "foo";
+
+    const CSTR: &CStr = concatcp_cstr!(NAME, '_', 10u8, true, -0.5);
+    assert_eq!(CSTR.to_bytes_with_nul(), b"foo_10true-0.5\0");
+    assert_eq!(
+        CSTR.to_bytes(),
+        concatcp!(NAME, '_', 10u8, true, -0.5).as_bytes()
+    );
+
+    assert_eq!(concatcp_cstr!("ñ", "👀",).to_str(), Ok("ñ👀"));
+}
+
+#[test]
+fn formatcp_cstr_test() {
+    const EMPTY: &CStr = formatcp_cstr!("");
+    assert_eq!(EMPTY.to_bytes_with_nul(), b"\0");
+
+    const NAME: &str = "foo";
+
+    const CSTR: &CStr = formatcp_cstr!("{NAME}{:?}{:>4}{:#x}", "\n", 'c', 255u8);
+    assert_eq!(CSTR.to_bytes_with_nul(), b"foo\"\\n\"   c0xff\0");
+    assert_eq!(
+        CSTR.to_bytes(),
+        formatcp!("{NAME}{:?}{:>4}{:#x}", "\n", 'c', 255u8).as_bytes()
+    );
+
+    // Debug formatting escapes nul bytes, so they're allowed.
+    assert_eq!(formatcp_cstr!("{:?}", "\0").to_bytes(), b"\"\\0\"");
+}
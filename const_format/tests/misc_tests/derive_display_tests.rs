@@ -0,0 +1,125 @@
+use cfmt_b::{formatc, ConstDebug, ConstDisplay};
+
+use core::marker::PhantomData;
+
+///////////////////////////////////////////////////////////////////////////////
+
+const SEP: &str = " - ";
+
+#[derive(ConstDisplay)]
+#[cdisplay(crate = "::cfmt_b", format_marker)]
+#[cdisplay("{x}{SEP}{y:?}{SEP}{z:>width$}|")]
+struct Braced {
+    x: u32,
+    y: &'static str,
+    z: u8,
+    width: usize,
+    #[allow(dead_code)]
+    unused: (u32, u32),
+}
+
+#[derive(ConstDisplay)]
+#[cdisplay(crate = "::cfmt_b", format_marker)]
+#[cdisplay("({1}, {0:x}, {}, {}, {2:#b})")]
+struct Tupled(u32, u32, u32);
+
+#[derive(ConstDisplay)]
+#[cdisplay(crate = "::cfmt_b", format_marker, "unit")]
+struct Unit;
+
+#[test]
+fn struct_formatting() {
+    const BRACED: &str = formatc!(
+        "{}",
+        Braced {
+            x: 3,
+            y: "foo\n",
+            z: 5,
+            width: 4,
+            unused: (8, 13),
+        }
+    );
+    assert_eq!(BRACED, "3 - \"foo\\n\" -    5|");
+
+    const TUPLED: &str = formatc!("{}", Tupled(21, 34, 5));
+    assert_eq!(TUPLED, "(34, 15, 21, 34, 0b101)");
+
+    const UNIT: &str = formatc!("[{}]", Unit);
+    assert_eq!(UNIT, "[unit]");
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDisplay)]
+#[cdisplay(crate = "::cfmt_b", format_marker)]
+enum Error {
+    #[cdisplay("invalid digit {digit:?} at {index}")]
+    InvalidDigit { digit: char, index: usize },
+    #[cdisplay("overflowed {0}-bit integer")]
+    Overflow(u8),
+    #[cdisplay("empty input")]
+    Empty,
+    #[cdisplay("{{escaped}} {0}")]
+    Nested(&'static Error),
+}
+
+#[test]
+fn enum_formatting() {
+    const INVALID: &str = formatc!(
+        "{}",
+        Error::InvalidDigit {
+            digit: 'x',
+            index: 3
+        }
+    );
+    const OVERFLOW: &str = formatc!("{}", Error::Overflow(16));
+    const EMPTY: &str = formatc!("{}", Error::Empty);
+    const NESTED: &str = formatc!("{}", Error::Nested(&Error::Nested(&Error::Empty)));
+
+    assert_eq!(INVALID, "invalid digit 'x' at 3");
+    assert_eq!(OVERFLOW, "overflowed 16-bit integer");
+    assert_eq!(EMPTY, "empty input");
+    assert_eq!(NESTED, "{escaped} {escaped} empty input");
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDisplay)]
+#[cdisplay(crate = "::cfmt_b", format_marker)]
+#[cdisplay(impls("<U> Generic<u32, U>", "<U> Generic<&'static str, U>"))]
+#[cdisplay("value={value}")]
+struct Generic<T, U> {
+    value: T,
+    _marker: PhantomData<U>,
+}
+
+#[derive(ConstDebug, ConstDisplay)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdisplay(crate = "::cfmt_b")]
+#[cdisplay("{major}.{minor}")]
+struct Version {
+    major: u16,
+    minor: u16,
+}
+
+#[test]
+fn impls_and_format_marker() {
+    const GEN_U32: Generic<u32, ()> = Generic {
+        value: 100,
+        _marker: PhantomData,
+    };
+    const GEN_STR: Generic<&str, bool> = Generic {
+        value: "hello",
+        _marker: PhantomData,
+    };
+    assert_eq!(formatc!("{} {}", GEN_U32, GEN_STR), "value=100 value=hello");
+
+    const VERSION: Version = Version {
+        major: 1,
+        minor: 57,
+    };
+    assert_eq!(
+        formatc!("{0} {0:?}", VERSION),
+        "1.57 Version { major: 1, minor: 57 }"
+    );
+}
@@ -0,0 +1,477 @@
+use cfmt_b::{assertc_eq, assertc_lt, assertc_ne, ConstCmp, ConstDebug, ConstEq};
+
+use core::{cmp::Ordering, marker::PhantomData};
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDebug, ConstEq)]
+#[cdeb(crate = "::cfmt_b")]
+#[ceq(crate = "::cfmt_b")]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+struct Braced {
+    num: u8,
+    text: &'static str,
+    array: [u16; 2],
+    slice: &'static [&'static str],
+    opt: Option<u32>,
+    point: Point,
+    points: &'static [Point],
+    opt_point: Option<Point>,
+}
+
+const BRACED: Braced = Braced {
+    num: 3,
+    text: "foo",
+    array: [5, 8],
+    slice: &["bar", "baz"],
+    opt: Some(13),
+    point: Point { x: 21, y: 34 },
+    points: &[Point { x: 1, y: 2 }, Point { x: 3, y: 4 }],
+    opt_point: None,
+};
+
+macro_rules! braced_with {
+    ($($field:ident: $value:expr),*) => {
+        Braced { $($field: $value,)* ..BRACED }
+    };
+}
+
+#[test]
+fn struct_eq() {
+    const ARE_EQ: [bool; 10] = [
+        BRACED.const_eq(&BRACED),
+        BRACED.const_eq(&braced_with!(num: 4)),
+        BRACED.const_eq(&braced_with!(text: "fo")),
+        BRACED.const_eq(&braced_with!(array: [5, 9])),
+        BRACED.const_eq(&braced_with!(slice: &["bar"])),
+        BRACED.const_eq(&braced_with!(opt: None)),
+        BRACED.const_eq(&braced_with!(point: Point { x: 21, y: 35 })),
+        BRACED.const_eq(&braced_with!(points: &[Point { x: 1, y: 2 }, Point { x: 0, y: 4 }])),
+        BRACED.const_eq(&braced_with!(opt_point: Some(Point { x: 0, y: 0 }))),
+        braced_with!(opt_point: Some(Point { x: 0, y: 0 }))
+            .const_eq(&braced_with!(opt_point: Some(Point { x: 0, y: 0 }))),
+    ];
+
+    assert_eq!(
+        ARE_EQ,
+        [true, false, false, false, false, false, false, false, false, true]
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+#[allow(dead_code)]
+struct Tupled(u32, #[ceq(ignore)] u32, &'static str);
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+struct Unit;
+
+#[test]
+fn tuple_and_unit_eq() {
+    const ARE_EQ: [bool; 4] = [
+        Tupled(3, 5, "foo").const_eq(&Tupled(3, 8, "foo")),
+        Tupled(3, 5, "foo").const_eq(&Tupled(4, 5, "foo")),
+        Tupled(3, 5, "foo").const_eq(&Tupled(3, 5, "bar")),
+        Unit.const_eq(&Unit),
+    ];
+
+    assert_eq!(ARE_EQ, [true, false, false, true]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDebug, ConstEq)]
+#[cdeb(crate = "::cfmt_b")]
+#[ceq(crate = "::cfmt_b")]
+enum Enum {
+    Braced { x: u32, y: &'static [u8] },
+    Tupled(Point, Option<&'static str>),
+    Unit,
+}
+
+#[test]
+fn enum_eq() {
+    const BRACED: Enum = Enum::Braced { x: 3, y: &[5, 8] };
+    const TUPLED: Enum = Enum::Tupled(Point { x: 3, y: 5 }, Some("foo"));
+
+    const ARE_EQ: [bool; 8] = [
+        BRACED.const_eq(&Enum::Braced { x: 3, y: &[5, 8] }),
+        BRACED.const_eq(&Enum::Braced {
+            x: 3,
+            y: &[5, 8, 13],
+        }),
+        TUPLED.const_eq(&Enum::Tupled(Point { x: 3, y: 5 }, Some("foo"))),
+        TUPLED.const_eq(&Enum::Tupled(Point { x: 3, y: 5 }, None)),
+        Enum::Unit.const_eq(&Enum::Unit),
+        BRACED.const_eq(&TUPLED),
+        TUPLED.const_eq(&Enum::Unit),
+        Enum::Unit.const_eq(&BRACED),
+    ];
+
+    assert_eq!(
+        ARE_EQ,
+        [true, false, true, false, true, false, false, false]
+    );
+}
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+enum Single {
+    Only(u8),
+}
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+#[allow(dead_code)]
+enum Empty {}
+
+#[test]
+fn single_variant_enum_eq() {
+    const ARE_EQ: [bool; 2] = [
+        Single::Only(3).const_eq(&Single::Only(3)),
+        Single::Only(3).const_eq(&Single::Only(5)),
+    ];
+
+    assert_eq!(ARE_EQ, [true, false]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+mod custom {
+    #[derive(Copy, Clone)]
+    pub struct Wrapper(pub u32);
+
+    pub struct ByParity<'a>(pub &'a u32);
+
+    impl ByParity<'_> {
+        pub const fn const_eq(&self, other: &Self) -> bool {
+            *self.0 % 2 == *other.0 % 2
+        }
+    }
+
+    pub const fn eq_len(left: &&str, right: &&str) -> bool {
+        left.len() == right.len()
+    }
+
+    macro_rules! eq_first {
+        ($left:expr, $right:expr) => {
+            $left[0] == $right[0]
+        };
+    }
+    pub(crate) use eq_first;
+}
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+struct Custom {
+    #[ceq(with = "custom::eq_len")]
+    text: &'static str,
+    #[ceq(with_wrapper = "custom::ByParity")]
+    parity: u32,
+    #[ceq(with_macro = "custom::eq_first")]
+    first: [u8; 3],
+    #[ceq(is_a(newtype))]
+    wrapper: custom::Wrapper,
+}
+
+#[test]
+fn custom_eq() {
+    use self::custom::Wrapper;
+
+    const CUSTOM: Custom = Custom {
+        text: "foo",
+        parity: 3,
+        first: [1, 2, 3],
+        wrapper: Wrapper(5),
+    };
+
+    const ARE_EQ: [bool; 6] = [
+        CUSTOM.const_eq(&Custom {
+            text: "bar",
+            parity: 5,
+            first: [1, 0, 0],
+            wrapper: Wrapper(5),
+        }),
+        CUSTOM.const_eq(&Custom {
+            text: "fo",
+            ..CUSTOM
+        }),
+        CUSTOM.const_eq(&Custom {
+            parity: 4,
+            ..CUSTOM
+        }),
+        CUSTOM.const_eq(&Custom {
+            first: [0, 2, 3],
+            ..CUSTOM
+        }),
+        CUSTOM.const_eq(&Custom {
+            wrapper: Wrapper(6),
+            ..CUSTOM
+        }),
+        CUSTOM.const_eq(&CUSTOM),
+    ];
+
+    assert_eq!(ARE_EQ, [true, false, false, false, false, true]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+struct Generic<'a, T> {
+    value: &'a [u8],
+    #[ceq(ignore)]
+    _marker: PhantomData<T>,
+}
+
+#[derive(ConstEq)]
+#[ceq(crate = "::cfmt_b")]
+#[ceq(impls("<T> Concrete<u8, T>", "<T> Concrete<&'static str, T>",))]
+struct Concrete<T, U> {
+    value: T,
+    #[ceq(ignore)]
+    _marker: PhantomData<U>,
+}
+
+#[test]
+fn generic_eq() {
+    const ARE_EQ: [bool; 6] = [
+        Generic::<u8> {
+            value: &[3],
+            _marker: PhantomData,
+        }
+        .const_eq(&Generic {
+            value: &[3],
+            _marker: PhantomData,
+        }),
+        Generic::<u8> {
+            value: &[3],
+            _marker: PhantomData,
+        }
+        .const_eq(&Generic {
+            value: &[5],
+            _marker: PhantomData,
+        }),
+        Concrete::<u8, ()> {
+            value: 3,
+            _marker: PhantomData,
+        }
+        .const_eq(&Concrete {
+            value: 3,
+            _marker: PhantomData,
+        }),
+        Concrete::<u8, ()> {
+            value: 3,
+            _marker: PhantomData,
+        }
+        .const_eq(&Concrete {
+            value: 5,
+            _marker: PhantomData,
+        }),
+        Concrete::<&str, ()> {
+            value: "foo",
+            _marker: PhantomData,
+        }
+        .const_eq(&Concrete {
+            value: "foo",
+            _marker: PhantomData,
+        }),
+        Concrete::<&str, ()> {
+            value: "foo",
+            _marker: PhantomData,
+        }
+        .const_eq(&Concrete {
+            value: "bar",
+            _marker: PhantomData,
+        }),
+    ];
+
+    assert_eq!(ARE_EQ, [true, false, true, false, true, false]);
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+assertc_eq!(Point { x: 3, y: 5 }, Point { x: 3, y: 5 });
+
+assertc_ne!(Point { x: 3, y: 5 }, Point { x: 3, y: 8 });
+
+assertc_eq!(
+    Enum::Tupled(Point { x: 3, y: 5 }, None),
+    Enum::Tupled(Point { x: 3, y: 5 }, None)
+);
+
+assertc_ne!(Enum::Unit, Enum::Braced { x: 0, y: &[] });
+
+///////////////////////////////////////////////////////////////////////////////
+//              ConstCmp
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, ConstDebug, ConstCmp)]
+#[cdeb(crate = "::cfmt_b")]
+#[ccmp(crate = "::cfmt_b")]
+struct CmpPoint {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, ConstDebug, ConstCmp)]
+#[cdeb(crate = "::cfmt_b")]
+#[ccmp(crate = "::cfmt_b")]
+enum CmpEnum {
+    Unit,
+    Braced { name: &'static str, opt: Option<u8> },
+    Tupled(CmpPoint, &'static [CmpPoint], [bool; 2]),
+    Nested(Option<CmpPoint>),
+}
+
+const fn cmp_point(x: i32, y: i32) -> CmpPoint {
+    CmpPoint { x, y }
+}
+
+#[test]
+fn derived_cmp_matches_std() {
+    const CASES: &[CmpEnum] = &[
+        CmpEnum::Unit,
+        CmpEnum::Braced {
+            name: "",
+            opt: None,
+        },
+        CmpEnum::Braced {
+            name: "",
+            opt: Some(0),
+        },
+        CmpEnum::Braced {
+            name: "",
+            opt: Some(3),
+        },
+        CmpEnum::Braced {
+            name: "a",
+            opt: None,
+        },
+        CmpEnum::Braced {
+            name: "ab",
+            opt: None,
+        },
+        CmpEnum::Braced {
+            name: "b",
+            opt: None,
+        },
+        CmpEnum::Tupled(cmp_point(0, 0), &[], [false, false]),
+        CmpEnum::Tupled(cmp_point(0, 0), &[], [false, true]),
+        CmpEnum::Tupled(cmp_point(0, 0), &[cmp_point(-1, 0)], [false, false]),
+        CmpEnum::Tupled(
+            cmp_point(0, 0),
+            &[cmp_point(-1, 0), cmp_point(0, 0)],
+            [true, false],
+        ),
+        CmpEnum::Tupled(cmp_point(0, 0), &[cmp_point(0, -1)], [false, false]),
+        CmpEnum::Tupled(cmp_point(0, 1), &[], [false, false]),
+        CmpEnum::Tupled(cmp_point(1, -5), &[], [false, false]),
+        CmpEnum::Nested(None),
+        CmpEnum::Nested(Some(cmp_point(-3, 0))),
+        CmpEnum::Nested(Some(cmp_point(0, 0))),
+    ];
+
+    for left in CASES {
+        for right in CASES {
+            assert_eq!(
+                left.const_cmp(right),
+                left.cmp(right),
+                "{:?} {:?}",
+                left,
+                right
+            );
+        }
+    }
+}
+
+mod custom_cmp {
+    use core::cmp::Ordering;
+
+    pub struct ByLen<'a>(pub &'a &'static str);
+
+    impl ByLen<'_> {
+        pub const fn const_cmp(&self, other: &Self) -> Ordering {
+            cfmt_b::PWrapper(self.0.len()).const_cmp(&other.0.len())
+        }
+    }
+
+    pub const fn reversed(left: &u32, right: &u32) -> Ordering {
+        cfmt_b::PWrapper(*right).const_cmp(left)
+    }
+
+    macro_rules! cmp_first {
+        ($left:expr, $right:expr) => {
+            cfmt_b::PWrapper($left[0]).const_cmp(&$right[0])
+        };
+    }
+    pub(crate) use cmp_first;
+}
+
+#[derive(ConstCmp)]
+#[ccmp(crate = "::cfmt_b")]
+struct CustomCmp {
+    #[ccmp(with_wrapper = "custom_cmp::ByLen")]
+    name: &'static str,
+    #[ccmp(with = "custom_cmp::reversed")]
+    rank: u32,
+    #[ccmp(with_macro = "custom_cmp::cmp_first")]
+    first: [u8; 2],
+    #[ccmp(ignore)]
+    #[allow(dead_code)]
+    ignored: u32,
+}
+
+#[test]
+fn custom_cmp() {
+    const BASE: CustomCmp = CustomCmp {
+        name: "foo",
+        rank: 5,
+        first: [3, 0],
+        ignored: 0,
+    };
+
+    const CMPS: [Ordering; 6] = [
+        BASE.const_cmp(&CustomCmp {
+            name: "bar",
+            first: [3, 100],
+            ignored: 100,
+            ..BASE
+        }),
+        BASE.const_cmp(&CustomCmp { name: "fo", ..BASE }),
+        BASE.const_cmp(&CustomCmp {
+            name: "fooo",
+            ..BASE
+        }),
+        BASE.const_cmp(&CustomCmp { rank: 8, ..BASE }),
+        BASE.const_cmp(&CustomCmp { rank: 3, ..BASE }),
+        BASE.const_cmp(&CustomCmp {
+            first: [4, 0],
+            ..BASE
+        }),
+    ];
+
+    assert_eq!(
+        CMPS,
+        [
+            Ordering::Equal,
+            Ordering::Greater,
+            Ordering::Less,
+            Ordering::Greater,
+            Ordering::Less,
+            Ordering::Less,
+        ]
+    );
+}
+
+assertc_lt!(cmp_point(3, 5), cmp_point(3, 8));
+
+assertc_lt!(CmpEnum::Unit, CmpEnum::Nested(None));
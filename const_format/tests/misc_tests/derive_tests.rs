@@ -162,3 +162,110 @@ fn enum_formatting() {
         ",
     );
 }
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(non_exhaustive)]
+enum NonExhaustive {
+    Braced {
+        x: u32,
+        #[allow(dead_code)]
+        #[cdeb(ignore)]
+        y: (u32, u32),
+    },
+    Tupled(
+        #[cdeb(ignore)]
+        #[allow(dead_code)]
+        Option<&'static str>,
+        u32,
+    ),
+    AllIgnored(
+        #[cdeb(ignore)]
+        #[allow(dead_code)]
+        u32,
+    ),
+    NoneIgnored {
+        x: u32,
+    },
+    Unit,
+}
+
+#[test]
+fn non_exhaustive_formatting() {
+    const fn inner(f: &mut Formatter<'_>) -> Result<(), Error> {
+        try_!(writec!(
+            f,
+            "{:?}\n{:?}\n{:?}\n{:?}\n{:?}\n{:#?}\n{:#?}",
+            NonExhaustive::Braced { x: 3, y: (5, 8) },
+            NonExhaustive::Tupled(None, 13),
+            NonExhaustive::AllIgnored(21),
+            NonExhaustive::NoneIgnored { x: 34 },
+            NonExhaustive::Unit,
+            NonExhaustive::Braced { x: 3, y: (5, 8) },
+            NonExhaustive::Tupled(None, 13),
+        ));
+
+        Ok(())
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 1024]);
+
+    inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
+
+    assert_eq!(
+        writer.as_str(),
+        "\
+            Braced { x: 3, .. }\n\
+            Tupled(13, ..)\n\
+            AllIgnored(..)\n\
+            NoneIgnored { x: 34 }\n\
+            Unit\n\
+            Braced {\n    x: 3,\n    ..\n}\n\
+            Tupled(\n    13,\n    ..\n)\
+        ",
+    );
+}
+
+///////////////////////////////////////////////////////////////////////////////
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(std_debug)]
+struct StdDebug<'a> {
+    name: &'a str,
+    values: &'a [u8],
+}
+
+#[derive(ConstDebug)]
+#[cdeb(crate = "::cfmt_b")]
+#[cdeb(std_debug)]
+#[cdeb(impls("StdDebugImpls<u8>", "<'a> StdDebugImpls<&'a str>"))]
+enum StdDebugImpls<T> {
+    Value(T),
+    Nothing,
+}
+
+#[test]
+fn std_debug_attribute() {
+    let value = StdDebug {
+        name: "foo\n",
+        values: &[3u8, 5],
+    };
+    assert_eq!(
+        format!("{:?}", value),
+        r#"StdDebug { name: "foo\n", values: [3, 5] }"#
+    );
+    assert_eq!(
+        format!("{:#?}", value),
+        "StdDebug {\n    name: \"foo\\n\",\n    values: [\n        3,\n        5,\n    ],\n}"
+    );
+
+    assert_eq!(format!("{:?}", StdDebugImpls::Value(8u8)), "Value(8)");
+    assert_eq!(
+        format!("{:?}", StdDebugImpls::Value("bar")),
+        r#"Value("bar")"#
+    );
+    assert_eq!(format!("{:?}", StdDebugImpls::<u8>::Nothing), "Nothing");
+}
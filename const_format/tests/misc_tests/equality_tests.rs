@@ -118,3 +118,53 @@ fn ranges() {
     compare_cases! {..0, ..5}
     compare_cases! {..=0, ..=5}
 }
+
+macro_rules! compare_ordering_cases {
+    ($($value:expr),* $(,)* ) => ({
+        let cases = [$($value,)*];
+
+        for left in cases.iter() {
+            for right in cases.iter() {
+                assert_eq!(
+                    coerce_to_fmt!(left).const_cmp(&right),
+                    left.cmp(right),
+                    "{:?} {:?}",
+                    left,
+                    right,
+                );
+            }
+        }
+    })
+}
+
+#[test]
+fn ordering_of_primitives() {
+    compare_ordering_cases! {0u8, 3, 5, u8::MAX}
+    compare_ordering_cases! {i8::MIN, -3i8, 0, 5, i8::MAX}
+    compare_ordering_cases! {0u128, 3, u128::MAX}
+    compare_ordering_cases! {i128::MIN, -3i128, 0, i128::MAX}
+    compare_ordering_cases! {isize::MIN, -3isize, 0, isize::MAX}
+    compare_ordering_cases! {false, true}
+    compare_ordering_cases! {'a', 'A', 'Ñ', 'ñ', '\0'}
+    compare_ordering_cases! {"", "a", "aa", "ab", "b", "Ñ", "ñ", "ña"}
+}
+
+#[test]
+fn ordering_of_slices() {
+    compare_ordering_cases! {&[][..], &[0u8][..], &[0, 0], &[0, 1], &[1], &[255, 0]}
+    compare_ordering_cases! {&[][..], &[-1i64][..], &[-1, 0], &[0], &[0, -1]}
+    compare_ordering_cases! {&[][..], &[false][..], &[false, true], &[true]}
+    compare_ordering_cases! {&[][..], &['a'][..], &['a', 'b'], &['b']}
+    compare_ordering_cases! {&[][..], &[""][..], &["", "a"], &["a"], &["a", ""], &["b"]}
+
+    // arrays are coerced to slices
+    assert_eq!(coerce_to_fmt!([3u8, 5]).const_cmp(&[3, 5]), Ordering::Equal);
+    assert_eq!(
+        coerce_to_fmt!([3u8, 5]).const_cmp(&[3, 5, 8]),
+        Ordering::Less
+    );
+    assert_eq!(
+        coerce_to_fmt!(["b"]).const_cmp(&["a", "b"]),
+        Ordering::Greater
+    );
+}
@@ -1,5 +1,5 @@
-use cfmt_b::formatcp;
 use cfmt_b::test_utils::{ALL_ASCII, ALL_ASCII_ESCAPED};
+use cfmt_b::{concatcp, formatcp};
 
 #[cfg(feature = "fmt")]
 use cfmt_b::formatc;
@@ -144,6 +144,33 @@ fn debug_formatting() {
     }
 }
 
+#[test]
+fn debug_formatting_matches_std() {
+    const S: &str = "\u{301}a\u{301}\u{200B}\u{AD}'\"\0\u{7F}\u{85}ñ个\u{E0100}\u{100000}🧡";
+    const CHARS: [char; 6] = ['\u{301}', '\u{AD}', '\'', '"', 'ñ', '\u{10FFFF}'];
+
+    assert_eq!(formatcp!("{:?}", S), format!("{:?}", S));
+    assert_eq!(
+        formatcp!(
+            "{:?}{:?}{:?}{:?}{:?}{:?}",
+            CHARS[0],
+            CHARS[1],
+            CHARS[2],
+            CHARS[3],
+            CHARS[4],
+            CHARS[5]
+        ),
+        CHARS.iter().map(|c| format!("{:?}", c)).collect::<String>(),
+    );
+
+    #[cfg(feature = "fmt")]
+    {
+        assert_eq!(formatc!("{:?}", S), format!("{:?}", S));
+        assert_eq!(formatc!("{:?}", CHARS), format!("{:?}", CHARS));
+        assert_eq!(formatc!("{:#?}", [S, ""]), format!("{:#?}", [S, ""]));
+    }
+}
+
 macro_rules! binary_hex_test_case {
     ($ty:ident, $buffer:ident) => {{
         binary_hex_test_case! {@inner formatcp, $ty, $buffer};
@@ -266,6 +293,152 @@ fn binary_and_hex_formatting() {
     binary_hex_test_case!(i128, s);
 }
 
+macro_rules! std_fmt_assert {
+    ($string:ident; $($fmt:literal, $($arg:expr),* ;)*) => ({
+        $({
+            $string.clear();
+            write!($string, $fmt, $($arg),*).unwrap();
+            fmt_assert!(($fmt, $($arg),*), $string.as_str());
+        })*
+    })
+}
+
+#[test]
+fn width_fill_alignment() {
+    let mut s = ArrayString::<256>::new();
+
+    std_fmt_assert! {s;
+        "[{:5}]", 13u8;
+        "[{:<5}]", 13u8;
+        "[{:^5}]", 13u8;
+        "[{:>5}]", 13u8;
+        "[{:1}]", 1300u32;
+        "[{:6}]", -13i8;
+        "[{:06}]", -13i8;
+        "[{:+^6}]", -13i8;
+        "[{:<06}]", 13i64;
+        "[{:#010x?}]", 255u16;
+        "[{:#10X?}]", 255u16;
+        "[{:010b}]", 5u128;
+        "[{:ñ^9}]", "foo";
+        "[{:8}]", "ñó";
+        // std ignores the width when Debug formatting `str`s and `char`s
+        "[{:>8?}]", "a\nb";
+        "[{:>8?}]", "ñó";
+        "[{:-^6}]", 'ñ';
+        "[{:6?}]", '\n';
+        "[{:>7}]", true;
+        "[{:<7?}]", false;
+        "[{:<2}]", "longer than the width";
+        "[{:0$}]", 5usize;
+        "[{:>1$}|{1:}]", "ab", 6usize;
+    }
+
+    const W: usize = 7;
+    s.clear();
+    write!(s, "[{:W$}|{:_^W$?}|{:>w$}]", 3u8, "a", 'b', w = 4).unwrap();
+    fmt_assert!(
+        ("[{:W$}|{:_^W$?}|{:>w$}]", 3u8, "a", 'b', w = 4),
+        s.as_str()
+    );
+}
+
+#[test]
+fn float_formatting() {
+    let mut s = ArrayString::<256>::new();
+
+    std_fmt_assert! {s;
+        "[{}|{:?}]", 1.0f64, 1.0f64;
+        "[{}|{:?}]", 0.1f32, 0.1f32;
+        "[{}|{:?}]", -0.0f64, -0.0f64;
+        "[{}|{:?}]", 1e-7f64, 1e-7f64;
+        "[{}|{:?}]", 1e16f64, 1e16f64;
+        "[{}|{:?}]", 123.456f32, 123.456f32;
+        "[{}|{:?}]", f64::NAN, f64::NAN;
+        "[{}|{:?}]", f32::NEG_INFINITY, f32::INFINITY;
+        "[{:8}|{:<8?}]", 2.5f64, 2.5f64;
+        "[{:08}|{:^8?}]", -2.5f64, 1.25f32;
+        "[{:06}|{:06}]", f64::NAN, f64::NEG_INFINITY;
+        "[{:x?}|{:#?}]", 10.0f64, 10.0f64;
+    }
+
+    // unsuffixed float literals are formatted as `f64`s
+    assert_eq!(formatcp!("{}", 0.5), "0.5");
+    assert_eq!(formatcp!("{:?}", -1e20), "-1e20");
+    assert_eq!(concatcp!("ratio=", 0.5, ";"), "ratio=0.5;");
+}
+
+#[test]
+fn precision() {
+    let mut s = ArrayString::<256>::new();
+
+    std_fmt_assert! {s;
+        "[{:.3}]", "abcdef";
+        "[{:.0}]", "abcdef";
+        "[{:.10}]", "abc";
+        "[{:.2}]", "ñóñó";
+        "[{:5.1}|{:>5.1}]", "héllo", "héllo";
+        "[{:_^7.3}]", "abcdef";
+        "[{:.0}|{:.1}]", 'x', 'x';
+        "[{:.2}|{:.3}]", true, false;
+        // std ignores the precision when Debug formatting strings
+        "[{:.1?}]", "abc";
+        "[{:.1?}]", 'a';
+        // std ignores the precision of integers
+        "[{:.2}|{:.2?}|{:5.1x}]", 12u8, -3i32, 255u16;
+        "[{:.0}|{:.0}|{:.0}|{:.0}]", 0.5f64, 1.5f64, 2.5f64, -3.5f64;
+        "[{:.2}|{:.2?}|{:.1}]", 1.0f64, 1.0f64, 5e-2f32;
+        "[{:.30}]", 1e-1f64;
+        "[{:.3}|{:.3}|{:.3?}]", -0.0f64, f64::NAN, f64::NEG_INFINITY;
+        "[{:08.2}|{:<8.1}|{:^8.0?}]", -1.234f64, 9.96f64, 1e20f64;
+        "[{:.1$}|{:.*}]", 1.23456f64, 3usize, 1.23456f32;
+    }
+
+    s.clear();
+    write!(s, "[{:.p$}|{:.*}]", 1.25f64, 1, 1.35f32, p = 1).unwrap();
+    fmt_assert!(
+        ("[{:.p$}|{:.*}]", 1.25f64, 1usize, 1.35f32, p = 1usize),
+        s.as_str()
+    );
+
+    s.clear();
+    write!(s, "{:.3}", 1e-7f64).unwrap();
+    assert_eq!(formatcp!("{:.3}", 1e-7), s.as_str());
+
+    const LABEL: &str = "a_very_long_identifier";
+    const MAX_LEN: usize = 6;
+    assert_eq!(formatcp!("v{:.MAX_LEN$}", LABEL), "va_very");
+}
+
+#[test]
+fn octal_sign_exponent() {
+    let mut s = ArrayString::<256>::new();
+
+    std_fmt_assert! {s;
+        "[{:o}|{:#o}|{:o}|{:#o}]", 0o755u32, 0o644u16, 0u8, -1i8;
+        "[{:o}|{:#o}]", u128::MAX, i128::MIN;
+        "[{:#010o}|{:>8o}|{:+o}]", 8u8, 0o17u32, 7i64;
+        "[{:+}|{:+}|{:+?}|{:+}]", 5u8, -5i8, 0i32, i128::MIN;
+        "[{:+06}|{:<+6}|{:+x}|{:+#06b}]", 5i16, 5i16, -1i8, 5u8;
+        "[{:+}|{:+?}|{:+}|{:+.1}]", 1.5f64, -0.0f64, f64::NAN, f32::INFINITY;
+        "[{:e}|{:E}|{:e}|{:e}]", 1234u32, 1234u32, 1200i64, 0u8;
+        "[{:e}|{:+e}|{:e}]", -5i8, 7u16, u128::MAX;
+        "[{:.2e}|{:.0e}|{:.5e}|{:.1e}]", 1235u32, 15u8, 12i32, 125u64;
+        "[{:010e}|{:<8e}|{:+08.1e}]", -12i32, 100u8, 1250u16;
+        "[{:e}|{:E}|{:e}|{:.2e}]", 1234.5f64, 1.2e-4f64, 0.0f32, 1234.5f32;
+        "[{:e}|{:e}|{:010.1e}|{:+e}]", f64::NAN, f64::INFINITY, -2.5f64, 1.0f64;
+    }
+
+    const MODE: u32 = 0o755;
+    assert_eq!(formatcp!("mode={:#o}", MODE), "mode=0o755");
+
+    #[cfg(feature = "fmt")]
+    assert_eq!(
+        formatc!("{:o}|{:+e}|{:+X}", [8u8, 9], [1500u16, 0], [-1i8, 2]),
+        "[10, 11]|[+1.5e3, +0e0]|[+FF, +2]",
+    );
+}
+
 #[test]
 fn other_tests() {
     assert_eq!(formatcp!("{0:?}-{0:x?}-{0:b?}", ""), r#"""-""-"""#);
@@ -57,6 +57,7 @@ tests! {
         {}{}{}{}{}{}{}{}\
         {}{}{}{}{}{}{}{}\
         {}{}{}{}{}{}{}{}\
+        {}{}{}{}{}{}{}{}\
     ",
 
     i8::MIN, " ", i8::MAX, " ",
@@ -75,6 +76,7 @@ tests! {
     usize::MIN, " ", usize::MAX, " ",
     false, true,
     'o', 'ñ', '个', '\u{100000}',
+    0.5f64, " ", -0.0f64, " ", f32::MIN_POSITIVE, " ", 1e21f64, f64::NEG_INFINITY,
 }
 
 #[test]
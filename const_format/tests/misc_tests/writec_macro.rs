@@ -54,7 +54,7 @@ fn repeated_positional_args() {
     inner(&foo, writer).unwrap();
     assert_eq!(
         writer.as_str(),
-        "13,13,0xd,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\x00\""
+        "13,13,0xd,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\0\""
     );
 }
 
@@ -76,7 +76,7 @@ fn write_from_consts() {
     inner(&mut writer.make_formatter(FormattingFlags::NEW)).unwrap();
     assert_eq!(
         writer.as_str(),
-        "13,13,0xd,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\x00\""
+        "13,13,0xd,0xD,0b1101,foo\nbar\tbaz\x00,\"foo\\nbar\\tbaz\\0\""
     );
 }
 
@@ -124,6 +124,42 @@ fn write_from_locals() {
     assert_eq!(writer.as_str(), r#"13,58,13,"58",d,"58",D,"58",1101,"58""#);
 }
 
+#[test]
+fn width_from_locals() {
+    const fn inner(f: &mut Formatter<'_>, width: usize) -> Result<(), Error> {
+        writec!(
+            f,
+            "[{0:width$}|{1:<width$}|{1:_^2$}|{0:02$x}|{3:>width$}]",
+            13u8,
+            "ab",
+            width + 1,
+            |fmt| fmt.write_u8_display(fmt.flags().width().unwrap() as u8),
+        )
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 96]);
+    inner(&mut writer.make_formatter(FormattingFlags::NEW), 4).unwrap();
+    assert_eq!(writer.as_str(), "[  13|ab  |_ab__|0000d|4]");
+}
+
+#[test]
+fn precision_from_locals() {
+    const fn inner(f: &mut Formatter<'_>, prec: usize) -> Result<(), Error> {
+        writec!(
+            f,
+            "[{0:.prec$}|{1:.prec$}|{1:6.2$}|{3:.prec$}]",
+            "abcdef",
+            2.5f64,
+            prec - 1,
+            |fmt| fmt.write_u8_display(fmt.flags().precision().unwrap() as u8),
+        )
+    }
+
+    let writer: &mut StrWriter = &mut StrWriter::new([0; 96]);
+    inner(&mut writer.make_formatter(FormattingFlags::NEW), 3).unwrap();
+    assert_eq!(writer.as_str(), "[abc|2.500|  2.50|3]");
+}
+
 #[test]
 #[cfg(feature = "fmt")]
 fn access_formatter() {
@@ -17,6 +17,9 @@ mod misc_tests {
     #[cfg(feature = "assertcp")]
     mod assertcp_tests;
 
+    #[cfg(not(feature = "__only_new_tests"))]
+    mod bytes_macro_tests;
+
     #[cfg(feature = "fmt")]
     #[cfg(not(feature = "__only_new_tests"))]
     mod call_debug_fmt_macro;
@@ -25,6 +28,21 @@ mod misc_tests {
     #[cfg(not(feature = "__only_new_tests"))]
     mod concatc_macro_tests;
 
+    #[cfg(not(feature = "__only_new_tests"))]
+    mod cap_macro_tests;
+
+    #[cfg(feature = "rust_1_64")]
+    #[cfg(not(feature = "__only_new_tests"))]
+    mod cstr_macro_tests;
+
+    #[cfg(feature = "derive")]
+    #[cfg(not(feature = "__only_new_tests"))]
+    mod derive_display_tests;
+
+    #[cfg(all(feature = "derive", feature = "assertc"))]
+    #[cfg(not(feature = "__only_new_tests"))]
+    mod derive_eq_tests;
+
     #[cfg(feature = "derive")]
     #[cfg(not(feature = "__only_new_tests"))]
     mod derive_tests;
@@ -1,10 +1,26 @@
 mod str_methods_modules {
+    mod byte_encoding;
+
     mod conv_ascii_case;
 
+    mod conv_case;
+
+    mod str_escape;
+
+    mod str_find;
+
+    mod str_join;
+
+    mod str_parse;
+
     mod str_replace;
 
+    mod str_scan;
+
     mod str_splice;
 
     #[cfg(feature = "rust_1_64")]
-    mod str_split_tests;
+    mod str_split;
+
+    mod str_trim;
 }
@@ -0,0 +1,113 @@
+use const_format::{base64_decode, base64_encode, hex_decode, hex_encode, str_replace};
+
+fn reference_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn reference_base64(bytes: &[u8]) -> String {
+    const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::new();
+    for chunk in bytes.chunks(3) {
+        let mut group = [0u8; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let n = u32::from_be_bytes([0, group[0], group[1], group[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(CHARS[(n >> (18 - i * 6)) as usize & 0x3F] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+macro_rules! assert_roundtrip {
+    ($($input:expr),* $(,)*) => ({
+        $({
+            const INPUT: &[u8] = $input;
+
+            const HEX: &str = hex_encode!(INPUT);
+            const BASE64: &str = base64_encode!(INPUT);
+
+            assert_eq!(HEX, reference_hex(INPUT));
+            assert_eq!(BASE64, reference_base64(INPUT));
+
+            assert_eq!(hex_decode!(HEX), INPUT);
+            assert_eq!(base64_decode!(BASE64), INPUT);
+            const UNPADDED: &str = str_replace!(BASE64, '=', "");
+            assert_eq!(base64_decode!(UNPADDED), INPUT);
+        })*
+    });
+}
+
+#[test]
+fn matches_reference_impl() {
+    assert_roundtrip! {
+        b"",
+        b"f",
+        b"fo",
+        b"foo",
+        b"foob",
+        b"fooba",
+        b"foobar",
+        &[0, 1, 2, 0x7F, 0x80, 0xFE, 0xFF],
+        "ñ🧡\n\t\"'".as_bytes(),
+        &[0xFF; 100],
+    }
+}
+
+#[test]
+fn roundtrip_all_bytes() {
+    const ALL_BYTES: [u8; 256] = {
+        let mut arr = [0u8; 256];
+        let mut i = 0;
+        while i < 256 {
+            arr[i] = i as u8;
+            i += 1;
+        }
+        arr
+    };
+
+    assert_roundtrip! {&ALL_BYTES}
+}
+
+#[test]
+fn decode_examples() {
+    // rfc 4648 test vectors
+    assert_eq!(base64_decode!("Zm9vYmFy"), b"foobar");
+    assert_eq!(base64_decode!("Zm9vYmE="), b"fooba");
+    assert_eq!(base64_decode!("Zm9vYg=="), b"foob");
+    assert_eq!(base64_decode!("Zm9vYg"), b"foob");
+    assert_eq!(base64_decode!(""), b"");
+
+    assert_eq!(
+        hex_decode!("0123456789abcdefABCDEF"),
+        [0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0xAB, 0xCD, 0xEF]
+    );
+    assert_eq!(hex_decode!(""), b"");
+}
+
+#[test]
+fn input_types() {
+    const STR: &str = "hi";
+    const SLICE: &[u8] = b"hi";
+    const ARRAY: &[u8; 2] = b"hi";
+
+    assert_eq!(hex_encode!(STR), "6869");
+    assert_eq!(hex_encode!(SLICE), "6869");
+    assert_eq!(hex_encode!(ARRAY), "6869");
+
+    assert_eq!(hex_decode!("6869"), b"hi");
+    assert_eq!(hex_decode!(b"6869"), b"hi");
+    {
+        const HEX_SLICE: &[u8] = b"6869";
+        assert_eq!(hex_decode!(HEX_SLICE), b"hi");
+    }
+
+    assert_eq!(
+        base64_decode!(base64_encode!(include_bytes!("byte_encoding.rs"))),
+        include_bytes!("byte_encoding.rs")
+    );
+}
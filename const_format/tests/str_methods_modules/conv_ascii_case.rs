@@ -1,20 +1,24 @@
 use const_format::__ascii_case_conv::{convert_str, size_after_conversion};
-use const_format::{map_ascii_case, Case};
+use const_format::{map_ascii_case, Acronyms, Case, CaseOptions};
 
 macro_rules! assert_case {
-    ($case:expr, $input:expr, $output:expr $(,)?) => {{
+    ($case:expr, $input:expr, $output:expr $(,)?) => {
+        assert_case!($case, CaseOptions::NEW, $input, $output)
+    };
+    ($case:expr, $options:expr, $input:expr, $output:expr $(,)?) => {{
         const IN: &str = $input;
         const OUT: &str = $output;
         const CASE: Case = $case;
+        const OPTIONS: CaseOptions = $options;
 
-        assert_eq!(size_after_conversion(CASE, IN), OUT.len());
+        assert_eq!(size_after_conversion(CASE, OPTIONS, IN), OUT.len());
 
         assert_eq!(
-            std::str::from_utf8(&convert_str::<{ OUT.len() }>(CASE, IN)).unwrap(),
+            std::str::from_utf8(&convert_str::<{ OUT.len() }>(CASE, OPTIONS, IN)).unwrap(),
             OUT,
         );
 
-        assert_eq!(map_ascii_case!(CASE, IN), OUT);
+        assert_eq!(map_ascii_case!(CASE, IN, OPTIONS), OUT);
     }};
 }
 
@@ -75,3 +79,135 @@ fn test_pascal_camel_case() {
 
     assert_case!(Case::Camel, "一门foo 一门", "一门foo一门");
 }
+
+#[test]
+fn test_title_train_case() {
+    assert_case!(
+        Case::Title,
+        " _foo_ 100 hello_nnñWorld ",
+        "Foo 100 Hello Nnñ World"
+    );
+    assert_case!(Case::Title, "一门 foo 一门", "一门 Foo 一门");
+
+    assert_case!(Case::Train, "content_TYPE", "Content-Type");
+    assert_case!(
+        Case::Train,
+        " _foo_ 100 hello_nnñWorld ",
+        "Foo-100-Hello-Nnñ-World"
+    );
+}
+
+#[test]
+fn test_separated_lowercase() {
+    assert_case!(Case::Dot, " __ 100 hello_nnWorld ", "100.hello.nn.world");
+    assert_case!(Case::Flat, " __ 100 hello_nnWorld ", "100hellonnworld");
+    assert_case!(Case::Path, " __ 100 hello_nnWorld ", "100/hello/nn/world");
+    assert_case!(Case::Path, "", "");
+}
+
+#[test]
+fn test_cobol_case() {
+    assert_case!(Case::Cobol, " __ 100 hello_nnWorld ", "100-HELLO-NN-WORLD");
+    assert_case!(Case::Cobol, "一门 foo 一门", "一门-FOO-一门");
+}
+
+#[test]
+fn test_custom_case() {
+    use const_format::WordCase;
+
+    assert_case!(
+        Case::custom(", ", WordCase::Upper),
+        "fooBar baz",
+        "FOO, BAR, BAZ"
+    );
+    assert_case!(
+        Case::custom("", WordCase::Capitalized),
+        " _foo_ 100 hello_nnñWorld ",
+        "Foo100HelloNnñWorld"
+    );
+    assert_case!(
+        Case::Custom {
+            separator: "::",
+            first_word_case: WordCase::Lower,
+            word_case: WordCase::Capitalized,
+        },
+        "FOO BAR_BAZ",
+        "foo::Bar::Baz"
+    );
+    assert_case!(
+        Case::Custom {
+            separator: " ñ ",
+            first_word_case: WordCase::Capitalized,
+            word_case: WordCase::Lower,
+        },
+        "hello WORLD",
+        "Hello ñ world"
+    );
+    assert_case!(Case::custom("-", WordCase::Lower), "foo", "foo");
+}
+
+#[test]
+fn test_case_options() {
+    const GROUPED: CaseOptions = CaseOptions::NEW.set_acronyms(Acronyms::Grouped);
+    const SPLIT: CaseOptions = CaseOptions::NEW.set_acronyms(Acronyms::Split);
+
+    assert_case!(Case::Snake, "HTTPServer2Go", "httpserver_2_go");
+    assert_case!(Case::Snake, GROUPED, "HTTPServer2Go", "http_server_2_go");
+    assert_case!(
+        Case::Snake,
+        GROUPED.set_digit_boundaries(false),
+        "HTTPServer2Go",
+        "http_server2_go"
+    );
+    assert_case!(Case::Snake, SPLIT, "HTTPServer2Go", "h_t_t_p_server_2_go");
+    assert_case!(Case::Pascal, GROUPED, "XMLHttpRequest", "XmlHttpRequest");
+    assert_case!(Case::Camel, SPLIT, "ABc", "aBc");
+
+    const KEEP: CaseOptions = CaseOptions::NEW.set_kept_punctuation(".:a");
+    assert_case!(
+        Case::UpperSnake,
+        KEEP,
+        "fooBar.baz::qux-v1.2",
+        "FOO_BAR.BAZ::QUX_V_1.2"
+    );
+    assert_case!(Case::Kebab, KEEP, ".hidden file", ".hidden-file");
+    assert_case!(Case::Lower, KEEP, "FOO-BAR", "foo-bar");
+}
+
+#[test]
+fn test_is_case() {
+    use const_format::{is_case, WordCase};
+
+    const _: () = assert!(is_case(Case::Snake, "foo_bar_100"));
+
+    let cases = [
+        (Case::Lower, "hello world_ñ", "Hello"),
+        (Case::Upper, "HELLO WORLD_ñ", "HELLo"),
+        (Case::Pascal, "HelloWorld100", "helloWorld"),
+        (Case::Camel, "helloWorld100", "HelloWorld"),
+        (Case::Snake, "hello_world_100", "hello_World"),
+        (Case::UpperSnake, "HELLO_WORLD", "HELLO__WORLD"),
+        (Case::Kebab, "hello-world", "hello-world-"),
+        (Case::UpperKebab, "HELLO-WORLD", "HELLO_WORLD"),
+        (Case::Title, "Hello World", "Hello world"),
+        (Case::Train, "Content-Type", "Content-type"),
+        (Case::Dot, "hello.world", "hello..world"),
+        (Case::Flat, "helloworld", "hello_world"),
+        (Case::Cobol, "HELLO-WORLD", "HELLO-world"),
+        (Case::Path, "hello/world", "/hello/world"),
+        (
+            Case::custom(", ", WordCase::Upper),
+            "HELLO, WORLD",
+            "HELLO,WORLD",
+        ),
+    ];
+
+    for (case, valid, invalid) in cases {
+        assert!(is_case(case, valid), "{:?} {:?}", case, valid);
+        assert!(!is_case(case, invalid), "{:?} {:?}", case, invalid);
+    }
+
+    assert!(is_case(Case::Snake, ""));
+    assert!(!is_case(Case::Snake, "_"));
+    assert!(is_case(Case::Lower, ""));
+}
@@ -0,0 +1,158 @@
+use const_format::__ascii_case_conv::{convert_str_unicode, size_after_unicode_conversion};
+use const_format::{map_case, Acronyms, Case, CaseOptions};
+
+macro_rules! assert_case {
+    ($case:expr, $input:expr, $output:expr $(,)?) => {
+        assert_case!($case, CaseOptions::NEW, $input, $output)
+    };
+    ($case:expr, $options:expr, $input:expr, $output:expr $(,)?) => {{
+        const IN: &str = $input;
+        const OUT: &str = $output;
+        const CASE: Case = $case;
+        const OPTIONS: CaseOptions = $options;
+
+        assert_eq!(size_after_unicode_conversion(CASE, OPTIONS, IN), OUT.len());
+
+        assert_eq!(
+            std::str::from_utf8(&convert_str_unicode::<{ OUT.len() }>(CASE, OPTIONS, IN)).unwrap(),
+            OUT,
+        );
+
+        assert_eq!(map_case!(CASE, IN, OPTIONS), OUT);
+    }};
+}
+
+// Checks that `map_case` lowercases and uppercases the same as std
+macro_rules! assert_matches_std {
+    ($($text:expr),* $(,)?) => {$({
+        const TEXT: &str = $text;
+        assert_eq!(map_case!(Case::Lower, TEXT), TEXT.to_lowercase());
+        assert_eq!(map_case!(Case::Upper, TEXT), TEXT.to_uppercase());
+    })*};
+}
+
+#[test]
+fn test_lowercase() {
+    assert_case!(
+        Case::Lower,
+        "helloazWORLDAZ 効率 \u{303}n\u{303}Nñ",
+        "helloazworldaz 効率 \u{303}n\u{303}nñ",
+    );
+    assert_case!(Case::Lower, "ÑANDÚ ΟΔΟΣ Σ", "ñandú οδος σ");
+    assert_case!(Case::Lower, "İ", "i\u{307}");
+}
+
+#[test]
+fn test_uppercase() {
+    assert_case!(
+        Case::Upper,
+        "helloazWORLDAZ 効率 \u{303}n\u{303}Nñ",
+        "HELLOAZWORLDAZ 効率 \u{303}N\u{303}NÑ",
+    );
+    assert_case!(Case::Upper, "straße ǆ ﬀ", "STRASSE Ǆ FF");
+}
+
+#[test]
+fn test_matches_std() {
+    assert_matches_std!(
+        "helloazWORLDAZ 効率 \u{303}n\u{303}Nñ",
+        "Straße größer ŉ ǰ ﬀ İ",
+        "ΟΔΟΣ ΣΚΙΑΣ Σ ΑΣ. ΑΣ' ΑΣΑ",
+        "маленький ДОМ Ǆǅǆ",
+    );
+}
+
+#[test]
+fn test_snake_kebab_case() {
+    assert_case!(
+        Case::Snake,
+        " __ 100 hello_ñandúWorld ",
+        "100_hello_ñandú_world"
+    );
+    assert_case!(
+        Case::UpperSnake,
+        " __ 100 hello_straßeWorld ",
+        "100_HELLO_STRASSE_WORLD"
+    );
+    assert_case!(Case::Snake, "ΟΔΟΣ_ΣΚΙΑΣ", "οδος_σκιας");
+
+    // Kebab case
+    assert_case!(Case::Kebab, "маленькийДом 一门", "маленький-дом-一门");
+    assert_case!(
+        Case::UpperKebab,
+        " __ 100 hello_ñandúWorld ",
+        "100-HELLO-ÑANDÚ-WORLD"
+    );
+}
+
+#[test]
+fn test_pascal_camel_case() {
+    assert_case!(
+        Case::Pascal,
+        " _foo_ 100 hello_nnñWorld ",
+        "Foo100HelloNnñWorld"
+    );
+    assert_case!(Case::Pascal, "ñandú ÑANDÚ", "ÑandúÑandú");
+    assert_case!(Case::Pascal, "ΟΔΟΣ ΣΚΙΑΣ", "ΟδοςΣκιας");
+    assert_case!(Case::Pascal, "一门 foo 一门", "一门Foo一门");
+
+    // Camel case
+    assert_case!(Case::Camel, "ÑANDÚ_WORLD", "ñandúWorld");
+    assert_case!(Case::Camel, "ßtraße ŉ", "ßtraßeʼN");
+    assert_case!(Case::Camel, "一门foo 一门", "一门foo一门");
+}
+
+#[test]
+fn test_other_word_cases() {
+    assert_case!(Case::Title, "größe_ÜBER маленький", "Größe Über Маленький");
+    assert_case!(Case::Train, "ΟΔΟΣ_σκιας", "Οδος-Σκιας");
+    assert_case!(Case::Dot, "straßeÜber", "straße.über");
+    assert_case!(Case::Flat, "Straße ÜBER", "straßeüber");
+    assert_case!(Case::Cobol, "straße_über", "STRASSE-ÜBER");
+    assert_case!(Case::Path, "ÑANDÚ ΟΔΟΣ", "ñandú/οδος");
+}
+
+#[test]
+fn test_custom_case() {
+    use const_format::WordCase;
+
+    assert_case!(
+        Case::custom(" → ", WordCase::Upper),
+        "straße über",
+        "STRASSE → ÜBER"
+    );
+    assert_case!(
+        Case::Custom {
+            separator: "::",
+            first_word_case: WordCase::Lower,
+            word_case: WordCase::Capitalized,
+        },
+        "ΟΔΟΣ ΣΚΙΑΣ ñandú",
+        "οδος::Σκιας::Ñandú"
+    );
+}
+
+#[test]
+fn test_case_options() {
+    const GROUPED: CaseOptions = CaseOptions::NEW.set_acronyms(Acronyms::Grouped);
+
+    assert_case!(Case::Snake, GROUPED, "ÜBERSchön٣Wert", "über_schön_٣_wert");
+    assert_case!(
+        Case::Snake,
+        GROUPED.set_digit_boundaries(false),
+        "ÜBERSchön٣Wert",
+        "über_schön٣_wert"
+    );
+    assert_case!(
+        Case::Kebab,
+        CaseOptions::NEW.set_acronyms(Acronyms::Split),
+        "ΟΔΟΣ",
+        "ο-δ-ο-σ"
+    );
+    assert_case!(
+        Case::Snake,
+        CaseOptions::NEW.set_kept_punctuation("."),
+        "Straße.ÜBER ñ",
+        "straße._über_ñ"
+    );
+}
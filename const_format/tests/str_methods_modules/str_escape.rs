@@ -0,0 +1,117 @@
+use const_format::{str_escape, Escape};
+
+use std::fmt::Write;
+
+// A straightforward runtime implementation of each escaping mode
+fn reference_escape(escape: Escape, s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        let mut buf = [0; 4];
+        let as_bytes = c.encode_utf8(&mut buf).as_bytes();
+        match escape {
+            Escape::Json => match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                '\u{8}' => out.push_str("\\b"),
+                '\u{c}' => out.push_str("\\f"),
+                '\0'..='\u{1f}' => write!(out, "\\u{:04x}", c as u32).unwrap(),
+                _ => out.push(c),
+            },
+            Escape::C => match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                '\u{7}' => out.push_str("\\a"),
+                '\u{8}' => out.push_str("\\b"),
+                '\u{b}' => out.push_str("\\v"),
+                '\u{c}' => out.push_str("\\f"),
+                ' '..='~' => out.push(c),
+                _ => {
+                    for b in as_bytes {
+                        write!(out, "\\{:03o}", b).unwrap();
+                    }
+                }
+            },
+            Escape::Html => match c {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '"' => out.push_str("&quot;"),
+                '\'' => out.push_str("&#39;"),
+                _ => out.push(c),
+            },
+            Escape::Shell => match c {
+                '\'' => out.push_str("'\\''"),
+                _ => out.push(c),
+            },
+            Escape::Percent => match c {
+                'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '.' | '_' | '~' => out.push(c),
+                _ => {
+                    for b in as_bytes {
+                        write!(out, "%{:02X}", b).unwrap();
+                    }
+                }
+            },
+            _ => unimplemented!("{:?}", escape),
+        }
+    }
+    out
+}
+
+macro_rules! assert_escape {
+    ($($input:expr),* $(,)?) => {$({
+        const IN: &str = $input;
+
+        assert_eq!(str_escape!(Escape::Json, IN), reference_escape(Escape::Json, IN));
+        assert_eq!(str_escape!(Escape::C, IN), reference_escape(Escape::C, IN));
+        assert_eq!(str_escape!(Escape::Html, IN), reference_escape(Escape::Html, IN));
+        assert_eq!(str_escape!(Escape::Shell, IN), reference_escape(Escape::Shell, IN));
+        assert_eq!(str_escape!(Escape::Percent, IN), reference_escape(Escape::Percent, IN));
+    })*};
+}
+
+#[test]
+fn matches_reference_impl() {
+    assert_escape!(
+        "",
+        "hello world",
+        "\0\x01\x07\x08\t\n\x0b\x0c\r\x1b\x1f !\"#$%&'()*+,-./09:;<=>?@AZ[\\]^_`az{|}~\x7f",
+        "ñ日本語🧡\u{7f}\u{80}\u{200B}",
+        "it's a \"<b>\" & 'c' 100% ~_-.",
+    );
+}
+
+#[test]
+fn escape_examples() {
+    assert_eq!(
+        str_escape!(Escape::Json, "a\"b\\c\nd\u{1}"),
+        r#"a\"b\\c\nd\u0001"#
+    );
+    assert_eq!(
+        str_escape!(Escape::C, "a\"b\0c\u{7f}ñ"),
+        r#"a\"b\000c\177\303\261"#
+    );
+    assert_eq!(str_escape!(Escape::C, "\u{1}1"), r"\0011");
+    assert_eq!(
+        str_escape!(Escape::Html, "<a title='x'>&\"</a>"),
+        "&lt;a title=&#39;x&#39;&gt;&amp;&quot;&lt;/a&gt;"
+    );
+    assert_eq!(str_escape!(Escape::Shell, "'it's'"), r"'\''it'\''s'\''");
+    assert_eq!(
+        str_escape!(Escape::Percent, "a b+c/ñ?"),
+        "a%20b%2Bc%2F%C3%B1%3F"
+    );
+}
+
+#[test]
+fn const_arguments() {
+    const ESCAPE: Escape = Escape::Percent;
+    const IN: &str = "foo bar";
+    const OUT: &str = str_escape!(ESCAPE, IN);
+    assert_eq!(OUT, "foo%20bar");
+}
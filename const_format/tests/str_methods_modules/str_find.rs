@@ -0,0 +1,98 @@
+use const_format::{str_contains, str_ends_with, str_find, str_index, str_rfind, str_starts_with};
+
+macro_rules! case {
+    ($string:expr, $pattern:expr, $std_pattern:expr) => {{
+        const S: &str = $string;
+        assert_eq!(str_find!(S, $pattern), S.find($std_pattern), "find");
+        assert_eq!(str_rfind!(S, $pattern), S.rfind($std_pattern), "rfind");
+        assert_eq!(
+            str_contains!(S, $pattern),
+            S.contains($std_pattern),
+            "contains"
+        );
+        assert_eq!(
+            str_starts_with!(S, $pattern),
+            S.starts_with($std_pattern),
+            "starts_with"
+        );
+        assert_eq!(
+            str_ends_with!(S, $pattern),
+            S.ends_with($std_pattern),
+            "ends_with"
+        );
+    }};
+    ($string:expr, $pattern:expr) => {
+        case!($string, $pattern, $pattern)
+    };
+}
+
+#[test]
+fn test_str_find_str_pattern() {
+    case!("", "");
+    case!("", "a");
+    case!("foo", "");
+    case!("foo", "foo");
+    case!("foo", "fooo");
+    case!("foo", "o");
+    case!("foo", "oo");
+    case!("foofoo", "foo");
+    case!("aaaa", "aa");
+    case!("abcabcab", "cab");
+    case!("aaab", "aab");
+    case!("abaabaab", "aabaab");
+    case!("lawlawn", "lawn");
+    case!("ñ個🧠ñ個🧠", "個🧠");
+    case!("hello world", "xyz");
+}
+
+#[test]
+fn test_str_find_char_pattern() {
+    case!("", 'a');
+    case!("a", 'a');
+    case!("foo.bar.baz", '.');
+    case!("foo.bar.baz", 'z');
+    case!("foo.bar.baz", 'q');
+    case!("ñ個🧠ñ個🧠", 'ñ');
+    case!("ñ個🧠ñ個🧠", '個');
+    case!("ñ個🧠ñ個🧠", '🧠');
+    case!("ñ個🧠ñ個🧠", 'n');
+}
+
+#[test]
+fn test_str_find_u8_pattern() {
+    case!("", b'a', 'a');
+    case!("a", b'a', 'a');
+    case!("foo.bar.baz", b'.', '.');
+    case!("foo.bar.baz", b'f', 'f');
+    case!("foo.bar.baz", b'z', 'z');
+    case!("foo.bar.baz", b'q', 'q');
+    case!("ñ個🧠", b'n', 'n');
+}
+
+#[test]
+fn test_str_find_in_const_context() {
+    const VERSION: &str = "1.57.0";
+
+    const MAJOR_END: usize = match str_find!(VERSION, '.') {
+        Some(x) => x,
+        None => VERSION.len(),
+    };
+    const MINOR_END: usize = match str_rfind!(VERSION, '.') {
+        Some(x) => x,
+        None => VERSION.len(),
+    };
+
+    const MAJOR: &str = str_index!(VERSION, ..MAJOR_END);
+    const MINOR: &str = str_index!(VERSION, MAJOR_END + 1..MINOR_END);
+    const PATCH: &str = str_index!(VERSION, MINOR_END + 1..);
+
+    assert_eq!(MAJOR, "1");
+    assert_eq!(MINOR, "57");
+    assert_eq!(PATCH, "0");
+
+    const HAS_DOT: bool = str_contains!(VERSION, '.');
+    const IS_V1: bool = str_starts_with!(VERSION, "1.");
+    const IS_PATCH0: bool = str_ends_with!(VERSION, ".0");
+
+    assert_eq!([HAS_DOT, IS_V1, IS_PATCH0], [true; 3]);
+}
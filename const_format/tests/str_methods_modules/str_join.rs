@@ -0,0 +1,65 @@
+use const_format::{str_concat_slice, str_join};
+
+#[test]
+fn test_str_join_separators() {
+    const STRS: &[&str] = &["foo", "bar", "baz"];
+
+    assert_eq!(str_join!(STRS, ", "), "foo, bar, baz");
+    assert_eq!(str_join!(STRS, ""), "foobarbaz");
+    assert_eq!(str_join!(STRS, ' '), "foo bar baz");
+    assert_eq!(str_join!(STRS, 'ñ'), "fooñbarñbaz");
+    assert_eq!(str_join!(STRS, '🧠'), "foo🧠bar🧠baz");
+    assert_eq!(str_join!(STRS, b'-'), "foo-bar-baz");
+    assert_eq!(str_join!(STRS, "个个"), "foo个个bar个个baz");
+}
+
+#[test]
+fn test_str_join_lengths() {
+    const EMPTY: &[&str] = &[];
+
+    assert_eq!(str_join!(EMPTY, ", "), "");
+    assert_eq!(str_join!(&["foo"], ", "), "foo");
+    assert_eq!(str_join!(&[""], ", "), "");
+    assert_eq!(str_join!(&["", ""], ", "), ", ");
+    assert_eq!(str_join!(&["", "", ""], '/'), "//");
+    assert_eq!(str_join!(&["a", "", "b", ""], "->"), "a->->b->");
+}
+
+#[test]
+fn test_str_join_argument_types() {
+    const SLICE: &[&str] = &["a", "b"];
+    const ARRAY: [&str; 2] = ["c", "d"];
+    const ARRAY_REF: &[&str; 2] = &["e", "f"];
+
+    assert_eq!(str_join!(SLICE, '+'), "a+b");
+    assert_eq!(str_join!(ARRAY, '+'), "c+d");
+    assert_eq!(str_join!(&ARRAY, '+'), "c+d");
+    assert_eq!(str_join!(ARRAY_REF, '+'), "e+f");
+    assert_eq!(str_join!(["g", "h"], '+'), "g+h");
+}
+
+#[test]
+fn test_str_concat_slice() {
+    const EMPTY: [&str; 0] = [];
+
+    assert_eq!(str_concat_slice!(EMPTY), "");
+    assert_eq!(str_concat_slice!(&["foo"]), "foo");
+    assert_eq!(str_concat_slice!(&["foo", "", "bar", "ñ"]), "foobarñ");
+    assert_eq!(str_concat_slice!(["🧡", "🧠"]), "🧡🧠");
+}
+
+#[cfg(feature = "rust_1_64")]
+#[test]
+fn test_str_split_join_roundtrip() {
+    use const_format::str_split;
+
+    const PATH: &str = "usr/local//bin/";
+
+    assert_eq!(str_join!(str_split!(PATH, '/'), '/'), PATH);
+    assert_eq!(
+        str_join!(str_split!(PATH, '/'), "::"),
+        "usr::local::::bin::"
+    );
+    assert_eq!(str_concat_slice!(str_split!(PATH, "/")), "usrlocalbin");
+    assert_eq!(str_join!(str_split!("ñ个🧠", ""), '.'), ".ñ.个.🧠.");
+}
@@ -0,0 +1,90 @@
+use const_format::{formatcp, str_parse};
+
+macro_rules! assert_parse_bounds {
+    ($($ty:ty),* $(,)*) => ({
+        $({
+            const MIN: &str = formatcp!("{}", <$ty>::MIN);
+            const MAX: &str = formatcp!("{}", <$ty>::MAX);
+            const MAX_HEX: &str = formatcp!("0x{:x}", <$ty>::MAX);
+            const MAX_BIN: &str = formatcp!("{:b}", <$ty>::MAX);
+
+            assert_eq!(str_parse!($ty, MIN), <$ty>::MIN);
+            assert_eq!(str_parse!($ty, MAX), <$ty>::MAX);
+            assert_eq!(str_parse!($ty, MAX_HEX), <$ty>::MAX);
+            assert_eq!(str_parse!($ty, MAX_BIN, radix = 2), <$ty>::MAX);
+            assert_eq!(str_parse!($ty, "0"), 0);
+            assert_eq!(str_parse!($ty, "+1_0"), 10);
+        })*
+    });
+}
+
+#[test]
+fn integer_bounds() {
+    assert_parse_bounds! {
+        u8, u16, u32, u64, u128, usize,
+        i8, i16, i32, i64, i128, isize,
+    }
+}
+
+#[test]
+fn matches_std() {
+    macro_rules! assert_matches_std {
+        ($ty:ty, $input:expr) => {{
+            const INPUT: &str = $input;
+            assert_eq!(
+                str_parse!($ty, INPUT),
+                INPUT.parse::<$ty>().unwrap(),
+                "{:?}",
+                INPUT,
+            );
+        }};
+        ($ty:ty, $input:expr, $radix:expr) => {{
+            const INPUT: &str = $input;
+            assert_eq!(
+                str_parse!($ty, INPUT, radix = $radix),
+                <$ty>::from_str_radix(INPUT, $radix).unwrap(),
+                "{:?}",
+                INPUT,
+            );
+        }};
+    }
+
+    assert_matches_std!(i32, "-2147483648");
+    assert_matches_std!(i32, "-7fffffff", 16);
+    assert_matches_std!(u32, "DeadBeef", 16);
+    assert_matches_std!(u64, "1y2p0ij32e8e7", 36);
+    assert_matches_std!(i16, "-777", 8);
+    assert_matches_std!(u8, "11111111", 2);
+    assert_matches_std!(i128, "-170141183460469231731687303715884105728");
+}
+
+#[test]
+fn prefixes_and_separators() {
+    assert_eq!(str_parse!(u32, "0xdead_BEEF"), 0xDEAD_BEEF);
+    assert_eq!(str_parse!(i32, "-0o7_7"), -0o77);
+    assert_eq!(str_parse!(i32, "+0b1010"), 0b1010);
+    assert_eq!(str_parse!(u32, "1_000_000"), 1_000_000);
+    assert_eq!(str_parse!(u32, "1__0__"), 10);
+    assert_eq!(str_parse!(u32, "0100"), 100);
+    assert_eq!(str_parse!(i8, "-0"), 0);
+    assert_eq!(str_parse!(u8, "+0"), 0);
+
+    // the prefix of the radix argument
+    assert_eq!(str_parse!(u32, "0xff", radix = 16), 0xFF);
+    assert_eq!(str_parse!(u32, "0b11", radix = 2), 3);
+
+    // `0b` is not a prefix in hexadecimal
+    assert_eq!(str_parse!(u32, "0b11", radix = 16), 0xB11);
+}
+
+#[test]
+fn const_arguments() {
+    const INPUT: &str = "zz";
+    const RADIX: u32 = 36;
+    const OUT: u16 = str_parse!(u16, INPUT, radix = RADIX);
+    assert_eq!(OUT, 36 * 36 - 1);
+
+    const FLAG: &str = "false";
+    const FLAG_B: bool = str_parse!(bool, FLAG);
+    assert_eq!([FLAG_B, str_parse!(bool, "true")], [false, true]);
+}
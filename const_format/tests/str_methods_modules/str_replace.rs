@@ -31,6 +31,13 @@ fn test_small_pattern() {
     assert_case! {"hequx", "qu", "XYZ", "heXYZx"}
 }
 
+#[test]
+fn test_pattern_overlapping_its_prefix() {
+    assert_case! {"aaab", "aab", "X", "aX"}
+    assert_case! {"abaabaab", "aabaab", "X", "abX"}
+    assert_case! {"aaabaaab", "aab", "XY", "aXYaXY"}
+}
+
 #[test]
 fn test_char_pattern() {
     {
@@ -0,0 +1,75 @@
+use const_format::{concatcp, str_scan};
+
+#[test]
+fn version_numbers() {
+    const VERSION: &str = "1.22.333";
+    const PARTS: (u32, u32, u32) = str_scan!("{}.{}.{}", VERSION => u32, u32, u32);
+    assert_eq!(PARTS, (1, 22, 333));
+
+    assert_eq!(str_scan!("v{}.{}", "v0.0" => u8, u16), (0, 0));
+    assert_eq!(str_scan!("v{}.{}", "v255.65535" => u8, u16), (255, 65535));
+}
+
+#[test]
+fn argument_types() {
+    assert_eq!(
+        str_scan!("{},{},{}", "-128,340282366920938463463374607431768211455,true" => i8, u128, bool),
+        (i8::MIN, u128::MAX, true),
+    );
+    assert_eq!(
+        str_scan!("[{};{}]", "[false;-0x7F]" => bool, i64),
+        (false, -0x7F),
+    );
+    assert_eq!(str_scan!("{}", "1_000" => usize), (1000,));
+}
+
+#[test]
+fn radix_arguments() {
+    assert_eq!(str_scan!("#{:x}", "#ff8000" => u32), (0xFF8000,));
+    assert_eq!(str_scan!("#{:X}", "#0xFF8000" => u32), (0xFF8000,));
+    assert_eq!(
+        str_scan!("{:o}/{:b}", "777/0b101" => u16, u8),
+        (0o777, 0b101)
+    );
+
+    // `0b` is not a prefix in hexadecimal
+    assert_eq!(str_scan!("{:x}", "0b1" => u16), (0xB1,));
+}
+
+#[test]
+fn literal_text() {
+    let () = str_scan!("", "" =>);
+    let () = str_scan!("abc", "abc" =>);
+    assert_eq!(str_scan!("{{{}}}", "{-3}" => i8), (-3,));
+    assert_eq!(
+        str_scan!(concat!("key=", "{}", ";"), "key=10;" => u8),
+        (10,),
+    );
+    assert_eq!(str_scan!(r"\{}\", r"\5\" => u8), (5,));
+    assert_eq!(str_scan!("ñ={}→{}", "ñ=3→4" => u8, u8), (3, 4));
+}
+
+#[test]
+fn arguments_end_at_first_separator() {
+    // the first argument ends at the first `.`, the rest goes to the last one
+    assert_eq!(str_scan!("{}.{}", "1.2" => u8, u8), (1, 2));
+    assert_eq!(str_scan!("{}, {}", "10, 20" => u8, u8), (10, 20));
+    assert_eq!(str_scan!("{}..{}", "3..4" => i8, i8), (3, 4));
+
+    // separators that overlap their own prefix
+    assert_eq!(str_scan!("{:x}aab", "1aaab" => u8), (0x1A,));
+    assert_eq!(
+        str_scan!("{:x}aabaab{}", "1abaabaab2" => u16, u8),
+        (0x1AB, 2)
+    );
+}
+
+#[test]
+fn const_input() {
+    const HOST: &str = "127.0.0.1:8080";
+    const PARTS: (u8, u8, u8, u8, u16) = str_scan!("{}.{}.{}.{}:{}", HOST => u8, u8, u8, u8, u16);
+    assert_eq!(PARTS, (127, 0, 0, 1, 8080));
+
+    const SIZE: &str = concatcp!(16u32, "x", 9u32);
+    assert_eq!(str_scan!("{}x{}", SIZE => u32, u32), (16, 9));
+}
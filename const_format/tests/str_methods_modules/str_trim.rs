@@ -0,0 +1,107 @@
+use const_format::{
+    str_trim, str_trim_end, str_trim_end_matches, str_trim_matches, str_trim_start,
+    str_trim_start_matches,
+};
+
+macro_rules! assert_subslice {
+    ($string:expr, $trimmed:expr, $expected:expr) => {{
+        let string: &str = $string;
+        let trimmed: &str = $trimmed;
+        assert_eq!(trimmed, $expected);
+
+        // ensuring that the trimmed string points into the original one
+        let start = trimmed.as_ptr() as usize - string.as_ptr() as usize;
+        assert_eq!(&string[start..start + trimmed.len()], trimmed);
+    }};
+}
+
+#[test]
+fn test_str_trim_whitespace() {
+    macro_rules! case {
+        ($string:expr) => {{
+            const S: &str = $string;
+            assert_subslice!(S, str_trim!(S), S.trim());
+            assert_subslice!(S, str_trim_start!(S), S.trim_start());
+            assert_subslice!(S, str_trim_end!(S), S.trim_end());
+        }};
+    }
+
+    case!("");
+    case!(" ");
+    case!(" \t\n\r\x0B\x0C ");
+    case!("foo");
+    case!("  foo  ");
+    case!("\n\nfoo bar\n\n");
+    case!("\r\nfoo\r\n");
+    case!(" \u{85}\u{A0}foo\u{1680}\u{2000}");
+    case!("\u{200A}\u{2028}\u{2029} foo \u{202F}\u{205F}\u{3000}");
+    case!("\u{200B}foo\u{200B}");
+    case!("  ñ個🧠  ");
+}
+
+#[test]
+fn test_str_trim_matches() {
+    macro_rules! case {
+        ($string:expr, $pattern:expr) => {{
+            const S: &str = $string;
+            assert_subslice!(S, str_trim_matches!(S, $pattern), S.trim_matches($pattern));
+            assert_subslice!(
+                S,
+                str_trim_start_matches!(S, $pattern),
+                S.trim_start_matches($pattern)
+            );
+            assert_subslice!(
+                S,
+                str_trim_end_matches!(S, $pattern),
+                S.trim_end_matches($pattern)
+            );
+        }};
+    }
+
+    case!("", 'a');
+    case!("aaa", 'a');
+    case!("aafooaa", 'a');
+    case!("aafooaa", 'b');
+    case!("ññfoo-ñ-ññ", 'ñ');
+    case!("🧠🧠foo🧠", '🧠');
+    case!("\"quoted\"", '"');
+}
+
+#[test]
+fn test_str_trim_matches_str_and_u8() {
+    const S: &str = "--foo--bar----";
+
+    assert_subslice!(S, str_trim_matches!(S, b'-'), "foo--bar");
+    assert_subslice!(S, str_trim_start_matches!(S, b'-'), "foo--bar----");
+    assert_subslice!(S, str_trim_end_matches!(S, b'-'), "--foo--bar");
+
+    // `str::trim_matches` doesn't take `&str` patterns,
+    // so this compares with the `trim_start_matches` and `trim_end_matches` methods.
+    macro_rules! str_case {
+        ($string:expr, $pattern:expr) => {{
+            const S: &str = $string;
+            let expected = S.trim_start_matches($pattern).trim_end_matches($pattern);
+            assert_subslice!(S, str_trim_matches!(S, $pattern), expected);
+            assert_subslice!(
+                S,
+                str_trim_start_matches!(S, $pattern),
+                S.trim_start_matches($pattern)
+            );
+            assert_subslice!(
+                S,
+                str_trim_end_matches!(S, $pattern),
+                S.trim_end_matches($pattern)
+            );
+        }};
+    }
+
+    str_case!("", "ab");
+    str_case!("foo", "");
+    str_case!("ababfooab", "ab");
+    str_case!("abafooaba", "ab");
+    str_case!("aba", "ab");
+    str_case!("abab", "ab");
+    str_case!("--foo--bar----", "-");
+    str_case!("个个foo个", "个");
+    str_case!("个个foo个", "个个");
+}
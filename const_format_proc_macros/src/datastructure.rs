@@ -47,6 +47,7 @@ impl<'a> DataStructure<'a> {
                         StructParams {
                             variant: variant,
                             name: &var.ident,
+                            attrs: &var.attrs,
                         },
                         &var.fields,
                     ));
@@ -58,6 +59,7 @@ impl<'a> DataStructure<'a> {
                     StructParams {
                         variant: 0,
                         name: name,
+                        attrs: &[],
                     },
                     &struct_.fields,
                 ));
@@ -71,6 +73,7 @@ impl<'a> DataStructure<'a> {
                     StructParams {
                         variant: 0,
                         name: name,
+                        attrs: &[],
                     },
                     sk,
                     fields,
@@ -121,6 +124,7 @@ pub struct FieldIndex {
 struct StructParams<'a> {
     variant: usize,
     name: &'a Ident,
+    attrs: &'a [Attribute],
 }
 
 /// A struct/union or a variant of an enum.
@@ -132,6 +136,11 @@ pub struct Struct<'a> {
     ///
     /// If this is an enum:this is the name of the variant.
     pub name: &'a Ident,
+    /// The attributes of this `Struct`.
+    ///
+    /// If this is a struct/union:this is empty,
+    /// the attributes are in DataStructure.attrs instead.
+    pub attrs: &'a [Attribute],
     pub kind: StructKind,
     pub fields: Vec<Field<'a>>,
     _priv: (),
@@ -164,6 +173,7 @@ impl<'a> Struct<'a> {
 
         Self {
             name: p.name,
+            attrs: p.attrs,
             kind,
             fields,
             _priv: (),
@@ -7,10 +7,13 @@ use quote::{quote, quote_spanned, quote_spanned as quote_s, ToTokens, TokenStrea
 use syn::{DeriveInput, Ident};
 
 mod attribute_parsing;
-mod syntax;
+pub(crate) mod syntax;
 mod type_detection;
 
-use self::attribute_parsing::HowToFmt;
+pub(crate) use self::{
+    attribute_parsing::{parse_the_is_a_attribute, with_nested_meta, HowToFmt},
+    type_detection::detect_type_formatting,
+};
 
 pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2, crate::Error> {
     let ds = &DataStructure::new(&input);
@@ -113,16 +116,27 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
                 Some(field_ts)
             });
 
+        let ignores_fields = variant
+            .fields
+            .iter()
+            .any(|f| matches!(config.field_map[f].how_to_fmt, HowToFmt::Ignore));
+
+        let finish_method = if config.non_exhaustive && ignores_fields {
+            Ident::new("finish_non_exhaustive", Span::call_site())
+        } else {
+            Ident::new("finish", Span::call_site())
+        };
+
         quote!(
             #enum_prefix #vname { #(#patt)* .. } => {
                 let mut formatter = formatter.#debug_method(stringify!(#vname));
                 #(#fmt_call)*
-                formatter.finish()
+                formatter.#finish_method()
             }
         )
     });
 
-    let ret = quote!(
+    let mut ret = quote!(
         #cratep::impl_fmt!{
             #impl_headers
 
@@ -139,12 +153,62 @@ pub(crate) fn derive_constdebug_impl(input: DeriveInput) -> Result<TokenStream2,
         }
     );
 
+    if config.std_debug {
+        ret.append_all(std_debug_impls(&cratep, ds, &config));
+    }
+
     if config.debug_print {
         panic!("\n\n\n{}\n\n\n", ret);
     }
     Ok(ret)
 }
 
+/// Implements `core::fmt::Debug` by delegating to the `const_debug_fmt` method.
+fn std_debug_impls(
+    cratep: &TokenStream2,
+    ds: &DataStructure<'_>,
+    config: &attribute_parsing::ConstDebugConfig<'_>,
+) -> TokenStream2 {
+    let impl_debug = |impl_params: TokenStream2, self_ty: TokenStream2, where_: TokenStream2| {
+        quote!(
+            impl #impl_params #cratep::pmr::core_fmt::Debug for #self_ty #where_ {
+                fn fmt(
+                    &self,
+                    f: &mut #cratep::pmr::core_fmt::Formatter<'_>,
+                ) -> #cratep::pmr::core_fmt::Result {
+                    #cratep::pmr::core_fmt::Debug::fmt(
+                        &#cratep::pmr::StdAdapter::new(self, Self::const_debug_fmt),
+                        f,
+                    )
+                }
+            }
+        )
+    };
+
+    if config.impls.is_empty() {
+        let name = ds.name;
+        let (impl_generics, ty_generics, where_clause) = ds.generics.split_for_impl();
+        impl_debug(
+            impl_generics.into_token_stream(),
+            quote!(#name #ty_generics),
+            where_clause.into_token_stream(),
+        )
+    } else {
+        config
+            .impls
+            .iter()
+            .map(|imp| {
+                let (impl_generics, _, where_clause) = imp.generics.split_for_impl();
+                impl_debug(
+                    impl_generics.into_token_stream(),
+                    imp.self_ty.to_token_stream(),
+                    where_clause.into_token_stream(),
+                )
+            })
+            .collect()
+    }
+}
+
 // Copying the definitino of the `const_format::coerce_to_fn` macro here
 // because the compiler points inside the coerce_to_fn macro otherwise
 fn coerce_and_fmt(cratep: &TokenStream2, field: &Field<'_>) -> TokenStream2 {
@@ -278,7 +342,7 @@ fn call_debug_fmt(
     })
 }
 
-fn get_where_clause_tokens(where_clause: &Option<syn::WhereClause>) -> TokenStream2 {
+pub(crate) fn get_where_clause_tokens(where_clause: &Option<syn::WhereClause>) -> TokenStream2 {
     match where_clause {
         Some(x) => {
             let preds = x.predicates.iter();
@@ -13,6 +13,8 @@ use std::marker::PhantomData;
 
 pub(crate) struct ConstDebugConfig<'a> {
     pub(crate) debug_print: bool,
+    pub(crate) non_exhaustive: bool,
+    pub(crate) std_debug: bool,
     pub(crate) crate_path: Option<syn::Path>,
     pub(crate) impls: Vec<ImplHeader>,
     pub(crate) field_map: FieldMap<FieldConfig<'a>>,
@@ -23,6 +25,8 @@ impl<'a> ConstDebugConfig<'a> {
     fn new(roa: ConstDebugAttrs<'a>) -> Result<Self, crate::Error> {
         let ConstDebugAttrs {
             debug_print,
+            non_exhaustive,
+            std_debug,
             crate_path,
             impls,
             field_map,
@@ -32,6 +36,8 @@ impl<'a> ConstDebugConfig<'a> {
 
         Ok(Self {
             debug_print,
+            non_exhaustive,
+            std_debug,
             crate_path,
             impls,
             field_map,
@@ -42,6 +48,8 @@ impl<'a> ConstDebugConfig<'a> {
 
 struct ConstDebugAttrs<'a> {
     debug_print: bool,
+    non_exhaustive: bool,
+    std_debug: bool,
     crate_path: Option<syn::Path>,
     impls: Vec<ImplHeader>,
     field_map: FieldMap<FieldConfig<'a>>,
@@ -90,6 +98,8 @@ pub(crate) fn parse_attrs_for_derive<'a>(
 ) -> Result<ConstDebugConfig<'a>, crate::Error> {
     let mut this = ConstDebugAttrs {
         debug_print: false,
+        non_exhaustive: false,
+        std_debug: false,
         crate_path: None,
         impls: Vec::new(),
         field_map: FieldMap::with(ds, |f| FieldConfig {
@@ -210,6 +220,10 @@ fn parse_sabi_attr<'a>(
         (ParseContext::TypeAttr { .. }, Meta::Path(path)) => {
             if path.is_ident("debug_print") {
                 this.debug_print = true;
+            } else if path.is_ident("non_exhaustive") {
+                this.non_exhaustive = true;
+            } else if path.is_ident("std_debug") {
+                this.std_debug = true;
             } else {
                 return Err(make_err(&path));
             }
@@ -242,7 +256,7 @@ fn parse_sabi_attr<'a>(
 
 ///////////////////////////////////////////////////////////////////////////////
 
-fn parse_the_is_a_attribute<'a>(
+pub(crate) fn parse_the_is_a_attribute<'a>(
     attr: syn::Meta,
     f: &Field<'a>,
 ) -> Result<HowToFmt<'a>, crate::Error> {
@@ -2,7 +2,7 @@ use super::HowToFmt;
 
 use syn::Type;
 
-pub(super) fn detect_type_formatting(ty: &Type) -> HowToFmt {
+pub(crate) fn detect_type_formatting(ty: &Type) -> HowToFmt {
     let ty = unwrap_reference(ty);
 
     // println!("{:?} {}", ty, ty.to_token_stream());
@@ -0,0 +1,291 @@
+use crate::{
+    datastructure::{DataStructure, DataVariant, Field, StructKind},
+    derive_debug::get_where_clause_tokens,
+    format_args::{ExpandCount, ExpandFormatted, ExpandInto},
+    format_str::{Count, FmtArg, FmtStrComponent, FormatStr, WhichArg},
+    utils::LinearResult,
+};
+
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+
+use quote::{quote, ToTokens, TokenStreamExt};
+
+use syn::DeriveInput;
+
+mod attribute_parsing;
+
+pub(crate) fn derive_constdisplay_impl(input: DeriveInput) -> Result<TokenStream2, crate::Error> {
+    let ds = &DataStructure::new(&input);
+    let config = attribute_parsing::parse_attrs_for_derive(ds)?;
+    let cratep = match &config.crate_path {
+        Some(p) => p.to_token_stream(),
+        None => quote!(::const_format),
+    };
+
+    let vis = ds.vis;
+
+    let name = ds.name;
+
+    let enum_prefix = match ds.data_variant {
+        DataVariant::Enum => quote!(#name::),
+        DataVariant::Struct => TokenStream2::new(),
+        DataVariant::Union => panic!("Cannot derive ConstDisplay on unions"),
+    };
+
+    let formatter = Ident::new("formatter", Span::mixed_site());
+
+    let mut res = LinearResult::ok();
+
+    let variant_branches = ds
+        .variants
+        .iter()
+        .zip(config.format_strs)
+        .map(|(variant, format_str)| {
+            let vname = variant.name;
+
+            let mut used_fields = vec![false; variant.fields.len()];
+
+            let expanded_into = resolve_fields(variant.kind, &variant.fields, format_str)
+                .unwrap_or_else(|(expanded_into, e)| {
+                    res.push_err(e);
+                    expanded_into
+                });
+
+            for ei in &expanded_into {
+                mark_used_fields(ei, &variant.fields, &mut used_fields);
+            }
+
+            let patt = variant
+                .fields
+                .iter()
+                .zip(&used_fields)
+                .filter(|(_, is_used)| **is_used)
+                .map(|(f, _)| {
+                    let pat = &f.ident;
+                    let variable = f.pattern_ident();
+                    quote!(#pat : #variable,)
+                });
+
+            let fmt_call = expanded_into.iter().map(|ei| ei.fmt_call(&formatter));
+
+            quote!(
+                #enum_prefix #vname { #(#patt)* .. } => {
+                    #(
+                        __cf_osRcTFl4A::try_!(#fmt_call);
+                    )*
+                    __cf_osRcTFl4A::pmr::Ok(())
+                }
+            )
+        })
+        .collect::<Vec<TokenStream2>>();
+
+    res.take()?;
+
+    let method = quote!(
+        #vis const fn const_display_fmt(
+            &self,
+            #formatter: &mut #cratep::pmr::Formatter<'_>,
+        ) -> #cratep::pmr::Result<(), #cratep::pmr::Error> {
+            use #cratep::__cf_osRcTFl4A;
+
+            match self {
+                #(
+                    #variant_branches
+                )*
+            }
+        }
+    );
+
+    let ret = if config.format_marker {
+        let mut impl_headers;
+
+        if config.impls.is_empty() {
+            let impl_params = ds.generics.params.iter();
+            let (_, tygen, _) = ds.generics.split_for_impl();
+            let where_clause = get_where_clause_tokens(&ds.generics.where_clause);
+
+            impl_headers = quote!(
+                impl[#( #impl_params ,)*] #name #tygen
+                #where_clause;
+            );
+        } else {
+            impl_headers = TokenStream2::new();
+
+            for imp in config.impls.iter() {
+                let params = imp.generics.params.iter();
+                let self_ty = &imp.self_ty;
+                let where_clause = get_where_clause_tokens(&imp.generics.where_clause);
+
+                impl_headers.append_all(quote!(
+                    impl[#(#params)*] #self_ty
+                    #where_clause;
+                ));
+            }
+        }
+
+        quote!(
+            #cratep::impl_fmt!{
+                #impl_headers
+
+                #method
+            }
+        )
+    } else if config.impls.is_empty() {
+        let (impl_generics, tygen, where_clause) = ds.generics.split_for_impl();
+
+        quote!(
+            impl #impl_generics #name #tygen #where_clause {
+                #method
+            }
+        )
+    } else {
+        let mut impls = TokenStream2::new();
+
+        for imp in config.impls.iter() {
+            let (impl_generics, _, where_clause) = imp.generics.split_for_impl();
+            let self_ty = &imp.self_ty;
+
+            impls.append_all(quote!(
+                impl #impl_generics #self_ty #where_clause {
+                    #method
+                }
+            ));
+        }
+
+        impls
+    };
+
+    if config.debug_print {
+        panic!("\n\n\n{}\n\n\n", ret);
+    }
+    Ok(ret)
+}
+
+/// Resolves the arguments in the format string to the fields of the struct/variant,
+/// treating identifiers that aren't field names as constants from the enclosing scope.
+fn resolve_fields(
+    kind: StructKind,
+    fields: &[Field<'_>],
+    format_str: FormatStr,
+) -> Result<Vec<ExpandInto>, (Vec<ExpandInto>, crate::Error)> {
+    let mut res = LinearResult::ok();
+    let mut current_pos_arg = 0;
+
+    let mut resolve_arg = |which_arg: WhichArg, span: Span| -> Ident {
+        let field = match which_arg {
+            WhichArg::Ident(ident) => {
+                let field = match kind {
+                    StructKind::Braced => fields.iter().find(|f| f.ident.to_string() == ident),
+                    StructKind::Tupled => None,
+                };
+                match field {
+                    Some(field) => field,
+                    // `#[cdisplay("{FOO}")]` assumes that FOO is a constant in scope
+                    None => return Ident::new(&ident, span),
+                }
+            }
+            WhichArg::Positional(opt_pos) => {
+                let pos = opt_pos.unwrap_or_else(|| {
+                    let pos = current_pos_arg;
+                    current_pos_arg += 1;
+                    pos
+                });
+
+                match (kind, fields.get(pos)) {
+                    (StructKind::Tupled, Some(field)) => field,
+                    (StructKind::Tupled, None) => {
+                        res.push_err(crate::Error::new(
+                            span,
+                            format!("attempting to use nonexistent field `{}`", pos),
+                        ));
+                        return crate::utils::dummy_ident();
+                    }
+                    (StructKind::Braced, _) => {
+                        res.push_err(crate::Error::new(
+                            span,
+                            "positional arguments can only be used in tuple structs/variants, \
+                             use the name of the field instead",
+                        ));
+                        return crate::utils::dummy_ident();
+                    }
+                }
+            }
+        };
+
+        let mut ident = field.pattern_ident().clone();
+        ident.set_span(span);
+        ident
+    };
+
+    let mut get_expansion = |param: FmtArg| -> ExpandInto {
+        let FmtArg {
+            which_arg,
+            formatting,
+            width,
+            precision,
+            rawness,
+        } = param;
+
+        let span = rawness.span();
+
+        let mut resolve_count = |count: Count| match count {
+            Count::Literal(x) => ExpandCount::Literal(x),
+            Count::Arg(which_arg) => {
+                let ident = resolve_arg(which_arg, span);
+                if is_field_ident(&ident, fields) {
+                    ExpandCount::Local(ident)
+                } else {
+                    ExpandCount::Constant(ident)
+                }
+            }
+        };
+
+        let width = width.map(&mut resolve_count);
+        // resolved before the formatted argument,
+        // because `{:.*}` takes the precision from the next positional argument.
+        let precision = precision.map(&mut resolve_count);
+
+        ExpandInto::Formatted(ExpandFormatted {
+            format: formatting,
+            width,
+            precision,
+            local_variable: resolve_arg(which_arg, span),
+        })
+    };
+
+    let expanded_into = format_str
+        .list
+        .into_iter()
+        .map(|fmt_str_comp| match fmt_str_comp {
+            FmtStrComponent::Str(str, str_rawness) => ExpandInto::Str(str, str_rawness),
+            FmtStrComponent::Arg(arg) => get_expansion(arg),
+        })
+        .collect::<Vec<ExpandInto>>();
+
+    match res.take() {
+        Ok(()) => Ok(expanded_into),
+        Err(e) => Err((expanded_into, e)),
+    }
+}
+
+fn is_field_ident(ident: &Ident, fields: &[Field<'_>]) -> bool {
+    fields.iter().any(|f| f.pattern_ident() == ident)
+}
+
+fn mark_used_fields(ei: &ExpandInto, fields: &[Field<'_>], used_fields: &mut [bool]) {
+    let mut mark = |ident: &Ident| {
+        if let Some(pos) = fields.iter().position(|f| f.pattern_ident() == ident) {
+            used_fields[pos] = true;
+        }
+    };
+
+    if let ExpandInto::Formatted(fmted) = ei {
+        mark(&fmted.local_variable);
+
+        for count in [&fmted.width, &fmted.precision].iter().copied().flatten() {
+            if let ExpandCount::Local(ident) = count {
+                mark(ident);
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use crate::{
+    datastructure::{DataStructure, DataVariant},
+    derive_debug::syntax::ImplHeader,
+    format_str::FormatStr,
+    parse_utils::LitStr,
+    utils::LinearResult,
+};
+
+use quote::ToTokens;
+
+use syn::{Attribute, Meta, MetaList, NestedMeta};
+
+use std::mem;
+
+pub(crate) struct ConstDisplayConfig {
+    pub(crate) debug_print: bool,
+    pub(crate) format_marker: bool,
+    pub(crate) crate_path: Option<syn::Path>,
+    pub(crate) impls: Vec<ImplHeader>,
+    /// The format string of each variant, or of the struct.
+    pub(crate) format_strs: Vec<FormatStr>,
+}
+
+struct ConstDisplayAttrs {
+    debug_print: bool,
+    format_marker: bool,
+    crate_path: Option<syn::Path>,
+    impls: Vec<ImplHeader>,
+    format_strs: Vec<Option<FormatStr>>,
+    errors: LinearResult,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Copy, Clone)]
+enum ParseContext {
+    TypeAttr,
+    Variant { index: usize },
+    Field,
+}
+
+pub(crate) fn parse_attrs_for_derive<'a>(
+    ds: &'a DataStructure<'a>,
+) -> Result<ConstDisplayConfig, crate::Error> {
+    let mut this = ConstDisplayAttrs {
+        debug_print: false,
+        format_marker: false,
+        crate_path: None,
+        impls: Vec::new(),
+        format_strs: ds.variants.iter().map(|_| None).collect(),
+        errors: LinearResult::ok(),
+    };
+
+    parse_inner(&mut this, ds, ds.attrs, ParseContext::TypeAttr)?;
+
+    for (index, variant) in ds.variants.iter().enumerate() {
+        parse_inner(
+            &mut this,
+            ds,
+            variant.attrs,
+            ParseContext::Variant { index },
+        )?;
+
+        for field in variant.fields.iter() {
+            parse_inner(&mut this, ds, field.attrs, ParseContext::Field)?;
+        }
+    }
+
+    let mut format_strs = Vec::with_capacity(ds.variants.len());
+    for (variant, format_str) in ds.variants.iter().zip(this.format_strs.drain(..)) {
+        match format_str {
+            Some(x) => format_strs.push(x),
+            None => {
+                let msg = match ds.data_variant {
+                    DataVariant::Enum => format!(
+                        "expected a `#[cdisplay(\"...\")]` attribute on the `{}` variant",
+                        variant.name,
+                    ),
+                    _ => "expected a `#[cdisplay(\"...\")]` attribute on the type".to_string(),
+                };
+                this.errors.push_err(spanned_err!(variant.name, "{}", msg));
+            }
+        }
+    }
+
+    this.errors.take()?;
+
+    Ok(ConstDisplayConfig {
+        debug_print: this.debug_print,
+        format_marker: this.format_marker,
+        crate_path: this.crate_path.take(),
+        impls: mem::take(&mut this.impls),
+        format_strs,
+    })
+}
+
+/// Parses an individual attribute
+fn parse_inner<'a, I>(
+    this: &mut ConstDisplayAttrs,
+    ds: &'a DataStructure<'a>,
+    attrs: I,
+    pctx: ParseContext,
+) -> Result<(), crate::Error>
+where
+    I: IntoIterator<Item = &'a Attribute>,
+{
+    for attr in attrs {
+        match attr.parse_meta() {
+            Ok(Meta::List(list)) => {
+                let x = parse_attr_list(this, ds, pctx, list);
+                this.errors.combine_err(x);
+            }
+            Ok(Meta::Path(path)) if path.is_ident("cdisplay") => {
+                this.errors.push_err(make_err(&path));
+            }
+            Err(e) if attr.path.is_ident("cdisplay") => {
+                this.errors.push_err(e);
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Parses an individual attribute list (A `#[attribute( .. )] attribute`).
+fn parse_attr_list(
+    this: &mut ConstDisplayAttrs,
+    ds: &DataStructure<'_>,
+    pctx: ParseContext,
+    list: MetaList,
+) -> Result<(), crate::Error> {
+    if list.path.is_ident("cdisplay") {
+        for nested in list.nested {
+            let x = parse_cdisplay_attr(this, ds, pctx, nested);
+            this.errors.combine_err(x);
+        }
+    }
+
+    Ok(())
+}
+
+fn make_err(tokens: &dyn ToTokens) -> crate::Error {
+    spanned_err!(tokens, "unrecognized attribute")
+}
+
+/// Parses the contents of a `#[cdisplay( .. )]` attribute.
+fn parse_cdisplay_attr(
+    this: &mut ConstDisplayAttrs,
+    ds: &DataStructure<'_>,
+    pctx: ParseContext,
+    attr: NestedMeta,
+) -> Result<(), crate::Error> {
+    match (pctx, attr) {
+        (ParseContext::TypeAttr, NestedMeta::Lit(lit)) => {
+            if let DataVariant::Enum = ds.data_variant {
+                return_spanned_err!(
+                    lit,
+                    "the format strings of enums go on each variant, \
+                     with the `#[cdisplay(\"...\")]` attribute",
+                );
+            }
+            set_format_str(&mut this.format_strs[0], &lit)?;
+        }
+        (ParseContext::Variant { index }, NestedMeta::Lit(lit)) => {
+            set_format_str(&mut this.format_strs[index], &lit)?;
+        }
+        (ParseContext::TypeAttr, NestedMeta::Meta(Meta::Path(path))) => {
+            if path.is_ident("debug_print") {
+                this.debug_print = true;
+            } else if path.is_ident("format_marker") {
+                this.format_marker = true;
+            } else {
+                return Err(make_err(&path));
+            }
+        }
+        (ParseContext::TypeAttr, NestedMeta::Meta(Meta::NameValue(nv))) => {
+            if nv.path.is_ident("crate") {
+                this.crate_path = Some(parse_lit(&nv.lit)?);
+            } else {
+                return Err(make_err(&nv));
+            }
+        }
+        (ParseContext::TypeAttr, NestedMeta::Meta(Meta::List(list))) => {
+            if list.path.is_ident("impls") {
+                for x in list.nested {
+                    let lit = match x {
+                        NestedMeta::Meta(attr) => return Err(make_err(&attr)),
+                        NestedMeta::Lit(lit) => lit,
+                    };
+                    this.impls.push(parse_lit::<ImplHeader>(&lit)?);
+                }
+            } else {
+                return Err(make_err(&list));
+            }
+        }
+        (_, x) => return Err(make_err(&x)),
+    }
+    Ok(())
+}
+
+fn set_format_str(slot: &mut Option<FormatStr>, lit: &syn::Lit) -> Result<(), crate::Error> {
+    if slot.is_some() {
+        return_spanned_err!(lit, "cannot have more than one format string");
+    }
+
+    let lit = match lit {
+        syn::Lit::Str(x) => LitStr::parse_from_literal(&x.token())?,
+        _ => return_spanned_err!(lit, "expected a format string literal"),
+    };
+
+    match FormatStr::parse(lit.value(), lit.rawness) {
+        Ok(format_str) => {
+            *slot = Some(format_str);
+            Ok(())
+        }
+        Err(e) => {
+            // So that the missing format string error isn't reported for this type/variant.
+            *slot = Some(FormatStr { list: Vec::new() });
+            Err(e.into_crate_err(lit.span, lit.value()))
+        }
+    }
+}
+
+fn parse_lit<T>(lit: &syn::Lit) -> Result<T, crate::Error>
+where
+    T: syn::parse::Parse,
+{
+    match lit {
+        syn::Lit::Str(x) => x.parse().map_err(crate::Error::from),
+        _ => Err(spanned_err!(
+            lit,
+            "Expected string literal containing identifier"
+        )),
+    }
+}
@@ -0,0 +1,341 @@
+use crate::{
+    datastructure::{DataStructure, DataVariant, Field},
+    derive_debug::HowToFmt,
+};
+
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+
+use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
+
+use syn::DeriveInput;
+
+mod attribute_parsing;
+
+/// Which comparison method is derived.
+#[derive(Copy, Clone)]
+enum Comparison {
+    /// `ConstEq`, deriving `const fn const_eq(&self, other: &Self) -> bool`
+    Eq,
+    /// `ConstCmp`, deriving `const fn const_cmp(&self, other: &Self) -> Ordering`
+    Cmp,
+}
+
+impl Comparison {
+    const fn derive_name(self) -> &'static str {
+        match self {
+            Comparison::Eq => "ConstEq",
+            Comparison::Cmp => "ConstCmp",
+        }
+    }
+
+    const fn attr_name(self) -> &'static str {
+        match self {
+            Comparison::Eq => "ceq",
+            Comparison::Cmp => "ccmp",
+        }
+    }
+
+    fn method(self) -> Ident {
+        let name = match self {
+            Comparison::Eq => "const_eq",
+            Comparison::Cmp => "const_cmp",
+        };
+        Ident::new(name, Span::call_site())
+    }
+}
+
+pub(crate) fn derive_consteq_impl(input: DeriveInput) -> Result<TokenStream2, crate::Error> {
+    derive_comparison_impl(input, Comparison::Eq)
+}
+
+pub(crate) fn derive_constcmp_impl(input: DeriveInput) -> Result<TokenStream2, crate::Error> {
+    derive_comparison_impl(input, Comparison::Cmp)
+}
+
+fn derive_comparison_impl(
+    input: DeriveInput,
+    comparison: Comparison,
+) -> Result<TokenStream2, crate::Error> {
+    let ds = &DataStructure::new(&input);
+    let config = attribute_parsing::parse_attrs_for_derive(ds, comparison.attr_name())?;
+    let cratep = match &config.crate_path {
+        Some(p) => p.to_token_stream(),
+        None => quote!(::const_format),
+    };
+
+    let vis = ds.vis;
+
+    let name = ds.name;
+
+    let enum_prefix = match ds.data_variant {
+        DataVariant::Enum => quote!(#name::),
+        DataVariant::Struct => TokenStream2::new(),
+        DataVariant::Union => panic!("Cannot derive {} on unions", comparison.derive_name()),
+    };
+
+    let method = &comparison.method();
+
+    let variant_branches = ds.variants.iter().map(|variant| {
+        let vname = variant.name;
+
+        let compared = variant
+            .fields
+            .iter()
+            .filter(|f| !matches!(config.field_map[*f], HowToFmt::Ignore))
+            .collect::<Vec<&Field<'_>>>();
+
+        let left_patt = compared.iter().map(|f| {
+            let pat = &f.ident;
+            let variable = f.pattern_ident();
+            quote!(#pat : #variable,)
+        });
+
+        let right_patt = compared.iter().map(|f| {
+            let pat = &f.ident;
+            let variable = right_ident(f);
+            quote!(#pat : #variable,)
+        });
+
+        let comparisons = compared.iter().map(|f| {
+            let left = f.pattern_ident();
+            let right = &right_ident(f);
+            let span = left.span();
+
+            let cond = match &config.field_map[*f] {
+                HowToFmt::Regular => compare_coerced(&cratep, method, left, right, span),
+                HowToFmt::Ignore => unreachable!(),
+                HowToFmt::Slice => compare_slice(&cratep, comparison, left, right, span),
+                HowToFmt::Option_ => compare_option(&cratep, comparison, left, right, span),
+                HowToFmt::Newtype(_) => compare_coerced(
+                    &cratep,
+                    method,
+                    quote_spanned!(span=> &#left.0),
+                    quote_spanned!(span=> &#right.0),
+                    span,
+                ),
+                HowToFmt::With(func) => quote_spanned!(span=> #func(#left, #right)),
+                HowToFmt::WithMacro(macr) => quote_spanned!(span=> #macr!(#left, #right)),
+                HowToFmt::WithWrapper(newtype) => {
+                    quote_spanned!(span=> #newtype(#left).#method(&#newtype(#right)))
+                }
+            };
+
+            match comparison {
+                Comparison::Eq => quote_spanned!(span=>
+                    let are_eq: bool = #cond;
+                    if !are_eq {
+                        return false;
+                    }
+                ),
+                Comparison::Cmp => quote_spanned!(span=>
+                    match #cond {
+                        #cratep::pmr::Ordering::Equal => {}
+                        ordering => return ordering,
+                    }
+                ),
+            }
+        });
+
+        let all_equal = match comparison {
+            Comparison::Eq => quote!(true),
+            Comparison::Cmp => quote!(#cratep::pmr::Ordering::Equal),
+        };
+
+        quote!(
+            (
+                #enum_prefix #vname { #(#left_patt)* .. },
+                #enum_prefix #vname { #(#right_patt)* .. },
+            ) => {
+                #(#comparisons)*
+                #all_equal
+            }
+        )
+    });
+
+    let body = match (ds.data_variant, ds.variants.len()) {
+        (DataVariant::Enum, 0) => quote!(match *self {}),
+        (DataVariant::Enum, len) if len > 1 => {
+            let different_variants = match comparison {
+                Comparison::Eq => quote!(false),
+                Comparison::Cmp => {
+                    let vnames = ds.variants.iter().map(|v| v.name).collect::<Vec<_>>();
+                    let indices = 0..vnames.len();
+                    let variant_index = quote!(
+                        #( #enum_prefix #vnames {..} => #indices, )*
+                    );
+
+                    quote!({
+                        let left_index: usize = match self { #variant_index };
+                        let right_index: usize = match other { #variant_index };
+                        if left_index < right_index {
+                            #cratep::pmr::Ordering::Less
+                        } else {
+                            #cratep::pmr::Ordering::Greater
+                        }
+                    })
+                }
+            };
+
+            quote!(
+                match (self, other) {
+                    #(#variant_branches)*
+                    _ => #different_variants,
+                }
+            )
+        }
+        _ => quote!(
+            match (self, other) {
+                #(#variant_branches)*
+            }
+        ),
+    };
+
+    let method = match comparison {
+        Comparison::Eq => quote!(
+            #vis const fn const_eq(&self, other: &Self) -> bool {
+                #body
+            }
+        ),
+        Comparison::Cmp => quote!(
+            #vis const fn const_cmp(&self, other: &Self) -> #cratep::pmr::Ordering {
+                #body
+            }
+        ),
+    };
+
+    let ret = if config.impls.is_empty() {
+        let (impl_generics, tygen, where_clause) = ds.generics.split_for_impl();
+
+        quote!(
+            impl #impl_generics #name #tygen #where_clause {
+                #method
+            }
+        )
+    } else {
+        let mut impls = TokenStream2::new();
+
+        for imp in config.impls.iter() {
+            let (impl_generics, _, where_clause) = imp.generics.split_for_impl();
+            let self_ty = &imp.self_ty;
+
+            impls.append_all(quote!(
+                impl #impl_generics #self_ty #where_clause {
+                    #method
+                }
+            ));
+        }
+
+        impls
+    };
+
+    if config.debug_print {
+        panic!("\n\n\n{}\n\n\n", ret);
+    }
+    Ok(ret)
+}
+
+/// The variable that the field of `other` is bound to.
+fn right_ident(field: &Field<'_>) -> Ident {
+    let left = field.pattern_ident();
+    Ident::new(&format!("{}_rhs", left), left.span())
+}
+
+// Same as the coercion in the `const_format::coerce_to_fmt` macro,
+// calling the comparison method on the coerced left operand.
+fn compare_coerced(
+    cratep: &TokenStream2,
+    method: &Ident,
+    left: impl ToTokens,
+    right: impl ToTokens,
+    span: Span,
+) -> TokenStream2 {
+    quote_spanned!(span=>{
+        // Importing it like this because the error span is wrong otherwise
+        use #cratep::pmr::IsAFormatMarker as __IsAFormatMarker;
+
+        let mut marker = __IsAFormatMarker::NEW;
+        if false {
+            marker = marker.infer_type(#left);
+        }
+        marker.coerce(marker.unreference(#left)).#method(#right)
+    })
+}
+
+fn compare_slice(
+    cratep: &TokenStream2,
+    comparison: Comparison,
+    left: &Ident,
+    right: &Ident,
+    span: Span,
+) -> TokenStream2 {
+    let cond = compare_coerced(
+        cratep,
+        &comparison.method(),
+        quote_spanned!(span=> &#left[n]),
+        quote_spanned!(span=> &#right[n]),
+        span,
+    );
+
+    match comparison {
+        Comparison::Eq => quote_spanned!(span=>{
+            let len = #left.len();
+            let mut are_eq = len == #right.len();
+            let mut n = 0;
+            while are_eq && n != len {
+                are_eq = #cond;
+                n += 1;
+            }
+            are_eq
+        }),
+        // Compares lexicographically, the shorter slice is less if it's a prefix of the other.
+        Comparison::Cmp => quote_spanned!(span=>{
+            let mut ordering = #cratep::pmr::PWrapper(#left.len()).const_cmp(&#right.len());
+            let mut n = 0;
+            while n != #left.len() && n != #right.len() {
+                match #cond {
+                    #cratep::pmr::Ordering::Equal => n += 1,
+                    elem_ordering => {
+                        ordering = elem_ordering;
+                        break;
+                    }
+                }
+            }
+            ordering
+        }),
+    }
+}
+
+fn compare_option(
+    cratep: &TokenStream2,
+    comparison: Comparison,
+    left: &Ident,
+    right: &Ident,
+    span: Span,
+) -> TokenStream2 {
+    let cond = compare_coerced(
+        cratep,
+        &comparison.method(),
+        quote_spanned!(span=> l),
+        quote_spanned!(span=> r),
+        span,
+    );
+
+    match comparison {
+        Comparison::Eq => quote_spanned!(span=>
+            match (#left, #right) {
+                (#cratep::pmr::Some(l), #cratep::pmr::Some(r)) => #cond,
+                (#cratep::pmr::None, #cratep::pmr::None) => true,
+                _ => false,
+            }
+        ),
+        // `None` is less than `Some`, like in the `Ord` impl of `Option`.
+        Comparison::Cmp => quote_spanned!(span=>
+            match (#left, #right) {
+                (#cratep::pmr::Some(l), #cratep::pmr::Some(r)) => #cond,
+                (#cratep::pmr::None, #cratep::pmr::None) => #cratep::pmr::Ordering::Equal,
+                (#cratep::pmr::None, #cratep::pmr::Some(_)) => #cratep::pmr::Ordering::Less,
+                (#cratep::pmr::Some(_), #cratep::pmr::None) => #cratep::pmr::Ordering::Greater,
+            }
+        ),
+    }
+}
@@ -0,0 +1,214 @@
+use crate::{
+    datastructure::{DataStructure, Field, FieldMap},
+    derive_debug::{
+        detect_type_formatting, parse_the_is_a_attribute, syntax::ImplHeader, with_nested_meta,
+        HowToFmt,
+    },
+    utils::LinearResult,
+};
+
+use quote::ToTokens;
+
+use syn::{Attribute, Meta, MetaList, NestedMeta};
+
+use std::mem;
+
+pub(crate) struct ConstEqConfig<'a> {
+    pub(crate) debug_print: bool,
+    pub(crate) crate_path: Option<syn::Path>,
+    pub(crate) impls: Vec<ImplHeader>,
+    /// How each field is compared,
+    /// `HowToFmt::With*` hold the function/macro/wrapper used for the comparison.
+    pub(crate) field_map: FieldMap<HowToFmt<'a>>,
+}
+
+struct ConstEqAttrs<'a> {
+    /// The name of the helper attribute, `ceq` or `ccmp`.
+    attr_name: &'static str,
+    debug_print: bool,
+    crate_path: Option<syn::Path>,
+    impls: Vec<ImplHeader>,
+    field_map: FieldMap<HowToFmt<'a>>,
+    errors: LinearResult,
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Copy, Clone)]
+enum ParseContext<'a> {
+    TypeAttr,
+    Field { field: &'a Field<'a> },
+}
+
+pub(crate) fn parse_attrs_for_derive<'a>(
+    ds: &'a DataStructure<'a>,
+    attr_name: &'static str,
+) -> Result<ConstEqConfig<'a>, crate::Error> {
+    let mut this = ConstEqAttrs {
+        attr_name,
+        debug_print: false,
+        crate_path: None,
+        impls: Vec::new(),
+        field_map: FieldMap::with(ds, |f| detect_type_formatting(f.ty)),
+        errors: LinearResult::ok(),
+    };
+
+    parse_inner(&mut this, ds.attrs, ParseContext::TypeAttr)?;
+
+    for variant in &ds.variants {
+        for field in variant.fields.iter() {
+            parse_inner(&mut this, field.attrs, ParseContext::Field { field })?;
+        }
+    }
+
+    this.errors.take()?;
+
+    Ok(ConstEqConfig {
+        debug_print: this.debug_print,
+        crate_path: this.crate_path.take(),
+        impls: mem::take(&mut this.impls),
+        field_map: this.field_map,
+    })
+}
+
+/// Parses an individual attribute
+fn parse_inner<'a, I>(
+    this: &mut ConstEqAttrs<'a>,
+    attrs: I,
+    pctx: ParseContext<'a>,
+) -> Result<(), crate::Error>
+where
+    I: IntoIterator<Item = &'a Attribute>,
+{
+    for attr in attrs {
+        match attr.parse_meta() {
+            Ok(Meta::List(list)) => {
+                let x = parse_attr_list(this, pctx, list);
+                this.errors.combine_err(x);
+            }
+            Ok(Meta::Path(path)) if path.is_ident(this.attr_name) => {
+                this.errors.push_err(make_err(&path));
+            }
+            Err(e) if attr.path.is_ident(this.attr_name) => {
+                this.errors.push_err(e);
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Parses an individual attribute list (A `#[attribute( .. )] attribute`).
+fn parse_attr_list<'a>(
+    this: &mut ConstEqAttrs<'a>,
+    pctx: ParseContext<'a>,
+    list: MetaList,
+) -> Result<(), crate::Error> {
+    if list.path.is_ident(this.attr_name) {
+        with_nested_meta(this.attr_name, list.nested, |attr| {
+            let x = parse_helper_attr(this, pctx, attr);
+            this.errors.combine_err(x);
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+fn make_err(tokens: &dyn ToTokens) -> crate::Error {
+    spanned_err!(tokens, "unrecognized attribute")
+}
+
+/// Parses the contents of a `#[ceq( .. )]`/`#[ccmp( .. )]` attribute.
+fn parse_helper_attr<'a>(
+    this: &mut ConstEqAttrs<'a>,
+    pctx: ParseContext<'a>,
+    attr: Meta,
+) -> Result<(), crate::Error> {
+    let attr_name = this.attr_name;
+
+    match (pctx, attr) {
+        (ParseContext::Field { field }, Meta::Path(path)) => {
+            if path.is_ident("ignore") {
+                this.field_map[field.index] = HowToFmt::Ignore;
+            } else {
+                return Err(make_err(&path));
+            }
+        }
+        (ParseContext::Field { field }, Meta::NameValue(nv)) => {
+            let how = &mut this.field_map[field.index];
+
+            if nv.path.is_ident("with") {
+                *how = HowToFmt::With(parse_lit(&nv.lit)?);
+            } else if nv.path.is_ident("with_macro") {
+                *how = HowToFmt::WithMacro(parse_lit(&nv.lit)?);
+            } else if nv.path.is_ident("with_wrapper") {
+                *how = HowToFmt::WithWrapper(parse_lit(&nv.lit)?);
+            } else {
+                return Err(make_err(&nv));
+            }
+        }
+        (ParseContext::Field { field }, Meta::List(list)) => {
+            let how = &mut this.field_map[field.index];
+
+            if list.path.is_ident("is_a") {
+                match list.nested.len() {
+                    0 => return Err(make_err(&list)),
+                    1 => (),
+                    _ => return_spanned_err!(
+                        list,
+                        "The `#[{}(is_a())` attribute must only specify one kind of type.",
+                        attr_name,
+                    ),
+                }
+                with_nested_meta("is_a", list.nested, |attr| {
+                    *how = parse_the_is_a_attribute(attr, field)?;
+                    Ok(())
+                })?;
+            } else {
+                return Err(make_err(&list));
+            }
+        }
+        (ParseContext::TypeAttr, Meta::Path(path)) => {
+            if path.is_ident("debug_print") {
+                this.debug_print = true;
+            } else {
+                return Err(make_err(&path));
+            }
+        }
+        (ParseContext::TypeAttr, Meta::NameValue(nv)) => {
+            if nv.path.is_ident("crate") {
+                this.crate_path = Some(parse_lit(&nv.lit)?);
+            } else {
+                return Err(make_err(&nv));
+            }
+        }
+        (ParseContext::TypeAttr, Meta::List(list)) => {
+            if list.path.is_ident("impls") {
+                for x in list.nested {
+                    let lit = match x {
+                        NestedMeta::Meta(attr) => return Err(make_err(&attr)),
+                        NestedMeta::Lit(lit) => lit,
+                    };
+                    this.impls.push(parse_lit::<ImplHeader>(&lit)?);
+                }
+            } else {
+                return Err(make_err(&list));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn parse_lit<T>(lit: &syn::Lit) -> Result<T, crate::Error>
+where
+    T: syn::parse::Parse,
+{
+    match lit {
+        syn::Lit::Str(x) => x.parse().map_err(crate::Error::from),
+        _ => Err(spanned_err!(
+            lit,
+            "Expected string literal containing identifier"
+        )),
+    }
+}
@@ -5,12 +5,14 @@ use crate::{
 
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 
-use quote::{quote_spanned, TokenStreamExt};
+use quote::{quote_spanned, ToTokens, TokenStreamExt};
 
 ////////////////////////////////////////////////
 
 mod parsing;
 
+pub(crate) use self::parsing::parse_fmt_lit;
+
 ////////////////////////////////////////////////
 
 struct UncheckedFormatArgs {
@@ -39,6 +41,12 @@ pub(crate) struct FormatIfArgs {
     pub(crate) inner: FormatArgs,
 }
 
+/// The arguments of `formatcp_cap`
+pub(crate) struct FormatCapArgs {
+    pub(crate) capacity: ExprArg,
+    pub(crate) inner: FormatArgs,
+}
+
 /// The arguments of `writec`
 pub(crate) struct WriteArgs {
     pub(crate) writer_expr: TokenStream2,
@@ -54,15 +62,36 @@ pub(crate) enum ExpandInto {
 
 pub(crate) struct ExpandFormatted {
     pub(crate) format: FormattingFlags,
+    pub(crate) width: Option<ExpandCount>,
+    pub(crate) precision: Option<ExpandCount>,
     pub(crate) local_variable: Ident,
 }
 
 pub(crate) struct ExpandWithFormatter {
     pub(crate) format: FormattingFlags,
+    pub(crate) width: Option<ExpandCount>,
+    pub(crate) precision: Option<ExpandCount>,
     pub(crate) fmt_ident: Ident,
     pub(crate) expr: TokenStream2,
 }
 
+/// A width or precision in a formatting argument,
+/// eg: the `8` in `{:8}`, the `foo$` in `{:foo$}`, or the `3` in `{:.3}`
+pub(crate) enum ExpandCount {
+    Literal(usize),
+    // A local variable that the macro outputs for an argument.
+    Local(Ident),
+    // A constant from the enclosing scope.
+    Constant(Ident),
+}
+
+/// Whether the local variables for the arguments are references to the arguments.
+#[derive(Copy, Clone)]
+pub(crate) enum LocalsAre {
+    Values,
+    References,
+}
+
 pub(crate) struct LocalVariable {
     // The local variable that the macro will output for this argument,
     // so that it is not evaluated multiple times when it's used multiple times
@@ -87,6 +116,38 @@ pub(crate) enum FormatArg {
 
 ////////////////////////////////////////////////
 
+/// Outputs the `FormattingFlags` for an argument, including its width and precision.
+pub(crate) fn formatting_flags_tokens(
+    format: FormattingFlags,
+    width: &Option<ExpandCount>,
+    precision: &Option<ExpandCount>,
+    locals_are: LocalsAre,
+) -> TokenStream2 {
+    let mut tokens = format.to_token_stream();
+
+    if let Some(width) = width {
+        let width = width.tokens(locals_are);
+        tokens.append_all(quote::quote!(.set_width(#width)));
+    }
+    if let Some(precision) = precision {
+        let precision = precision.tokens(locals_are);
+        tokens.append_all(quote::quote!(.set_precision(#precision)));
+    }
+
+    tokens
+}
+
+impl ExpandCount {
+    fn tokens(&self, locals_are: LocalsAre) -> TokenStream2 {
+        match (self, locals_are) {
+            (ExpandCount::Literal(x), _) => quote::quote!(#x),
+            (ExpandCount::Local(ident), LocalsAre::Values) => quote::quote!(#ident),
+            (ExpandCount::Local(ident), LocalsAre::References) => quote::quote!(*#ident),
+            (ExpandCount::Constant(ident), _) => quote::quote!(#ident),
+        }
+    }
+}
+
 impl ExpandInto {
     pub(crate) fn fmt_call(&self, formatter: &Ident) -> TokenStream2 {
         match self {
@@ -96,7 +157,12 @@ impl ExpandInto {
                 quote_spanned!(rawness.span()=> #formatter.write_str(#str_tokens) )
             }
             ExpandInto::Formatted(fmted) => {
-                let flags = fmted.format;
+                let flags = formatting_flags_tokens(
+                    fmted.format,
+                    &fmted.width,
+                    &fmted.precision,
+                    LocalsAre::References,
+                );
                 let fmt_method = fmted.format.fmt_method_name();
                 let local_variable = &fmted.local_variable;
                 let span = local_variable.span();
@@ -113,12 +179,18 @@ impl ExpandInto {
             }
             ExpandInto::WithFormatter(ExpandWithFormatter {
                 format,
+                width,
+                precision,
                 fmt_ident,
                 expr,
-            }) => quote::quote!({
-                let #fmt_ident = &mut #formatter.make_formatter(#format);
-                __cf_osRcTFl4A::pmr::ToResult( #expr ).to_result()
-            }),
+            }) => {
+                let flags =
+                    formatting_flags_tokens(*format, width, precision, LocalsAre::References);
+                quote::quote!({
+                    let #fmt_ident = &mut #formatter.make_formatter(#flags);
+                    __cf_osRcTFl4A::pmr::ToResult( #expr ).to_result()
+                })
+            }
         }
     }
 }
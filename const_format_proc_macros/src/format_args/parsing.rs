@@ -1,12 +1,12 @@
 use super::{
-    ExpandFormatted, ExpandInto, ExpandWithFormatter, FormatArg, FormatArgs, FormatIfArgs,
-    LocalVariable, UncheckedFormatArg, UncheckedFormatArgs, WriteArgs,
+    ExpandCount, ExpandFormatted, ExpandInto, ExpandWithFormatter, FormatArg, FormatArgs,
+    FormatCapArgs, FormatIfArgs, LocalVariable, UncheckedFormatArg, UncheckedFormatArgs, WriteArgs,
 };
 
 use crate::{
-    format_str::{FmtArg, FmtStrComponent, FormatStr, WhichArg},
+    format_str::{Count, FmtArg, FmtStrComponent, FormatStr, WhichArg},
     parse_utils::{LitStr, MyParse, ParseBuffer, ParseStream, TokenTreeExt},
-    shared_arg_parsing::ExprArg,
+    shared_arg_parsing::{default_float_literal, ExprArg},
     spanned::Spans,
     utils::{dummy_ident, LinearResult},
 };
@@ -50,7 +50,7 @@ impl MyParse for UncheckedFormatArg {
                     spans,
                     ident,
                     fmt_ident,
-                    expr,
+                    expr: default_float_literal(expr),
                 })
             })
         })
@@ -66,7 +66,12 @@ fn lit_str_to_fmt_lit(lit: &LitStr) -> Result<FormatStr, crate::Error> {
         .map_err(|e| e.into_crate_err(format_str_span, lit_str))
 }
 
-fn parse_fmt_lit(this: &mut FormatStr, input: ParseStream<'_>) -> Result<(), crate::Error> {
+/// Parses a format string literal, or a `concat!()` of format string literals,
+/// appending its components to `this`.
+pub(crate) fn parse_fmt_lit(
+    this: &mut FormatStr,
+    input: ParseStream<'_>,
+) -> Result<(), crate::Error> {
     input.parse_unwrap_tt(|input| {
         let tt = input.next();
 
@@ -207,26 +212,22 @@ impl FormatArgs {
 
         let fmt_str_components = unchecked_fargs.literal.list;
 
+        let mut count_errors = Vec::<crate::Error>::new();
+
         let expanded_into: Vec<ExpandInto> = {
             let mut current_pos_arg = 0;
-            let mut get_variable_name = |param: FmtArg| -> ExpandInto {
-                let FmtArg {
-                    which_arg,
-                    formatting,
-                    rawness,
-                } = param;
 
-                let arg = match which_arg {
+            // Gets the argument that `which_arg` refers to,
+            // returning `Err(ident)` for constants from the enclosing scope.
+            let mut resolve_arg = |which_arg: WhichArg, span: Span| -> Result<&FormatArg, Ident> {
+                match which_arg {
                     WhichArg::Ident(ident) => {
                         if let Some(pos) = named_arg_names.iter().position(|x| *x == ident) {
                             unused_args[pos + first_named_arg] = false;
-                            &named_args[pos]
+                            Ok(&named_args[pos])
                         } else {
                             // `formatcp!("{FOO}")` assumes that FOO is a constant in scope
-                            return ExpandInto::Formatted(ExpandFormatted {
-                                local_variable: Ident::new(&ident, rawness.span()),
-                                format: formatting,
-                            });
+                            Err(Ident::new(&ident, span))
                         }
                     }
                     WhichArg::Positional(opt_pos) => {
@@ -239,39 +240,83 @@ impl FormatArgs {
                         match positional_args.get(pos) {
                             Some(arg) => {
                                 unused_args[pos] = false;
-                                arg
+                                Ok(arg)
                             }
                             None => {
                                 res.push_err(crate::Error::new(
-                                    rawness.span(),
+                                    span,
                                     format!(
                                         "attempting to use nonexistent  positional argument `{}`",
                                         pos,
                                     ),
                                 ));
-                                return ExpandInto::Formatted(ExpandFormatted {
-                                    local_variable: dummy_ident(),
-                                    format: formatting,
-                                });
+                                Err(dummy_ident())
                             }
                         }
                     }
+                }
+            };
+
+            let mut get_variable_name = |param: FmtArg| -> ExpandInto {
+                let FmtArg {
+                    which_arg,
+                    formatting,
+                    width,
+                    precision,
+                    rawness,
+                } = param;
+
+                let span = rawness.span();
+
+                let mut resolve_count = |count: Count, what: &str| match count {
+                    Count::Literal(x) => ExpandCount::Literal(x),
+                    Count::Arg(which_arg) => match resolve_arg(which_arg, span) {
+                        Ok(FormatArg::WithLocal(local_variable)) => {
+                            ExpandCount::Local(local_variable.clone())
+                        }
+                        Ok(FormatArg::WithFormatter { .. }) => {
+                            count_errors.push(crate::Error::new(
+                                span,
+                                format!(
+                                    "cannot use an argument that takes a Formatter as a {}",
+                                    what
+                                ),
+                            ));
+                            ExpandCount::Literal(0)
+                        }
+                        Err(ident) => ExpandCount::Constant(ident),
+                    },
                 };
 
-                match arg {
-                    FormatArg::WithFormatter { fmt_ident, expr } => {
+                let width = width.map(|count| resolve_count(count, "width"));
+                // resolved before the formatted argument,
+                // because `{:.*}` takes the precision from the next positional argument.
+                let precision = precision.map(|count| resolve_count(count, "precision"));
+
+                match resolve_arg(which_arg, span) {
+                    Ok(FormatArg::WithFormatter { fmt_ident, expr }) => {
                         ExpandInto::WithFormatter(ExpandWithFormatter {
                             format: formatting,
+                            width,
+                            precision,
                             fmt_ident: fmt_ident.clone(),
                             expr: expr.clone(),
                         })
                     }
-                    FormatArg::WithLocal(local_variable) => {
+                    Ok(FormatArg::WithLocal(local_variable)) => {
                         ExpandInto::Formatted(ExpandFormatted {
                             format: formatting,
+                            width,
+                            precision,
                             local_variable: local_variable.clone(),
                         })
                     }
+                    Err(local_variable) => ExpandInto::Formatted(ExpandFormatted {
+                        format: formatting,
+                        width,
+                        precision,
+                        local_variable,
+                    }),
                 }
             };
 
@@ -284,6 +329,10 @@ impl FormatArgs {
                 .collect()
         };
 
+        for e in count_errors {
+            res.push_err(e);
+        }
+
         for (i, (is_it_unused, (spans, ident))) in
             unused_args.iter().zip(&arg_span_idents).enumerate()
         {
@@ -321,6 +370,18 @@ impl MyParse for FormatIfArgs {
 
 ////////////////////////////////////////////////
 
+impl MyParse for FormatCapArgs {
+    fn parse(input: ParseStream) -> Result<Self, crate::Error> {
+        let capacity = ExprArg::parse(input)?;
+
+        let inner = FormatArgs::parse(input)?;
+
+        Ok(Self { capacity, inner })
+    }
+}
+
+////////////////////////////////////////////////
+
 impl MyParse for WriteArgs {
     fn parse(input: ParseStream) -> Result<Self, crate::Error> {
         let prefix = Ident::new("__const_fmt_local_", Span::call_site());
@@ -1,11 +1,17 @@
 use crate::{
-    format_args::{ExpandInto, FormatArgs, FormatIfArgs, LocalVariable, WriteArgs},
+    format_args::{
+        formatting_flags_tokens, ExpandInto, FormatArgs, FormatCapArgs, FormatIfArgs,
+        LocalVariable, LocalsAre, WriteArgs,
+    },
+    formatting::{Formatting, FormattingFlags, IsAlternate},
     parse_utils::TokenStream2Ext,
-    shared_arg_parsing::{ExprArg, ExprArgs},
+    shared_arg_parsing::{default_byte_literal, ExprArg, ExprArgs},
     Error,
 };
 
-use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use proc_macro2::{
+    Delimiter, Group, Ident, Span, TokenStream as TokenStream2, TokenTree as TokenTree2,
+};
 
 use quote::{quote, quote_spanned};
 
@@ -14,12 +20,57 @@ mod tests;
 
 ////////////////////////////////////////////////////////////////////////////////
 
+/// What the `concatcp`-like macros evaluate to.
+#[derive(Copy, Clone)]
+pub(crate) enum ConcatOutput {
+    /// A `&'static str`
+    Str,
+    /// A `&'static [u8; N]`
+    Bytes,
+}
+
+impl ConcatOutput {
+    fn inner_macro(self) -> TokenStream2 {
+        match self {
+            Self::Str => quote!(__cf_osRcTFl4A::__concatcp_inner),
+            Self::Bytes => quote!(__cf_osRcTFl4A::__concatbytes_inner),
+        }
+    }
+
+    fn pargument_method_name(self, format: FormattingFlags) -> Ident {
+        match (self, format.formatting) {
+            (Self::Bytes, Formatting::Display) => {
+                Ident::new("to_pargument_bytes", Span::mixed_site())
+            }
+            _ => format.to_pargument_method_name(),
+        }
+    }
+
+    fn map_expr(self, expr: &TokenStream2) -> TokenStream2 {
+        match self {
+            Self::Str => expr.clone(),
+            Self::Bytes => default_byte_literal(expr.clone()),
+        }
+    }
+}
+
 pub(crate) fn concatcp_impl(value: ExprArgs) -> Result<TokenStream2, crate::Error> {
+    concat_impl(value, ConcatOutput::Str)
+}
+
+pub(crate) fn concatbytes_impl(value: ExprArgs) -> Result<TokenStream2, crate::Error> {
+    concat_impl(value, ConcatOutput::Bytes)
+}
+
+fn concat_impl(value: ExprArgs, output: ConcatOutput) -> Result<TokenStream2, crate::Error> {
     let fmt_var = Ident::new("fmt", Span::mixed_site());
+    let to_pargument_m = output.pargument_method_name(FormattingFlags::display(IsAlternate::No));
+    let inner_macro = output.inner_macro();
 
     let concat_args = value.args.iter().map(|ExprArg { expr, span }| {
+        let expr = output.map_expr(expr);
         quote_spanned!(span.start=>
-            __cf_osRcTFl4A::pmr::PConvWrapper(#expr).to_pargument_display(#fmt_var)
+            __cf_osRcTFl4A::pmr::PConvWrapper(#expr).#to_pargument_m(#fmt_var)
         )
     });
 
@@ -35,7 +86,7 @@ pub(crate) fn concatcp_impl(value: ExprArgs) -> Result<TokenStream2, crate::Erro
             ]
         };
 
-        __cf_osRcTFl4A::__concatcp_inner!(CONCATP_NHPMWYD3NJA)
+        #inner_macro!(CONCATP_NHPMWYD3NJA)
     })))
 }
 
@@ -46,14 +97,69 @@ pub(crate) fn formatcp_if_macro_impl(value: FormatIfArgs) -> Result<TokenStream2
 }
 
 pub(crate) fn formatcp_impl(fmt_args: FormatArgs) -> Result<TokenStream2, crate::Error> {
+    format_impl(fmt_args, ConcatOutput::Str)
+}
+
+pub(crate) fn formatbytes_impl(fmt_args: FormatArgs) -> Result<TokenStream2, crate::Error> {
+    format_impl(fmt_args, ConcatOutput::Bytes)
+}
+
+fn format_impl(fmt_args: FormatArgs, output: ConcatOutput) -> Result<TokenStream2, crate::Error> {
+    let inner_macro = output.inner_macro();
+
     let locals = fmt_args
         .local_variables
         .iter()
         .map(|LocalVariable { ident, expr }| {
             let span = ident.span();
+            let expr = output.map_expr(expr);
             quote_spanned!(span=> let #ident = #expr;)
         });
 
+    let parg_constructor = format_pargument_constructors(&fmt_args, output)?;
+
+    let fmt_if_true = quote!({
+        let mut len = 0usize;
+
+        #( #locals )*
+
+        &[
+            #( #parg_constructor ),*
+        ]
+    });
+
+    if let Some(cond) = fmt_args.condition {
+        Ok(quote!(({
+            enum __Fooosrctfl4a {}
+
+            // This is generic so that the constant is only evaluated when it's needed.
+            impl<T> __cf_osRcTFl4A::pmr::ConcatArgsIf<T, true> for __Fooosrctfl4a {
+                #[doc(hidden)]
+                const PARGUMENTS : &'static [__cf_osRcTFl4A::pmr::PArgument] = #fmt_if_true;
+            }
+
+            #inner_macro!(
+                <__Fooosrctfl4a as __cf_osRcTFl4A::pmr::ConcatArgsIf<(), #cond>>::PARGUMENTS
+            )
+        })))
+    } else {
+        Ok(quote!(({
+            // The suffix is to avoid name collisions with identifiers in the passed-in expression.
+            #[doc(hidden)]
+            #[allow(unused_mut, non_snake_case)]
+            const CONCATP_NHPMWYD3NJA : &[__cf_osRcTFl4A::pmr::PArgument] = #fmt_if_true;
+
+            #inner_macro!(CONCATP_NHPMWYD3NJA)
+        })))
+    }
+}
+
+/// Outputs the expressions that construct the `PArgument`s for each formatted argument,
+/// which refer to the arguments through their local variables.
+fn format_pargument_constructors(
+    fmt_args: &FormatArgs,
+    output: ConcatOutput,
+) -> Result<Vec<TokenStream2>, crate::Error> {
     for ei in fmt_args.expanded_into.iter() {
         if let ExpandInto::WithFormatter(wf) = ei {
             return Err(crate::Error::new(
@@ -72,8 +178,13 @@ pub(crate) fn formatcp_impl(fmt_args: FormatArgs) -> Result<TokenStream2, crate:
             )
         }
         ExpandInto::Formatted(fmted) => {
-            let to_pargument_m = fmted.format.to_pargument_method_name();
-            let formatting = fmted.format;
+            let to_pargument_m = output.pargument_method_name(fmted.format);
+            let formatting = formatting_flags_tokens(
+                fmted.format,
+                &fmted.width,
+                &fmted.precision,
+                LocalsAre::Values,
+            );
             let local_variable = &fmted.local_variable;
             let span = local_variable.span();
             // I had to use `set_span_recursive` to set the span to that of the argument,
@@ -86,40 +197,76 @@ pub(crate) fn formatcp_impl(fmt_args: FormatArgs) -> Result<TokenStream2, crate:
         ExpandInto::WithFormatter { .. } => unreachable!(),
     });
 
-    let fmt_if_true = quote!({
-        let mut len = 0usize;
+    Ok(parg_constructor.collect())
+}
 
-        #( #locals )*
+////////////////////////////////////////////////////////////////////////////////
 
-        &[
-            #( #parg_constructor ),*
-        ]
+// The `*_cap` macros can be used in generic contexts,
+// by evaluating to a single expression that is promoted to a `'static` constant,
+// which is why they can't declare any items or local variables.
+
+pub(crate) fn concatcp_cap_impl(value: ExprArgs) -> Result<TokenStream2, crate::Error> {
+    let mut args = value.args.iter();
+    let capacity = args.next().expect("the capacity is always passed");
+
+    let concat_args = args.map(|ExprArg { expr, span }| {
+        quote_spanned!(span.start=>
+            __cf_osRcTFl4A::pmr::PConvWrapper(#expr)
+                .to_pargument_display(__cf_osRcTFl4A::pmr::FormattingFlags::NEW)
+        )
     });
 
-    if let Some(cond) = fmt_args.condition {
-        Ok(quote!(({
-            enum __Fooosrctfl4a {}
+    Ok(cap_concatenation(capacity, concat_args))
+}
 
-            // This is generic so that the constant is only evaluated when it's needed.
-            impl<T> __cf_osRcTFl4A::pmr::ConcatArgsIf<T, true> for __Fooosrctfl4a {
-                #[doc(hidden)]
-                const PARGUMENTS : &'static [__cf_osRcTFl4A::pmr::PArgument] = #fmt_if_true;
-            }
+pub(crate) fn formatcp_cap_impl(value: FormatCapArgs) -> Result<TokenStream2, crate::Error> {
+    let fmt_args = &value.inner;
 
-            __cf_osRcTFl4A::__concatcp_inner!(
-                <__Fooosrctfl4a as __cf_osRcTFl4A::pmr::ConcatArgsIf<(), #cond>>::PARGUMENTS
-            )
-        })))
-    } else {
-        Ok(quote!(({
-            // The suffix is to avoid name collisions with identifiers in the passed-in expression.
-            #[doc(hidden)]
-            #[allow(unused_mut, non_snake_case)]
-            const CONCATP_NHPMWYD3NJA : &[__cf_osRcTFl4A::pmr::PArgument] = #fmt_if_true;
+    let parg_constructor = format_pargument_constructors(fmt_args, ConcatOutput::Str)?
+        .into_iter()
+        .map(|tokens| inline_locals(tokens, &fmt_args.local_variables));
 
-            __cf_osRcTFl4A::__concatcp_inner!(CONCATP_NHPMWYD3NJA)
-        })))
-    }
+    Ok(cap_concatenation(&value.capacity, parg_constructor))
+}
+
+fn cap_concatenation<I>(capacity: &ExprArg, pargs: I) -> TokenStream2
+where
+    I: IntoIterator<Item = TokenStream2>,
+{
+    let pargs = pargs.into_iter();
+
+    quote!(
+        __cf_osRcTFl4A::pmr::__priv_cap_to_str(
+            &__cf_osRcTFl4A::pmr::__priv_concatenate_cap::<{ #capacity }>(&[
+                #( #pargs ),*
+            ])
+        )
+    )
+}
+
+/// Replaces the local variables for the arguments in `tokens` with the arguments themselves.
+fn inline_locals(tokens: TokenStream2, locals: &[LocalVariable]) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree2::Ident(ident) => match locals.iter().find(|lv| lv.ident == ident) {
+                Some(LocalVariable { expr, .. }) => {
+                    let mut group = Group::new(Delimiter::None, expr.clone());
+                    group.set_span(ident.span());
+                    TokenTree2::Group(group)
+                }
+                None => TokenTree2::Ident(ident),
+            },
+            TokenTree2::Group(group) => {
+                let stream = inline_locals(group.stream(), locals);
+                let mut new_group = Group::new(group.delimiter(), stream);
+                new_group.set_span(group.span());
+                TokenTree2::Group(new_group)
+            }
+            tt => tt,
+        })
+        .collect()
 }
 
 ////////////////////////////////////////////////////////////////////////////////
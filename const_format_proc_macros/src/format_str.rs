@@ -25,6 +25,8 @@ pub(crate) enum FmtStrComponent {
 pub(crate) struct FmtArg {
     pub(crate) which_arg: WhichArg,
     pub(crate) formatting: FormattingFlags,
+    pub(crate) width: Option<Count>,
+    pub(crate) precision: Option<Count>,
     pub(crate) rawness: StrRawness,
 }
 
@@ -33,3 +35,11 @@ pub(crate) enum WhichArg {
     Ident(String),
     Positional(Option<usize>),
 }
+
+/// A width or precision in a format argument,
+/// eg: the `8` in `"{:8}"`, the `foo$` in `"{:foo$}"`, the `3` in `"{:.3}"`
+#[derive(Debug, PartialEq)]
+pub(crate) enum Count {
+    Literal(usize),
+    Arg(WhichArg),
+}
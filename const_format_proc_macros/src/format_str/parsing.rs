@@ -1,7 +1,7 @@
-use super::{FmtArg, FmtStrComponent, FormatStr, ParseError, ParseErrorKind, WhichArg};
+use super::{Count, FmtArg, FmtStrComponent, FormatStr, ParseError, ParseErrorKind, WhichArg};
 
 use crate::{
-    formatting::{FormattingFlags, IsAlternate, NumberFormatting},
+    formatting::{Alignment, FormattingFlags, IsAlternate, NumberFormatting},
     parse_utils::StrRawness,
 };
 
@@ -11,11 +11,26 @@ impl FmtStrComponent {
         Self::Str(s.to_string(), StrRawness::dummy())
     }
     pub(super) fn arg(which_arg: WhichArg, formatting: FormattingFlags) -> Self {
-        Self::Arg(FmtArg {
-            which_arg,
-            formatting,
-            rawness: StrRawness::dummy(),
-        })
+        Self::Arg(FmtArg::new(which_arg, formatting, StrRawness::dummy()))
+    }
+    pub(super) fn arg_width(
+        which_arg: WhichArg,
+        formatting: FormattingFlags,
+        width: Count,
+    ) -> Self {
+        Self::Arg(FmtArg::new(which_arg, formatting, StrRawness::dummy()).with_width(Some(width)))
+    }
+    pub(super) fn arg_precision(
+        which_arg: WhichArg,
+        formatting: FormattingFlags,
+        width: Option<Count>,
+        precision: Count,
+    ) -> Self {
+        Self::Arg(
+            FmtArg::new(which_arg, formatting, StrRawness::dummy())
+                .with_width(width)
+                .with_precision(Some(precision)),
+        )
     }
 }
 
@@ -24,9 +39,21 @@ impl FmtArg {
         Self {
             which_arg,
             formatting,
+            width: None,
+            precision: None,
             rawness,
         }
     }
+
+    fn with_width(mut self, width: Option<Count>) -> Self {
+        self.width = width;
+        self
+    }
+
+    fn with_precision(mut self, precision: Option<Count>) -> Self {
+        self.precision = precision;
+        self
+    }
 }
 
 #[allow(dead_code)]
@@ -125,11 +152,16 @@ fn parse_fmt_arg(input: &str, starts_at: usize, rawness: StrRawness) -> Result<F
     let formatting_str = colon.map_or("", |x| &input[x + 1..]);
     let formatting_starts_at = colon.map_or(input.len(), |x| starts_at + x + 1);
 
-    Ok(FmtArg::new(
-        parse_which_arg(which_arg_str, starts_at)?,
-        parse_formatting(formatting_str, formatting_starts_at)?,
-        rawness,
-    ))
+    let which_arg = parse_which_arg(which_arg_str, starts_at)?;
+    let ParsedFormatting {
+        flags,
+        width,
+        precision,
+    } = parse_formatting(formatting_str, formatting_starts_at)?;
+
+    Ok(FmtArg::new(which_arg, flags, rawness)
+        .with_width(width)
+        .with_precision(precision))
 }
 
 /// Parses the name of the argument in `{foo}`, `{}`, `{bar:?}`
@@ -153,18 +185,20 @@ fn parse_which_arg(input: &str, starts_at: usize) -> Result<WhichArg, ParseError
     }
 }
 
+/// The formatting of a format argument, eg: the `>8.3?` in `{foo:>8.3?}`
+struct ParsedFormatting {
+    flags: FormattingFlags,
+    width: Option<Count>,
+    precision: Option<Count>,
+}
+
 /// Parses the `?` and other formatters inside formatting arguments (`{}`).
 ///
+/// The syntax is `[[fill]align]['+']['#']['0'][width]['.' precision][type]`,
+/// like in `std::fmt`.
+///
 /// `starts_at` is the offset of `input` in the formatting string.
-fn parse_formatting(input: &str, starts_at: usize) -> Result<FormattingFlags, ParseError> {
-    match input {
-        "#" => return Ok(FormattingFlags::display(IsAlternate::Yes)),
-        "" => return Ok(FormattingFlags::display(IsAlternate::No)),
-        _ => {}
-    }
-
-    let mut bytes = input.as_bytes();
-
+fn parse_formatting(input: &str, starts_at: usize) -> Result<ParsedFormatting, ParseError> {
     let make_error = || ParseError {
         pos: starts_at,
         kind: ParseErrorKind::UnknownFormatting {
@@ -172,23 +206,130 @@ fn parse_formatting(input: &str, starts_at: usize) -> Result<FormattingFlags, Pa
         },
     };
 
-    if let [before @ .., b'?'] = bytes {
-        bytes = before;
+    let mut rem = input;
+
+    let mut fill_align = None;
+    {
+        let mut chars = rem.chars();
+        let first = chars.next();
+        let second = chars.next();
+        if let (Some(fill), Some(alignment)) = (first, second.and_then(Alignment::from_char)) {
+            fill_align = Some((fill, alignment));
+            rem = &rem[fill.len_utf8() + 1..];
+        } else if let Some(alignment) = first.and_then(Alignment::from_char) {
+            fill_align = Some((' ', alignment));
+            rem = &rem[1..];
+        }
+    }
+
+    let mut sign_plus = false;
+    if let Some(x) = rem.strip_prefix('+') {
+        sign_plus = true;
+        rem = x;
     }
 
-    let mut num_fmt = NumberFormatting::Decimal;
     let mut is_alternate = IsAlternate::No;
+    if let Some(x) = rem.strip_prefix('#') {
+        is_alternate = IsAlternate::Yes;
+        rem = x;
+    }
+
+    let mut sign_aware_zero_pad = false;
+    if rem.starts_with('0') && !rem[1..].starts_with('$') {
+        sign_aware_zero_pad = true;
+        rem = &rem[1..];
+    }
 
-    for byte in bytes {
-        match byte {
-            b'b' if num_fmt.is_regular() => num_fmt = NumberFormatting::Binary,
-            b'x' if num_fmt.is_regular() => num_fmt = NumberFormatting::LowerHexadecimal,
-            b'X' if num_fmt.is_regular() => num_fmt = NumberFormatting::Hexadecimal,
-            b'#' => is_alternate = IsAlternate::Yes,
-            _ => return Err(make_error()),
+    let width = match parse_count(rem) {
+        Some((count, after)) => {
+            rem = after;
+            Some(count.ok_or_else(make_error)?)
         }
+        None => None,
+    };
+
+    let precision = match rem.strip_prefix('.') {
+        Some(after_dot) => {
+            // `.*` takes the precision from the next positional argument
+            if let Some(after) = after_dot.strip_prefix('*') {
+                rem = after;
+                Some(Count::Arg(WhichArg::Positional(None)))
+            } else {
+                let (count, after) = parse_count(after_dot).ok_or_else(make_error)?;
+                rem = after;
+                Some(count.ok_or_else(make_error)?)
+            }
+        }
+        None => None,
+    };
+
+    let mut flags = match rem {
+        "" => FormattingFlags::display(is_alternate),
+        _ => {
+            let num_fmt = match rem.strip_suffix('?').unwrap_or(rem) {
+                "" => NumberFormatting::Decimal,
+                "b" => NumberFormatting::Binary,
+                "x" => NumberFormatting::LowerHexadecimal,
+                "X" => NumberFormatting::Hexadecimal,
+                "o" => NumberFormatting::Octal,
+                "e" => NumberFormatting::LowerExponential,
+                "E" => NumberFormatting::Exponential,
+                _ => return Err(make_error()),
+            };
+            FormattingFlags::debug(num_fmt, is_alternate)
+        }
+    };
+
+    if let Some((fill, alignment)) = fill_align {
+        flags = flags.with_fill(fill, alignment);
+    }
+    if sign_plus {
+        flags = flags.with_sign_plus();
+    }
+    if sign_aware_zero_pad {
+        flags = flags.with_zero_pad();
+    }
+
+    Ok(ParsedFormatting {
+        flags,
+        width,
+        precision,
+    })
+}
+
+/// Parses a width or precision, eg: `10`, `0$`, `foo$`,
+/// returning the unparsed remainder of the string if it was a count.
+///
+/// The returned `Count` is `None` if the width is not a valid number.
+fn parse_count(input: &str) -> Option<(Option<Count>, &str)> {
+    let is_digits = input.starts_with(|c: char| c.is_ascii_digit());
+
+    let end = if is_digits {
+        input.find(|c: char| !c.is_ascii_digit())
+    } else {
+        input.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+    }
+    .unwrap_or(input.len());
+    let (count_str, rem) = input.split_at(end);
+    let after_dollar = rem.strip_prefix('$');
+
+    if is_digits {
+        let count = count_str.parse::<usize>().ok();
+        match after_dollar {
+            Some(after) => Some((
+                count.map(|x| Count::Arg(WhichArg::Positional(Some(x)))),
+                after,
+            )),
+            None => Some((count.map(Count::Literal), rem)),
+        }
+    } else if let Some(after) = after_dollar {
+        let count = Some(count_str)
+            .filter(|s| is_ident(s))
+            .map(|s| Count::Arg(WhichArg::Ident(s.to_string())));
+        Some((count, after))
+    } else {
+        None
     }
-    Ok(FormattingFlags::debug(num_fmt, is_alternate))
 }
 
 /// Parses an identifier in a formatting argument.
@@ -2,7 +2,7 @@ use super::*;
 
 use super::{ParseError as PE, ParseErrorKind as PEK};
 
-use crate::formatting::{FormattingFlags as FF, IsAlternate, NumberFormatting};
+use crate::formatting::{Alignment, FormattingFlags as FF, IsAlternate, NumberFormatting};
 
 use fastrand::Rng;
 
@@ -142,6 +142,69 @@ fn unknown_formatting() {
             kind: PEK::unknown_formatting("????")
         }
     );
+    assert_eq!(
+        err("{:<<<5}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("<<<5")
+        }
+    );
+    assert_eq!(
+        err("{:99999999999999999999999}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("99999999999999999999999")
+        }
+    );
+    assert_eq!(
+        err("{:5?x}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("5?x")
+        }
+    );
+    assert_eq!(
+        err("{:_a-$}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("_a-$")
+        }
+    );
+    assert_eq!(
+        err("{:.}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting(".")
+        }
+    );
+    assert_eq!(
+        err("{:5.x}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("5.x")
+        }
+    );
+    assert_eq!(
+        err("{:.3.2}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting(".3.2")
+        }
+    );
+    assert_eq!(
+        err("{:#+x}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("#+x")
+        }
+    );
+    assert_eq!(
+        err("{:++}"),
+        PE {
+            pos: 2,
+            kind: PEK::unknown_formatting("++")
+        }
+    );
 }
 
 #[test]
@@ -193,6 +256,185 @@ fn ok_cases() {
     );
 }
 
+#[test]
+fn width_fill_alignment() {
+    assert_eq!(
+        ok("{:5}{:<5}{:^5?}{:>5}").list,
+        vec![
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::display(NOALT),
+                Count::Literal(5),
+            ),
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::display(NOALT).with_fill(' ', Alignment::Left),
+                Count::Literal(5),
+            ),
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::debug(NFDEC, NOALT).with_fill(' ', Alignment::Center),
+                Count::Literal(5),
+            ),
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::display(NOALT).with_fill(' ', Alignment::Right),
+                Count::Literal(5),
+            ),
+        ]
+    );
+
+    assert_eq!(
+        ok("{A:-^10}{B:ñ<#3x?}{:>}").list,
+        vec![
+            FmtStrComponent::arg_width(
+                WhichArg::ident("A"),
+                FF::display(NOALT).with_fill('-', Alignment::Center),
+                Count::Literal(10),
+            ),
+            FmtStrComponent::arg_width(
+                WhichArg::ident("B"),
+                FF::debug(NumberFormatting::LowerHexadecimal, IsAlternate::Yes)
+                    .with_fill('ñ', Alignment::Left),
+                Count::Literal(3),
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF::display(NOALT).with_fill(' ', Alignment::Right),
+            ),
+        ]
+    );
+
+    assert_eq!(
+        ok("{:08}{:#010b}{:0$}{:1$?}{:width$}{:<_w$}").list,
+        vec![
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::display(NOALT).with_zero_pad(),
+                Count::Literal(8),
+            ),
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::debug(NumberFormatting::Binary, IsAlternate::Yes).with_zero_pad(),
+                Count::Literal(10),
+            ),
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::display(NOALT),
+                Count::Arg(WhichArg::Positional(Some(0))),
+            ),
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::debug(NFDEC, NOALT),
+                Count::Arg(WhichArg::Positional(Some(1))),
+            ),
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::display(NOALT),
+                Count::Arg(WhichArg::ident("width")),
+            ),
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::display(NOALT).with_fill(' ', Alignment::Left),
+                Count::Arg(WhichArg::ident("_w")),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn precision() {
+    assert_eq!(
+        ok("{:.3}{:8.0?}{:>1$.2$}{:.p$}{:.*}{:-^#.1x?}").list,
+        vec![
+            FmtStrComponent::arg_precision(
+                WhichArg::Positional(None),
+                FF::display(NOALT),
+                None,
+                Count::Literal(3),
+            ),
+            FmtStrComponent::arg_precision(
+                WhichArg::Positional(None),
+                FF::debug(NFDEC, NOALT),
+                Some(Count::Literal(8)),
+                Count::Literal(0),
+            ),
+            FmtStrComponent::arg_precision(
+                WhichArg::Positional(None),
+                FF::display(NOALT).with_fill(' ', Alignment::Right),
+                Some(Count::Arg(WhichArg::Positional(Some(1)))),
+                Count::Arg(WhichArg::Positional(Some(2))),
+            ),
+            FmtStrComponent::arg_precision(
+                WhichArg::Positional(None),
+                FF::display(NOALT),
+                None,
+                Count::Arg(WhichArg::ident("p")),
+            ),
+            FmtStrComponent::arg_precision(
+                WhichArg::Positional(None),
+                FF::display(NOALT),
+                None,
+                Count::Arg(WhichArg::Positional(None)),
+            ),
+            FmtStrComponent::arg_precision(
+                WhichArg::Positional(None),
+                FF::debug(NumberFormatting::LowerHexadecimal, IsAlternate::Yes)
+                    .with_fill('-', Alignment::Center),
+                None,
+                Count::Literal(1),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn octal_sign_exponent() {
+    assert_eq!(
+        ok("{:o}{:#o}{:+}{:+?}{:e}{:E}{:*>+#010o}{:+.2e}").list,
+        vec![
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF::debug(NumberFormatting::Octal, NOALT),
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF::debug(NumberFormatting::Octal, IsAlternate::Yes),
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF::display(NOALT).with_sign_plus(),
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF::debug(NFDEC, NOALT).with_sign_plus(),
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF::debug(NumberFormatting::LowerExponential, NOALT),
+            ),
+            FmtStrComponent::arg(
+                WhichArg::Positional(None),
+                FF::debug(NumberFormatting::Exponential, NOALT),
+            ),
+            FmtStrComponent::arg_width(
+                WhichArg::Positional(None),
+                FF::debug(NumberFormatting::Octal, IsAlternate::Yes)
+                    .with_fill('*', Alignment::Right)
+                    .with_sign_plus()
+                    .with_zero_pad(),
+                Count::Literal(10),
+            ),
+            FmtStrComponent::arg_precision(
+                WhichArg::Positional(None),
+                FF::debug(NumberFormatting::LowerExponential, NOALT).with_sign_plus(),
+                None,
+                Count::Literal(2),
+            ),
+        ]
+    );
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 
 trait RngExt {
@@ -14,12 +14,9 @@ pub(crate) enum NumberFormatting {
     Hexadecimal,
     LowerHexadecimal,
     Binary,
-}
-
-impl NumberFormatting {
-    pub(crate) fn is_regular(self) -> bool {
-        matches!(self, NumberFormatting::Decimal)
-    }
+    Octal,
+    Exponential,
+    LowerExponential,
 }
 
 impl ToTokens for NumberFormatting {
@@ -29,6 +26,9 @@ impl ToTokens for NumberFormatting {
             Self::Hexadecimal => quote!(.set_hexadecimal()),
             Self::LowerHexadecimal => quote!(.set_lower_hexadecimal()),
             Self::Binary => quote!(.set_binary()),
+            Self::Octal => quote!(.set_octal()),
+            Self::Exponential => quote!(.set_exponential()),
+            Self::LowerExponential => quote!(.set_lower_exponential()),
         });
     }
 }
@@ -43,10 +43,44 @@ pub(crate) enum IsAlternate {
 
 ////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Alignment {
+    Left,
+    Center,
+    Right,
+}
+
+impl Alignment {
+    pub(crate) fn from_char(c: char) -> Option<Self> {
+        match c {
+            '<' => Some(Self::Left),
+            '^' => Some(Self::Center),
+            '>' => Some(Self::Right),
+            _ => None,
+        }
+    }
+}
+
+impl ToTokens for Alignment {
+    fn to_tokens(&self, ts: &mut TokenStream2) {
+        ts.append_all(match self {
+            Self::Left => quote!(__cf_osRcTFl4A::pmr::Alignment::Left),
+            Self::Center => quote!(__cf_osRcTFl4A::pmr::Alignment::Center),
+            Self::Right => quote!(__cf_osRcTFl4A::pmr::Alignment::Right),
+        });
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub(crate) struct FormattingFlags {
     pub(crate) formatting: Formatting,
     pub(crate) is_alternate: IsAlternate,
+    pub(crate) fill: char,
+    pub(crate) alignment: Option<Alignment>,
+    pub(crate) sign_plus: bool,
+    pub(crate) sign_aware_zero_pad: bool,
 }
 
 impl FormattingFlags {
@@ -55,6 +89,10 @@ impl FormattingFlags {
         Self {
             formatting: Formatting::Display,
             is_alternate,
+            fill: ' ',
+            alignment: None,
+            sign_plus: false,
+            sign_aware_zero_pad: false,
         }
     }
 
@@ -63,8 +101,31 @@ impl FormattingFlags {
         Self {
             formatting: Formatting::Debug(num_fmt),
             is_alternate,
+            fill: ' ',
+            alignment: None,
+            sign_plus: false,
+            sign_aware_zero_pad: false,
         }
     }
+
+    #[allow(dead_code)]
+    pub(crate) const fn with_fill(mut self, fill: char, alignment: Alignment) -> Self {
+        self.fill = fill;
+        self.alignment = Some(alignment);
+        self
+    }
+
+    #[allow(dead_code)]
+    pub(crate) const fn with_sign_plus(mut self) -> Self {
+        self.sign_plus = true;
+        self
+    }
+
+    #[allow(dead_code)]
+    pub(crate) const fn with_zero_pad(mut self) -> Self {
+        self.sign_aware_zero_pad = true;
+        self
+    }
 }
 
 impl FormattingFlags {
@@ -114,12 +175,39 @@ impl ToTokens for FormattingFlags {
                 quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__LOWHEX)
             }
             (IA::No, FM::Binary) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__BIN),
+            (IA::No, FM::Octal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__OCT),
+            (IA::No, FM::Exponential) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__EXP),
+            (IA::No, FM::LowerExponential) => {
+                quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__LOWEXP)
+            }
             (IA::Yes, FM::Decimal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_REG),
             (IA::Yes, FM::Hexadecimal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_HEX),
             (IA::Yes, FM::LowerHexadecimal) => {
                 quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_LOWHEX)
             }
             (IA::Yes, FM::Binary) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_BIN),
+            (IA::Yes, FM::Octal) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_OCT),
+            (IA::Yes, FM::Exponential) => quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_EXP),
+            (IA::Yes, FM::LowerExponential) => {
+                quote!(__cf_osRcTFl4A::pmr::FormattingFlags::__A_LOWEXP)
+            }
         });
+
+        if self.fill != ' ' {
+            let fill = self.fill;
+            ts.append_all(quote!(.set_fill(#fill)));
+        }
+
+        if let Some(alignment) = self.alignment {
+            ts.append_all(quote!(.set_alignment(#alignment)));
+        }
+
+        if self.sign_plus {
+            ts.append_all(quote!(.set_sign_plus(true)));
+        }
+
+        if self.sign_aware_zero_pad {
+            ts.append_all(quote!(.set_sign_aware_zero_pad(true)));
+        }
     }
 }
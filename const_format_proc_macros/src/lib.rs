@@ -17,6 +17,12 @@ mod error;
 #[cfg(feature = "derive")]
 mod derive_debug;
 
+#[cfg(feature = "derive")]
+mod derive_display;
+
+#[cfg(feature = "derive")]
+mod derive_eq;
+
 mod format_args;
 
 mod format_str;
@@ -29,6 +35,8 @@ mod parse_utils;
 
 mod respan_to_macro;
 
+mod scan_macro;
+
 mod shared_arg_parsing;
 
 mod spanned;
@@ -49,6 +57,26 @@ fn compile_err_empty_str(e: crate::Error) -> TokenStream2 {
     })
 }
 
+fn compile_err_empty_bytes(e: crate::Error) -> TokenStream2 {
+    let e = e.to_compile_error();
+    quote::quote!({
+        #e;
+        &[0u8; 0]
+    })
+}
+
+// The errors are output inside a constant because
+// the parenthesized `compile_error` invocations are ignored in item position.
+#[cfg(feature = "derive")]
+fn compile_err_item(e: crate::Error) -> TokenStream2 {
+    let e = e.to_compile_error();
+    quote::quote!(
+        const _: () = {
+            #e;
+        };
+    )
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn __concatcp_impl(input: TokenStream1) -> TokenStream1 {
@@ -74,6 +102,46 @@ pub fn __formatcp_impl(input: TokenStream1) -> TokenStream1 {
         .into()
 }
 
+/// Input syntax: `(capacity), (arg0), (arg1)` (with optional trailing comma).
+#[doc(hidden)]
+#[proc_macro]
+pub fn __concatcp_cap_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(format_macro::concatcp_cap_impl)
+        .unwrap_or_else(compile_err_empty_str)
+        .into()
+}
+
+/// Input syntax: `(capacity) ("format string"), (arg0), (name = arg1)`
+/// (with optional trailing comma).
+#[doc(hidden)]
+#[proc_macro]
+pub fn __formatcp_cap_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(format_macro::formatcp_cap_impl)
+        .unwrap_or_else(compile_err_empty_str)
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __concatbytes_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(format_macro::concatbytes_impl)
+        .unwrap_or_else(compile_err_empty_bytes)
+        .into()
+}
+
+/// Takes the same input as `__formatcp_impl`
+#[doc(hidden)]
+#[proc_macro]
+pub fn __formatbytes_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(format_macro::formatbytes_impl)
+        .unwrap_or_else(compile_err_empty_bytes)
+        .into()
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn __formatc_impl(input: TokenStream1) -> TokenStream1 {
@@ -116,6 +184,22 @@ pub fn __writec_impl(input: TokenStream1) -> TokenStream1 {
         .into()
 }
 
+/// Input syntax: `("pattern") (input) (type0) (type1)`
+#[doc(hidden)]
+#[proc_macro]
+pub fn __str_scan_impl(input: TokenStream1) -> TokenStream1 {
+    MyParse::parse_token_stream_1(input)
+        .and_then(scan_macro::str_scan_impl)
+        .unwrap_or_else(|e| {
+            let e = e.to_compile_error();
+            quote::quote!({
+                #e;
+                loop {}
+            })
+        })
+        .into()
+}
+
 #[cfg(feature = "derive")]
 #[proc_macro_derive(ConstDebug, attributes(cdeb))]
 pub fn derive_const_debug(input: TokenStream1) -> TokenStream1 {
@@ -126,6 +210,36 @@ pub fn derive_const_debug(input: TokenStream1) -> TokenStream1 {
         .into()
 }
 
+#[cfg(feature = "derive")]
+#[proc_macro_derive(ConstDisplay, attributes(cdisplay))]
+pub fn derive_const_display(input: TokenStream1) -> TokenStream1 {
+    syn::parse(input)
+        .map_err(crate::Error::from)
+        .and_then(derive_display::derive_constdisplay_impl)
+        .unwrap_or_else(compile_err_item)
+        .into()
+}
+
+#[cfg(feature = "derive")]
+#[proc_macro_derive(ConstEq, attributes(ceq))]
+pub fn derive_const_eq(input: TokenStream1) -> TokenStream1 {
+    syn::parse(input)
+        .map_err(crate::Error::from)
+        .and_then(derive_eq::derive_consteq_impl)
+        .unwrap_or_else(compile_err_item)
+        .into()
+}
+
+#[cfg(feature = "derive")]
+#[proc_macro_derive(ConstCmp, attributes(ccmp))]
+pub fn derive_const_cmp(input: TokenStream1) -> TokenStream1 {
+    syn::parse(input)
+        .map_err(crate::Error::from)
+        .and_then(derive_eq::derive_constcmp_impl)
+        .unwrap_or_else(compile_err_item)
+        .into()
+}
+
 /// `__respan_to!(( foo tokens )  bar tokens )`
 /// Respan all the bar tokens to the span of the foo tokens
 #[proc_macro]
@@ -0,0 +1,195 @@
+use crate::{
+    format_args::parse_fmt_lit,
+    format_str::{FmtArg, FmtStrComponent, FormatStr, WhichArg},
+    formatting::{Formatting, FormattingFlags, IsAlternate, NumberFormatting},
+    parse_utils::{MyParse, ParseBuffer, ParseStream},
+    spanned::Spans,
+    Error,
+};
+
+use proc_macro2::{Literal, Span, TokenStream as TokenStream2, TokenTree as TokenTree2};
+
+use quote::quote;
+
+#[cfg(test)]
+mod tests;
+
+////////////////////////////////////////////////////////////////////////////////
+
+/// The arguments of `str_scan`
+pub(crate) struct ScanArgs {
+    pattern: FormatStr,
+    pattern_span: Span,
+    input: TokenStream2,
+    types: Vec<ScanType>,
+}
+
+/// A type that an argument of the pattern is parsed into.
+struct ScanType {
+    ty: TokenStream2,
+    spans: Spans,
+    is_bool: bool,
+}
+
+/// Input syntax: `("pattern") (input) (type0) (type1)`
+impl MyParse for ScanArgs {
+    fn parse(input: ParseStream<'_>) -> Result<Self, crate::Error> {
+        let mut pattern = FormatStr { list: Vec::new() };
+
+        let pattern_span;
+        {
+            let paren = input.parse_paren()?;
+            pattern_span = first_span(&paren.contents).unwrap_or(paren.paren_span);
+            parse_fmt_lit(&mut pattern, &mut ParseBuffer::new(paren.contents))?;
+        }
+
+        let input_expr = input.parse_paren()?.contents;
+
+        let mut types = Vec::new();
+        while !input.is_empty() {
+            let paren = input.parse_paren()?;
+            let mut content = ParseBuffer::new(paren.contents);
+            let (ty, spans) = content.parse_unwrap_tt(|c| Ok(c.parse_token_stream_and_span()))?;
+            let is_bool = matches!(
+                &ty.clone().into_iter().collect::<Vec<_>>()[..],
+                [TokenTree2::Ident(ident)] if ident == "bool",
+            );
+            types.push(ScanType { ty, spans, is_bool });
+        }
+
+        Ok(Self {
+            pattern,
+            pattern_span,
+            input: input_expr,
+            types,
+        })
+    }
+}
+
+/// The span of the first token, looking inside none-delimited groups.
+fn first_span(ts: &TokenStream2) -> Option<Span> {
+    match ts.clone().into_iter().next()? {
+        TokenTree2::Group(group) if group.delimiter() == proc_macro2::Delimiter::None => {
+            first_span(&group.stream())
+        }
+        tt => Some(tt.span()),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn str_scan_impl(args: ScanArgs) -> Result<TokenStream2, crate::Error> {
+    let pattern_span = args.pattern_span;
+
+    let mut pieces = Vec::new();
+    let mut radixes = Vec::new();
+    let mut prev_is_arg = false;
+
+    for component in &args.pattern.list {
+        match component {
+            FmtStrComponent::Str(str, rawness) => {
+                let str = rawness.tokenize_sub(str);
+                pieces.push(quote!(__cf_osRcTFl4A::__str_methods::ScanPiece::Str(#str)));
+                prev_is_arg = false;
+            }
+            FmtStrComponent::Arg(arg) => {
+                if prev_is_arg {
+                    return Err(Error::new(
+                        pattern_span,
+                        "the arguments of a `str_scan` pattern must be separated by text, \
+                         eg: `{}.{}` instead of `{}{}`",
+                    ));
+                }
+                radixes.push(arg_radix(arg, pattern_span)?);
+                pieces.push(quote!(__cf_osRcTFl4A::__str_methods::ScanPiece::Arg));
+                prev_is_arg = true;
+            }
+        }
+    }
+
+    if radixes.len() != args.types.len() {
+        return Err(Error::new(
+            pattern_span,
+            format!(
+                "the `str_scan` pattern has {} argument(s), but {} type(s) were passed",
+                radixes.len(),
+                args.types.len(),
+            ),
+        ));
+    }
+
+    let mut values = Vec::with_capacity(args.types.len());
+    for (index, (ScanType { ty, spans, is_bool }, radix)) in
+        args.types.iter().zip(&radixes).enumerate()
+    {
+        let index = Literal::usize_unsuffixed(index);
+        values.push(match (is_bool, radix) {
+            (true, None) => quote!((bool #index #ty)),
+            (true, Some(_)) => {
+                return Err(Error::spanned(
+                    *spans,
+                    "`bool` arguments of `str_scan` must use `{}`, not a radix formatter",
+                ))
+            }
+            (false, None) => quote!((int #index #ty, __cf_osRcTFl4A::pmr::None)),
+            (false, Some(radix)) => {
+                quote!((int #index #ty, __cf_osRcTFl4A::pmr::Some(#radix)))
+            }
+        });
+    }
+
+    let input = &args.input;
+    let arg_count = radixes.len();
+
+    Ok(quote!(__cf_osRcTFl4A::__str_scan_inner! {
+        (#input)
+        [#(#pieces),*]
+        (#arg_count)
+        #(#values)*
+    }))
+}
+
+/// The radix that an argument is parsed with, `None` for `{}`.
+fn arg_radix(arg: &FmtArg, pattern_span: Span) -> Result<Option<u32>, crate::Error> {
+    if arg.which_arg != WhichArg::Positional(None) {
+        return Err(Error::new(
+            pattern_span,
+            "the arguments of a `str_scan` pattern can't be named or numbered",
+        ));
+    }
+
+    let FormattingFlags {
+        formatting,
+        is_alternate,
+        fill: _,
+        alignment,
+        sign_plus,
+        sign_aware_zero_pad,
+    } = arg.formatting;
+
+    if alignment.is_some()
+        || is_alternate == IsAlternate::Yes
+        || sign_plus
+        || sign_aware_zero_pad
+        || arg.width.is_some()
+        || arg.precision.is_some()
+    {
+        return Err(unsupported_formatting(pattern_span));
+    }
+
+    match formatting {
+        Formatting::Display => Ok(None),
+        Formatting::Debug(NumberFormatting::Hexadecimal)
+        | Formatting::Debug(NumberFormatting::LowerHexadecimal) => Ok(Some(16)),
+        Formatting::Debug(NumberFormatting::Octal) => Ok(Some(8)),
+        Formatting::Debug(NumberFormatting::Binary) => Ok(Some(2)),
+        Formatting::Debug(_) => Err(unsupported_formatting(pattern_span)),
+    }
+}
+
+fn unsupported_formatting(span: Span) -> crate::Error {
+    Error::new(
+        span,
+        "`str_scan` patterns only support the `{}`, `{:x}`, `{:X}`, `{:o}`, and `{:b}` arguments",
+    )
+}
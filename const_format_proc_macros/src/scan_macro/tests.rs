@@ -0,0 +1,96 @@
+use crate::{parse_utils::MyParse, test_utils::StrExt};
+
+fn process_str(s: &str) -> Result<String, String> {
+    MyParse::parse_token_stream_2(s.parse().unwrap())
+        .and_then(crate::scan_macro::str_scan_impl)
+        .map(|x| x.to_string())
+        .map_err(|e| e.to_compile_error().to_string())
+}
+
+#[test]
+fn valid_patterns() {
+    let cases = [
+        (
+            r#"("{}.{}") (FOO) (u8) (i32)"#,
+            &["ScanPiece :: Arg", "ScanPiece :: Str (\".\")"][..],
+        ),
+        (
+            r#"("v{:x}") (FOO) (u8)"#,
+            &["ScanPiece :: Str (\"v\")", "(int 0 u8", "Some (16u32)"],
+        ),
+        (
+            r#"("{:b};{}") (FOO) (u8) (bool)"#,
+            &["Some (2u32)", "(bool 1 bool)"],
+        ),
+        (
+            r#"(concat!("{{", "{:o}}}")) (FOO) (u8)"#,
+            &["\"{\"", "\"}\"", "Some (8u32)"],
+        ),
+    ];
+
+    for (case, expected) in cases.iter().copied() {
+        let out = process_str(case).unwrap();
+        assert!(out.consecutive_in_self(expected), "{}", out);
+    }
+}
+
+#[test]
+fn consecutive_arguments_error() {
+    let err = process_str(r#"("{}{}") (FOO) (u8) (u8)"#).unwrap_err();
+    assert!(
+        err.consecutive_in_self(&["must be separated by text"]),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn argument_count_error() {
+    let err = process_str(r#"("{}.{}") (FOO) (u8)"#).unwrap_err();
+    assert!(
+        err.consecutive_in_self(&["has 2 argument", "but 1 type"]),
+        "{}",
+        err
+    );
+
+    let err = process_str(r#"("{}") (FOO) (u8) (u8)"#).unwrap_err();
+    assert!(
+        err.consecutive_in_self(&["has 1 argument", "but 2 type"]),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn unsupported_argument_errors() {
+    for case in [r#"("{foo}") (FOO) (u8)"#, r#"("{0}") (FOO) (u8)"#] {
+        let err = process_str(case).unwrap_err();
+        assert!(
+            err.consecutive_in_self(&["can't be named or numbered"]),
+            "{}",
+            err
+        );
+    }
+
+    for case in [
+        r#"("{:?}") (FOO) (u8)"#,
+        r#"("{:e}") (FOO) (u8)"#,
+        r#"("{:5}") (FOO) (u8)"#,
+        r#"("{:.5}") (FOO) (u8)"#,
+        r#"("{:<}") (FOO) (u8)"#,
+        r#"("{:+}") (FOO) (u8)"#,
+        r#"("{:08x}") (FOO) (u8)"#,
+        r#"("{:#x}") (FOO) (u8)"#,
+        r#"("{:#?}") (FOO) (u8)"#,
+    ] {
+        let err = process_str(case).unwrap_err();
+        assert!(err.consecutive_in_self(&["only support"]), "{}", err);
+    }
+
+    let err = process_str(r#"("{:x}") (FOO) (bool)"#).unwrap_err();
+    assert!(
+        err.consecutive_in_self(&["`bool`", "must use `{}`"]),
+        "{}",
+        err
+    );
+}
@@ -5,7 +5,7 @@ use crate::{
     spanned::Spans,
 };
 
-use proc_macro2::TokenStream as TokenStream2;
+use proc_macro2::{Literal, TokenStream as TokenStream2, TokenTree as TokenTree2};
 
 use quote::ToTokens;
 
@@ -41,7 +41,10 @@ impl MyParse for ExprArg {
         content.parse_unwrap_tt(|content| {
             let (expr, span) = content.parse_token_stream_and_span();
 
-            Ok(Self { span, expr })
+            Ok(Self {
+                span,
+                expr: default_float_literal(expr),
+            })
         })
     }
 }
@@ -63,3 +66,83 @@ impl MyParse for ExprArgs {
         Ok(Self { args })
     }
 }
+
+////////////////////////////////////////////////
+
+/// Gives unsuffixed float literals (eg: `0.5`, `-1e3`) the `f64` type,
+/// like Rust does by default,
+/// because the types that the macros call methods on are implemented for
+/// both `f32` and `f64`, making the type of those literals ambiguous.
+pub(crate) fn default_float_literal(expr: TokenStream2) -> TokenStream2 {
+    let mut tokens = expr.clone().into_iter().collect::<Vec<TokenTree2>>();
+
+    let lit = match &mut tokens[..] {
+        [TokenTree2::Literal(lit)] => lit,
+        [TokenTree2::Punct(minus), TokenTree2::Literal(lit)] if minus.as_char() == '-' => lit,
+        _ => return expr,
+    };
+
+    let lit_str = lit.to_string();
+
+    let is_unsuffixed_float = lit_str.starts_with(|c: char| c.is_ascii_digit())
+        && !lit_str.starts_with("0x")
+        && !lit_str.starts_with("0o")
+        && !lit_str.starts_with("0b")
+        && lit_str.contains(|c| matches!(c, '.' | 'e' | 'E'))
+        && lit_str
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '_' | '.' | 'e' | 'E' | '+' | '-'));
+
+    if !is_unsuffixed_float {
+        return expr;
+    }
+
+    match lit_str.replace('_', "").parse::<f64>() {
+        Ok(float) if float.is_finite() => {
+            let span = lit.span();
+            *lit = Literal::f64_suffixed(float);
+            lit.set_span(span);
+            tokens.into_iter().collect()
+        }
+        _ => expr,
+    }
+}
+
+/// Gives unsuffixed integer literals (eg: `10`, `0xFF`) the `u8` type,
+/// because the `concatbytes` and `formatbytes` macros write `u8`s as bytes,
+/// while the literals would otherwise be ambiguous between all the integer types.
+pub(crate) fn default_byte_literal(expr: TokenStream2) -> TokenStream2 {
+    let mut tokens = expr.clone().into_iter().collect::<Vec<TokenTree2>>();
+
+    let lit = match &mut tokens[..] {
+        [TokenTree2::Literal(lit)] => lit,
+        _ => return expr,
+    };
+
+    let lit_str = lit.to_string();
+
+    let (radix, digits) = match lit_str.get(..2) {
+        Some("0x") => (16, &lit_str[2..]),
+        Some("0o") => (8, &lit_str[2..]),
+        Some("0b") => (2, &lit_str[2..]),
+        _ => (10, &lit_str[..]),
+    };
+
+    let is_unsuffixed_int = !digits.is_empty()
+        && digits.starts_with(|c: char| c.is_digit(radix))
+        && digits.chars().all(|c| c.is_digit(radix) || c == '_');
+
+    if !is_unsuffixed_int {
+        return expr;
+    }
+
+    match u8::from_str_radix(&digits.replace('_', ""), radix) {
+        Ok(byte) => {
+            let span = lit.span();
+            *lit = Literal::u8_suffixed(byte);
+            lit.set_span(span);
+            tokens.into_iter().collect()
+        }
+        _ => expr,
+    }
+}